@@ -3,6 +3,7 @@ use crate::models::environment::Environment;
 use crate::models::template::CommandTemplate;
 use crate::execution::executor::{CommandExecutor, ExecutionResult};
 use crate::command::builder::CommandBuilder;
+use ratatui::layout::Rect;
 use std::collections::HashMap;
 use tui_textarea::{TextArea, Input};
 
@@ -24,10 +25,21 @@ pub struct App {
     pub execution_result: Option<ExecutionResult>,
     /// Command history
     pub history: Vec<CurlCommand>,
+    /// Past responses, keyed by `CurlCommand.id`, bounded per command
+    pub response_history: crate::execution::history::ResponseHistory,
     /// UI state
     pub ui_state: UiState,
     /// Command executor
     pub executor: Option<CommandExecutor>,
+    /// Most recent validation error, surfaced by `StatusBar`
+    pub status_message: Option<String>,
+    /// Resolves key chords to actions, rebindable via a user config file
+    pub keymap: crate::keymap::Keymap,
+    /// Whether field buffers use vim-style modal editing (`Config::vim_mode`)
+    pub vim_mode: bool,
+    /// Shared yank register for vim-mode `y`/`p`, carried across fields so
+    /// a value yanked from one header can be pasted into another field
+    pub register: String,
 }
 
 /// Application state enum
@@ -42,12 +54,125 @@ pub enum AppState {
     EditingTemplateName,
     /// Editing environment variables
     EditingEnvironment,
+    /// Prompting the user to fill in a template's unbound variables
+    FillingTemplateVariables,
+    /// Editing the selected template's collection folder path
+    EditingTemplateFolder,
+    /// Fuzzy command palette over templates, history, and curl options
+    CommandPalette,
+    /// Fuzzy picker over standard headers, curl flags, and saved requests,
+    /// with a live preview of the highlighted candidate
+    Picker,
+    /// Inline completion popup for a header key or curl flag being typed
+    Completing(EditField),
+    /// Pasting a raw curl command to import into the current command
+    ImportingCurlCommand,
     /// Viewing help
     Help,
+    /// Typing an incremental fuzzy filter query over the templates tree
+    FilteringTemplates,
+    /// Typing an incremental search query over the output panel's text
+    SearchingOutput,
     /// Exiting the application
     Exiting,
 }
 
+/// Tracks progress while prompting the user for a template's unbound
+/// variables before it can be loaded
+pub struct TemplateVariablePrompt {
+    /// Index of the template in `App::templates` being loaded
+    pub template_index: usize,
+    /// Variables still needing a value, in prompt order
+    pub pending: Vec<crate::models::template::TemplateVariable>,
+    /// Index into `pending` currently being filled
+    pub current_index: usize,
+    /// Values collected so far, keyed by variable name
+    pub values: HashMap<String, String>,
+}
+
+/// A single ranked entry in the fuzzy command palette
+#[derive(Clone, Debug)]
+pub enum PaletteItem {
+    /// Index into `App::templates`
+    Template(usize),
+    /// Index into `App::history`
+    History(usize),
+    /// A curl flag, e.g. "--location"
+    Option(String),
+}
+
+/// A single ranked entry in the header/flag/saved-request picker
+#[derive(Clone, Debug, PartialEq)]
+pub enum PickerItem {
+    /// Index into `headers::STANDARD_HEADERS`
+    Header(usize),
+    /// A curl flag, e.g. "--location"
+    Option(String),
+    /// Index into `App::templates`
+    SavedRequest(usize),
+}
+
+/// The highlighted picker item's rendered preview, cached so it's only
+/// recomputed when the highlighted item changes rather than on every
+/// keystroke or render pass
+#[derive(Clone, Debug, PartialEq)]
+pub struct PickerPreview {
+    /// Index into `picker_results` this preview was rendered for
+    pub for_index: usize,
+    /// Preview lines to render on the right-hand side of the picker
+    pub lines: Vec<String>,
+}
+
+/// Render the right-hand preview pane content for a single picker item: a
+/// header's canonical name and description, a curl flag's one-line
+/// description and the resulting command-line snippet, or a saved
+/// request's full rendered curl command
+fn render_picker_item_preview(item: &PickerItem, templates: &[CommandTemplate], current_options: &[crate::models::command::CurlOption]) -> Vec<String> {
+    match item {
+        PickerItem::Header(idx) => match crate::headers::STANDARD_HEADERS.get(*idx) {
+            Some(header) => vec![
+                header.name.to_string(),
+                String::new(),
+                header.description.to_string(),
+            ],
+            None => vec!["Unknown header".to_string()],
+        },
+        PickerItem::Option(flag) => {
+            let curl_options = crate::command::options::CurlOptions::new();
+            match curl_options.get_option(flag) {
+                Some(option_def) => {
+                    let label = option_def.long_flag.as_deref().unwrap_or(&option_def.flag);
+                    // If this option is already in the current command, show
+                    // the fragment it actually assembles to (with its real
+                    // value, if any) rather than a generic placeholder
+                    let already_added = current_options.iter().find(|o| o.flag == option_def.flag);
+                    let fragment = match (already_added, option_def.takes_value) {
+                        (Some(existing), true) => format!("curl {} {} ...", option_def.flag, existing.value.as_deref().unwrap_or("<value>")),
+                        (Some(_), false) => format!("curl {} ...", option_def.flag),
+                        (None, true) => format!("curl {} <value> ...", option_def.flag),
+                        (None, false) => format!("curl {} ...", option_def.flag),
+                    };
+                    vec![
+                        label.to_string(),
+                        String::new(),
+                        option_def.description.clone(),
+                        String::new(),
+                        format!("Takes a value: {}", if option_def.takes_value { "yes" } else { "no" }),
+                        String::new(),
+                        if already_added.is_some() { "Already in command:".to_string() } else { "If enabled:".to_string() },
+                        fragment,
+                    ]
+                }
+                None => vec!["Unknown option".to_string()],
+            }
+        }
+        PickerItem::SavedRequest(idx) => match templates.get(*idx) {
+            Some(template) => vec![template.name.clone(), String::new(), template.command.to_shell_command()],
+            None => vec!["Unknown saved request".to_string()],
+        },
+    }
+}
+
 /// Editable fields
 #[derive(Clone, Debug)]
 pub enum EditField {
@@ -67,6 +192,101 @@ pub enum EditField {
     Body,
     /// Option value
     OptionValue(usize),
+    /// Option flag, typed freehand with completion against known curl flags
+    OptionFlag(usize),
+}
+
+/// Vim-style modal editing state for the active `edit_buffer`, used only
+/// when `App::vim_mode` is on; otherwise editing stays in `Insert`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EditMode {
+    /// `h/l`, `w/b`, `0/$` move the cursor (`j/k` are no-ops in a
+    /// single-line buffer); `i/a/o` enter `Insert`,
+    /// `v/V` start a selection, `x` deletes the char under the cursor,
+    /// `p` pastes the shared register
+    Normal,
+    /// Typing inserts directly into the buffer at the cursor, like the
+    /// non-modal editing path
+    Insert,
+    /// Character-wise selection from `visual_anchor` to the cursor
+    VisualChar,
+    /// Line-wise selection; since field buffers are single-line this
+    /// selects the whole buffer
+    VisualLine,
+}
+
+/// Category tag shown in the completion popup's middle column
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CompletionKind {
+    /// A standard HTTP header name
+    Header,
+    /// A curl flag outside the Authentication category
+    Flag,
+    /// A curl flag in the Authentication category
+    Auth,
+    /// A standard MIME type, completed for a `Content-Type` header's value
+    Mime,
+    /// One of a curl option's known values (e.g. an HTTP method or enum
+    /// option), completed for that option's value
+    Value,
+}
+
+impl CompletionKind {
+    /// Short tag rendered alongside a candidate, e.g. "header" or "auth"
+    pub fn label(&self) -> &'static str {
+        match self {
+            CompletionKind::Header => "header",
+            CompletionKind::Flag => "flag",
+            CompletionKind::Auth => "auth",
+            CompletionKind::Mime => "mime",
+            CompletionKind::Value => "value",
+        }
+    }
+}
+
+/// A single ranked candidate in the inline completion popup
+#[derive(Clone, Debug, PartialEq)]
+pub struct CompletionCandidate {
+    /// The text inserted if this candidate is chosen
+    pub label: String,
+    /// Category tag shown in the popup's middle column
+    pub kind: CompletionKind,
+    /// Short hint shown in the popup's right column
+    pub hint: String,
+}
+
+/// Hit-test rectangles `ui::components::command_builder::CommandBuilder::render`
+/// records each frame so a mouse click can be mapped back to the tab/field
+/// it landed on, mirroring how the keyboard path resolves a chord through
+/// `Keymap` before dispatching it
+#[derive(Default)]
+pub struct ClickRegions {
+    /// The URL input box
+    pub url: Option<Rect>,
+    /// Each tab's title region, in display order
+    pub tabs: Vec<(Tab, Rect)>,
+    /// Each selectable row in the active tab's list, paired with the
+    /// `SelectedField` a click on it should select
+    pub rows: Vec<(SelectedField, Rect)>,
+}
+
+impl ClickRegions {
+    /// The tab whose title region contains `(x, y)`, if any
+    fn tab_at(&self, x: u16, y: u16) -> Option<Tab> {
+        self.tabs.iter().find(|(_, rect)| rect_contains(*rect, x, y)).map(|(tab, _)| tab.clone())
+    }
+
+    /// The row field whose region contains `(x, y)`, if any
+    fn row_at(&self, x: u16, y: u16) -> Option<SelectedField> {
+        self.rows.iter().find(|(_, rect)| rect_contains(*rect, x, y)).map(|(field, _)| field.clone())
+    }
+}
+
+/// Whether `(x, y)` falls within `rect`, written by hand rather than via
+/// `Rect::contains` since this snapshot has no `Cargo.toml` pinning a
+/// `ratatui` version and that method isn't available on every version
+fn rect_contains(rect: Rect, x: u16, y: u16) -> bool {
+    x >= rect.x && x < rect.x.saturating_add(rect.width) && y >= rect.y && y < rect.y.saturating_add(rect.height)
 }
 
 /// UI state
@@ -85,8 +305,25 @@ pub struct UiState {
     pub history_expanded: bool,
     /// Currently selected option category
     pub selected_option_category: OptionCategory,
+    /// Currently highlighted row in the options grid's flattened
+    /// basic+advanced option list, used to pick which option the grid's
+    /// side preview pane (see `OptionsPanel`) describes in full
+    pub options_grid_selected: usize,
+    /// Whether the options grid shows its side preview pane, toggled via
+    /// `ToggleOptionPreview`; auto-suppressed on narrow terminals
+    /// regardless of this flag
+    pub options_grid_preview_visible: bool,
     /// Current edit buffer for editing fields
     pub edit_buffer: String,
+    /// Vim-mode modal state for `edit_buffer` (always `Insert` when
+    /// `App::vim_mode` is off)
+    pub edit_mode: EditMode,
+    /// Character index of the cursor within `edit_buffer`, used by vim
+    /// mode's motions and by `CommandBuilder` to draw the cursor in place
+    pub edit_cursor: usize,
+    /// Start of an in-progress vim-mode visual selection, as a character
+    /// index into `edit_buffer`
+    pub visual_anchor: Option<usize>,
     /// Selected method index in dropdown (when dropdown is open)
     pub method_dropdown_index: usize,
     /// Cursor visibility for blinking effect
@@ -97,6 +334,106 @@ pub struct UiState {
     pub body_textarea: TextArea<'static>,
     /// Scroll offset for options tab
     pub options_scroll_offset: usize,
+    /// Scroll offset for headers tab
+    pub headers_scroll_offset: usize,
+    /// Scroll offset for the URL tab's query param list
+    pub query_params_scroll_offset: usize,
+    /// Scroll offset (first visible line) for the body editor's viewport
+    pub body_scroll_offset: usize,
+    /// Whether disabled headers/query params/options are collapsed into a
+    /// single "Disabled (n)" summary row in their respective lists
+    pub fold_disabled: bool,
+    /// Active prompt for filling a template's unbound variables, if any
+    pub template_variable_prompt: Option<TemplateVariablePrompt>,
+    /// Current query typed into the command palette
+    pub palette_query: String,
+    /// Ranked palette items for the current query, most relevant first
+    pub palette_results: Vec<PaletteItem>,
+    /// Currently highlighted row in the palette results
+    pub palette_selected: usize,
+    /// Current query typed into the header/flag/saved-request picker
+    pub picker_query: String,
+    /// Ranked picker items for the current query, most relevant first
+    pub picker_results: Vec<PickerItem>,
+    /// Currently highlighted row in the picker results
+    pub picker_selected: usize,
+    /// Cached preview for the currently highlighted picker item
+    pub picker_preview: Option<PickerPreview>,
+    /// Ranked completion candidates for the header key or curl flag
+    /// currently being typed
+    pub completion_results: Vec<CompletionCandidate>,
+    /// Currently highlighted row in the completion popup
+    pub completion_selected: usize,
+    /// Full paths of collections-tree folders currently expanded,
+    /// persisted across sessions
+    pub expanded_folders: std::collections::HashSet<String>,
+    /// Selected view mode for the output panel, cycled via `ToggleOutputFormat`
+    pub output_format: crate::execution::output::OutputFormat,
+    /// Whether the generated command preview shows secret variables' real
+    /// values instead of masking them with `***`, toggled via
+    /// `ToggleRevealSecrets`
+    pub reveal_secrets: bool,
+    /// Whether JSON response bodies in the output panel are reflowed into
+    /// indented multi-line form, rather than shown as the server's exact
+    /// raw bytes, toggled via `ToggleOutputPretty`
+    pub output_pretty_print: bool,
+    /// First visible line of the output panel's viewport, applied via
+    /// `Paragraph::scroll`
+    pub output_scroll_offset: u16,
+    /// Total line count of the output panel's last rendered text, used to
+    /// clamp `output_scroll_offset` and to compute page/bottom targets
+    pub output_line_count: usize,
+    /// Current incremental search query typed into the output panel,
+    /// matched case-insensitively against each rendered line
+    pub output_search_query: String,
+    /// Line numbers (into the output panel's rendered text) containing a
+    /// match for `output_search_query`, in ascending order
+    pub output_match_lines: Vec<usize>,
+    /// Index into `output_match_lines` the scroll is currently parked on,
+    /// cycled by `OutputSearchNext`/`OutputSearchPrev`
+    pub output_match_selected: usize,
+    /// Which parts of the formatted response the output panel shows,
+    /// cycled via `CycleOutputViewMode`
+    pub output_view_mode: OutputViewMode,
+    /// Current query typed into the templates tree's incremental fuzzy
+    /// filter; matching folders are force-expanded regardless of
+    /// `expanded_folders`
+    pub template_filter_query: String,
+    /// Target format the generated command preview renders, cycled via
+    /// `CycleGenerator`
+    pub current_generator: crate::command::generator::GeneratorKind,
+    /// Hit-test rectangles recorded by the last render pass, used to map a
+    /// mouse click's coordinates back to a tab/field
+    pub click_regions: ClickRegions,
+    /// Column, row, and time of the last mouse click, used to detect a
+    /// double-click (same spot, within the double-click window) that
+    /// enters edit mode instead of just selecting
+    pub last_click: Option<(u16, u16, std::time::Instant)>,
+    /// Cached, already-highlighted generated-command preview from the last
+    /// frame, keyed on a hash of everything that feeds into it, so
+    /// `CommandDisplay::render` only re-generates and re-tokenizes the
+    /// command when that state actually changed
+    pub command_preview_cache: Option<(u64, ratatui::text::Text<'static>)>,
+}
+
+impl UiState {
+    /// The active vim-mode visual selection as an inclusive `(start, end)`
+    /// character range into `edit_buffer`, sorted regardless of which end
+    /// the cursor is on. `VisualLine` selects the whole buffer, since
+    /// field buffers are always single-line. `None` outside visual mode.
+    pub fn visual_selection_range(&self) -> Option<(usize, usize)> {
+        match self.edit_mode {
+            EditMode::VisualChar => {
+                let anchor = self.visual_anchor?;
+                Some((anchor.min(self.edit_cursor), anchor.max(self.edit_cursor)))
+            }
+            EditMode::VisualLine => {
+                let len = self.edit_buffer.chars().count();
+                Some((0, len.saturating_sub(1)))
+            }
+            _ => None,
+        }
+    }
 }
 
 /// Selected field in each tab
@@ -133,6 +470,7 @@ pub enum BodyField {
 }
 
 /// UI tabs
+#[derive(Clone)]
 pub enum Tab {
     /// URL and method tab
     Url,
@@ -144,6 +482,41 @@ pub enum Tab {
     Options,
 }
 
+/// Which parts of a formatted response the output panel shows, cycled via
+/// `CycleOutputViewMode` -- mirrors the `-i`/`-I` distinction CLI HTTP
+/// clients draw between the full response, just the headers, and just
+/// the body
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputViewMode {
+    /// Headers and body, the default
+    All,
+    /// Only the headers/preamble, up to and including the blank separator
+    HeadersOnly,
+    /// Only the body, nothing before the blank separator
+    BodyOnly,
+}
+
+impl OutputViewMode {
+    /// Cycle to the next mode: All -> HeadersOnly -> BodyOnly -> All
+    pub fn next(self) -> Self {
+        match self {
+            OutputViewMode::All => OutputViewMode::HeadersOnly,
+            OutputViewMode::HeadersOnly => OutputViewMode::BodyOnly,
+            OutputViewMode::BodyOnly => OutputViewMode::All,
+        }
+    }
+
+    /// Short label shown in the output panel's block title, e.g. `"body"`
+    /// in `"Output [body]"`
+    pub fn label(self) -> Option<&'static str> {
+        match self {
+            OutputViewMode::All => None,
+            OutputViewMode::HeadersOnly => Some("headers"),
+            OutputViewMode::BodyOnly => Some("body"),
+        }
+    }
+}
+
 /// Curl option categories
 pub enum OptionCategory {
     /// Basic options
@@ -164,6 +537,8 @@ pub enum OptionCategory {
     Output,
     /// Command Line options
     CommandLine,
+    /// Transport/protocol-version options
+    Protocol,
 }
 
 impl Default for App {
@@ -181,6 +556,7 @@ impl Default for App {
             output: None,
             execution_result: None,
             history: Vec::new(),
+            response_history: crate::execution::history::ResponseHistory::default(),
             ui_state: UiState {
                 active_tab: Tab::Url,
                 selected_field: SelectedField::Url(UrlField::Url),
@@ -189,21 +565,79 @@ impl Default for App {
                 environments_expanded: true,
                 history_expanded: false,
                 selected_option_category: OptionCategory::CommandLine,
+                options_grid_selected: 0,
+                options_grid_preview_visible: true,
                 edit_buffer: String::new(),
+                edit_mode: EditMode::Insert,
+                edit_cursor: 0,
+                visual_anchor: None,
                 method_dropdown_index: 0,
                 cursor_visible: true,
                 cursor_blink_counter: 0,
                 body_textarea: TextArea::default(),
                 options_scroll_offset: 0,
+                headers_scroll_offset: 0,
+                query_params_scroll_offset: 0,
+                body_scroll_offset: 0,
+                fold_disabled: false,
+                template_variable_prompt: None,
+                palette_query: String::new(),
+                palette_results: Vec::new(),
+                palette_selected: 0,
+                picker_query: String::new(),
+                picker_results: Vec::new(),
+                picker_selected: 0,
+                picker_preview: None,
+                completion_results: Vec::new(),
+                completion_selected: 0,
+                expanded_folders: std::collections::HashSet::new(),
+                output_format: crate::execution::output::OutputFormat::Formatted,
+                reveal_secrets: false,
+                output_pretty_print: false,
+                output_scroll_offset: 0,
+                output_line_count: 0,
+                output_search_query: String::new(),
+                output_match_lines: Vec::new(),
+                output_match_selected: 0,
+                output_view_mode: OutputViewMode::All,
+                template_filter_query: String::new(),
+                current_generator: crate::command::generator::GeneratorKind::default(),
+                click_regions: ClickRegions::default(),
+                last_click: None,
+                command_preview_cache: None,
             },
             executor: None,
+            status_message: None,
+            keymap: crate::keymap::Keymap::load_default(),
+            vim_mode: crate::config::Config::load().vim_mode,
+            register: String::new(),
         }
     }
 }
 
 impl App {
-    /// Create a new application instance
+    /// Create a new application instance, loading persisted templates,
+    /// environments, and history from disk if a state file exists. Only a
+    /// brand-new install (no state file yet) gets the built-in sample
+    /// environment and templates.
     pub fn new() -> Self {
+        // Try to create command executor
+        let executor = CommandExecutor::new().ok();
+
+        if crate::persistence::has_persisted_state() {
+            let persisted = crate::persistence::load();
+            let mut app = Self {
+                environments: persisted.environments,
+                current_environment: persisted.current_environment,
+                templates: persisted.templates,
+                history: persisted.history,
+                executor,
+                ..Self::default()
+            };
+            app.ui_state.expanded_folders = persisted.expanded_folders;
+            return app;
+        }
+
         // Create default environment
         let mut environments = HashMap::new();
         environments.insert(
@@ -211,34 +645,33 @@ impl App {
             Environment {
                 id: "env_default".to_string(),
                 name: "Default".to_string(),
+                parent: None,
                 variables: Vec::new(),
                 created_at: chrono::Utc::now(),
                 updated_at: chrono::Utc::now(),
             },
         );
 
-        // Try to create command executor
-        let executor = CommandExecutor::new().ok();
-
         // Create some sample templates for testing
         let mut templates = Vec::new();
-        
+
         let mut get_command = CurlCommand::default();
         get_command.name = "GET Example".to_string();
         get_command.url = "https://httpbin.org/get".to_string();
         get_command.method = Some(crate::models::command::HttpMethod::GET);
         get_command.add_option("-i".to_string(), None); // Add -i option by default
-        
+
         templates.push(CommandTemplate {
             id: "template_1".to_string(),
             name: "GET Example".to_string(),
             description: Some("Simple GET request".to_string()),
             command: get_command,
+            variables: Vec::new(),
             category: Some("Examples".to_string()),
             created_at: chrono::Utc::now(),
             updated_at: chrono::Utc::now(),
         });
-        
+
         let mut post_command = CurlCommand::default();
         post_command.name = "POST JSON".to_string();
         post_command.url = "https://httpbin.org/post".to_string();
@@ -246,12 +679,13 @@ impl App {
         post_command.add_header("Content-Type".to_string(), "application/json".to_string());
         post_command.body = Some(crate::models::command::RequestBody::Raw(r#"{"key": "value"}"#.to_string()));
         post_command.add_option("-i".to_string(), None); // Add -i option by default
-        
+
         templates.push(CommandTemplate {
             id: "template_2".to_string(),
             name: "POST JSON".to_string(),
             description: Some("POST with JSON body".to_string()),
             command: post_command,
+            variables: Vec::new(),
             category: Some("Examples".to_string()),
             created_at: chrono::Utc::now(),
             updated_at: chrono::Utc::now(),
@@ -265,14 +699,80 @@ impl App {
         }
     }
 
+    /// Persist templates, environments, the active environment, and
+    /// history to disk under the platform config directory
+    pub fn save(&self) -> Result<(), String> {
+        let state = crate::persistence::PersistedState {
+            templates: self.templates.clone(),
+            environments: self.environments.clone(),
+            current_environment: self.current_environment.clone(),
+            history: self.history.clone(),
+            expanded_folders: self.ui_state.expanded_folders.clone(),
+            ..crate::persistence::PersistedState::default()
+        };
+        crate::persistence::save(&state)
+    }
+
     /// Handle application events
     pub fn handle_event(&mut self, event: &crossterm::event::Event) -> bool {
         match event {
             crossterm::event::Event::Key(key_event) => self.handle_key_event(key_event),
+            crossterm::event::Event::Mouse(mouse_event) => self.handle_mouse_event(mouse_event),
             _ => false,
         }
     }
 
+    /// Handle a mouse click against the hit-test rectangles the last render
+    /// pass recorded into `ui_state.click_regions`: clicking a tab title
+    /// switches `active_tab`, clicking the URL box or a header/query-param/
+    /// option row selects it, and a second click in the same spot within
+    /// the double-click window also enters edit mode for it. Only resolved
+    /// while the builder's tab content is actually on screen (`Normal`
+    /// state); other states (pickers, dropdowns, completion) keep their
+    /// own keyboard-only handling.
+    fn handle_mouse_event(&mut self, mouse_event: &crossterm::event::MouseEvent) -> bool {
+        use crossterm::event::MouseEventKind;
+
+        if !matches!(self.state, AppState::Normal) {
+            return false;
+        }
+        if !matches!(mouse_event.kind, MouseEventKind::Down(_)) {
+            return false;
+        }
+
+        let (x, y) = (mouse_event.column, mouse_event.row);
+        let is_double_click = self
+            .ui_state
+            .last_click
+            .is_some_and(|(lx, ly, at)| lx == x && ly == y && at.elapsed() < std::time::Duration::from_millis(400));
+        self.ui_state.last_click = Some((x, y, std::time::Instant::now()));
+
+        if let Some(tab) = self.ui_state.click_regions.tab_at(x, y) {
+            self.ui_state.active_tab = tab;
+            self.ui_state.selected_field = match self.ui_state.active_tab {
+                Tab::Url => SelectedField::Url(UrlField::Url),
+                Tab::Headers => SelectedField::Headers(0),
+                Tab::Body => SelectedField::Body(BodyField::Content),
+                Tab::Options => SelectedField::Options(0),
+            };
+            return false;
+        }
+
+        if let Some(url_rect) = self.ui_state.click_regions.url {
+            if rect_contains(url_rect, x, y) {
+                self.ui_state.selected_field = SelectedField::Url(UrlField::Url);
+                return if is_double_click { self.start_editing_field() } else { false };
+            }
+        }
+
+        if let Some(field) = self.ui_state.click_regions.row_at(x, y) {
+            self.ui_state.selected_field = field;
+            return if is_double_click { self.start_editing_field() } else { false };
+        }
+
+        false
+    }
+
     /// Handle key events
     fn handle_key_event(&mut self, key_event: &crossterm::event::KeyEvent) -> bool {
         match self.state {
@@ -284,23 +784,44 @@ impl App {
             AppState::MethodDropdown => self.handle_method_dropdown_key(key_event),
             AppState::EditingTemplateName => self.handle_editing_template_name_key(key_event),
             AppState::EditingEnvironment => self.handle_editing_environment_key(key_event),
+            AppState::FillingTemplateVariables => self.handle_template_variable_prompt_key(key_event),
+            AppState::EditingTemplateFolder => self.handle_editing_template_folder_key(key_event),
+            AppState::CommandPalette => self.handle_command_palette_key(key_event),
+            AppState::Picker => self.handle_picker_key(key_event),
+            AppState::Completing(ref field) => {
+                let field_clone = field.clone();
+                self.handle_completing_key(key_event, &field_clone)
+            },
+            AppState::ImportingCurlCommand => self.handle_importing_curl_command_key(key_event),
             AppState::Help => self.handle_help_key(key_event),
+            AppState::FilteringTemplates => self.handle_filtering_templates_key(key_event),
+            AppState::SearchingOutput => self.handle_searching_output_key(key_event),
             AppState::Exiting => true,
         }
     }
 
-    /// Handle key events in normal mode
+    /// Handle key events in normal mode by resolving the chord to an
+    /// `Action` via the keymap and dispatching through `apply_action`
     fn handle_normal_mode_key(&mut self, key_event: &crossterm::event::KeyEvent) -> bool {
-        use crossterm::event::{KeyCode, KeyModifiers};
+        match self.keymap.resolve(key_event) {
+            Some(action) => self.apply_action(action),
+            None => false,
+        }
+    }
+
+    /// Apply a resolved `Action`, returning `true` if the application
+    /// should exit
+    fn apply_action(&mut self, action: crate::keymap::Action) -> bool {
+        use crate::keymap::Action;
 
-        match (key_event.code, key_event.modifiers) {
+        match action {
             // Quit application
-            (KeyCode::Char('q'), KeyModifiers::CONTROL) => {
+            Action::QuitApp => {
                 self.state = AppState::Exiting;
                 true
             }
-            // Switch tabs with Tab or Right arrow
-            (KeyCode::Tab, KeyModifiers::NONE) | (KeyCode::Right, KeyModifiers::CONTROL) => {
+            // Switch to the next tab
+            Action::NextTab => {
                 self.ui_state.active_tab = match self.ui_state.active_tab {
                     Tab::Url => Tab::Headers,
                     Tab::Headers => Tab::Body,
@@ -316,8 +837,8 @@ impl App {
                 };
                 false
             }
-            // Switch tabs with Shift+Tab or Left arrow
-            (KeyCode::BackTab, _) | (KeyCode::Left, KeyModifiers::CONTROL) => {
+            // Switch to the previous tab
+            Action::PrevTab => {
                 self.ui_state.active_tab = match self.ui_state.active_tab {
                     Tab::Url => Tab::Options,
                     Tab::Headers => Tab::Url,
@@ -333,8 +854,8 @@ impl App {
                 };
                 false
             }
-            // Navigate fields with Up/Down arrows
-            (KeyCode::Up, KeyModifiers::NONE) => {
+            // Navigate fields up
+            Action::NavigateUp => {
                 if self.ui_state.selected_template.is_some() {
                     // Navigate templates
                     self.navigate_template_up();
@@ -343,7 +864,8 @@ impl App {
                 }
                 false
             }
-            (KeyCode::Down, KeyModifiers::NONE) => {
+            // Navigate fields down
+            Action::NavigateDown => {
                 if self.ui_state.selected_template.is_some() {
                     // Navigate templates
                     self.navigate_template_down();
@@ -352,12 +874,13 @@ impl App {
                 }
                 false
             }
-            // Navigate fields with Left/Right arrows
-            (KeyCode::Left, KeyModifiers::NONE) => {
+            // Navigate fields left
+            Action::NavigateLeft => {
                 self.navigate_field_left();
                 false
             }
-            (KeyCode::Right, KeyModifiers::NONE) => {
+            // Navigate fields right
+            Action::NavigateRight => {
                 if self.ui_state.selected_template.is_some() {
                     // From templates, go to Method field
                     self.ui_state.selected_template = None;
@@ -367,13 +890,13 @@ impl App {
                 }
                 false
             }
-            // Execute command with F5 or Ctrl+R (reliable options)
-            (KeyCode::F(5), KeyModifiers::NONE) | (KeyCode::Char('r'), KeyModifiers::CONTROL) => {
+            // Execute the current command
+            Action::ExecuteCommand => {
                 self.execute_command();
                 false
             }
-            // Add command line option with Enter
-            (KeyCode::Enter, KeyModifiers::NONE) => {
+            // Confirm the current selection
+            Action::ConfirmSelection => {
                 if let SelectedField::Options(idx) = self.ui_state.selected_field {
                     // Check if we're selecting a command line option
                     if self.is_command_line_option_selected(idx) {
@@ -381,20 +904,17 @@ impl App {
                         return false;
                     }
                 }
-                
-                if let Some(template_idx) = self.ui_state.selected_template {
-                    // Load the selected template
-                    self.load_template(template_idx);
-                    // Clear template selection and go to URL field
-                    self.ui_state.selected_template = None;
-                    self.ui_state.selected_field = SelectedField::Url(UrlField::Url);
+
+                if self.ui_state.selected_template.is_some() {
+                    // Toggle a folder, or load the selected template
+                    self.activate_selected_tree_row();
                 } else {
                     self.start_editing_field();
                 }
                 false
             }
-            // Remove option with Delete or Backspace
-            (KeyCode::Delete, KeyModifiers::NONE) | (KeyCode::Backspace, KeyModifiers::NONE) => {
+            // Remove the selected option
+            Action::DeleteOption => {
                 if let SelectedField::Options(idx) = self.ui_state.selected_field {
                     // Only remove if it's an active option (not a command line option)
                     if !self.is_command_line_option_selected(idx) {
@@ -403,8 +923,8 @@ impl App {
                 }
                 false
             }
-            // Toggle option enabled/disabled with Space
-            (KeyCode::Char(' '), KeyModifiers::NONE) => {
+            // Toggle the selected option enabled/disabled
+            Action::ToggleOption => {
                 if let SelectedField::Options(idx) = self.ui_state.selected_field {
                     // Only toggle if it's an active option (not a command line option)
                     if !self.is_command_line_option_selected(idx) {
@@ -416,185 +936,967 @@ impl App {
                 false
             }
             // Toggle panels
-            (KeyCode::Char('t'), KeyModifiers::CONTROL) => {
+            Action::ToggleTemplates => {
                 self.ui_state.templates_expanded = !self.ui_state.templates_expanded;
                 false
             }
-            (KeyCode::Char('e'), KeyModifiers::CONTROL) => {
+            Action::ToggleEnvironments => {
                 self.ui_state.environments_expanded = !self.ui_state.environments_expanded;
                 false
             }
-            (KeyCode::Char('h'), KeyModifiers::CONTROL) => {
+            Action::ToggleHistory => {
                 self.ui_state.history_expanded = !self.ui_state.history_expanded;
                 false
             }
             // Show help
-            (KeyCode::F(1), KeyModifiers::NONE) => {
+            Action::ShowHelp => {
                 self.state = AppState::Help;
                 false
             }
-            // Default - event not handled
-            _ => false,
-        }
-    }
-
-    /// Navigate to the field above the current one
-    fn navigate_field_up(&mut self) {
-        // Extract the current field without borrowing
-        let current_field = self.ui_state.selected_field.clone();
-        
-        match current_field {
-            SelectedField::Url(field) => {
-                match field {
-                    UrlField::Url => {
-                        // Already at the top, do nothing
-                    }
-                    UrlField::Method => {
-                        self.ui_state.selected_field = SelectedField::Url(UrlField::Url);
-                    }
-                    UrlField::QueryParam(idx) => {
-                        if idx > 0 {
-                            self.ui_state.selected_field = SelectedField::Url(UrlField::QueryParam(idx - 1));
-                        } else {
-                            self.ui_state.selected_field = SelectedField::Url(UrlField::Method);
-                        }
-                    }
-                }
+            // Open the fuzzy command palette
+            Action::OpenCommandPalette => {
+                self.open_command_palette();
+                false
             }
-            SelectedField::Headers(idx) => {
-                if idx > 0 {
-                    self.ui_state.selected_field = SelectedField::Headers(idx - 1);
-                }
+            // Open the header/flag/saved-request picker
+            Action::OpenPicker => {
+                self.open_picker();
+                false
             }
-            SelectedField::Body(field) => {
-                match field {
-                    BodyField::Type => {
-                        // Already at the top, do nothing
-                    }
-                    BodyField::Content => {
-                        self.ui_state.selected_field = SelectedField::Body(BodyField::Type);
-                    }
-                }
+            // Add a new header or curl flag, with inline completion
+            Action::AddField => {
+                self.start_adding_field();
+                false
             }
-            SelectedField::Options(idx) => {
-                if idx > 0 {
-                    // Update the selected field
-                    self.ui_state.selected_field = SelectedField::Options(idx - 1);
-                    
-                    // Adjust scroll offset if needed
-                    if idx <= self.ui_state.options_scroll_offset {
-                        self.ui_state.options_scroll_offset = self.ui_state.options_scroll_offset.saturating_sub(1);
-                    }
-                }
+            // Move the selected template into a different collection folder
+            Action::MoveTemplateFolder => {
+                self.start_reparenting_selected_template();
+                false
             }
-        }
-    }
-
-    /// Navigate to the field below the current one
-    fn navigate_field_down(&mut self) {
-        // Extract the current field without borrowing
-        let current_field = self.ui_state.selected_field.clone();
-        
-        match current_field {
-            SelectedField::Url(field) => {
-                match field {
-                    UrlField::Url => {
-                        self.ui_state.selected_field = SelectedField::Url(UrlField::Method);
-                    }
-                    UrlField::Method => {
-                        if !self.current_command.query_params.is_empty() {
-                            self.ui_state.selected_field = SelectedField::Url(UrlField::QueryParam(0));
-                        }
-                    }
-                    UrlField::QueryParam(idx) => {
-                        if !self.current_command.query_params.is_empty() && idx < self.current_command.query_params.len() - 1 {
-                            self.ui_state.selected_field = SelectedField::Url(UrlField::QueryParam(idx + 1));
-                        }
-                    }
-                }
+            // Start importing a pasted curl command
+            Action::ImportCurlCommand => {
+                self.start_importing_curl_command();
+                false
             }
-            SelectedField::Headers(idx) => {
-                if !self.current_command.headers.is_empty() && idx < self.current_command.headers.len() - 1 {
-                    self.ui_state.selected_field = SelectedField::Headers(idx + 1);
-                }
+            // Export the current command to a Hurl file
+            Action::ExportHurl => {
+                self.export_to_hurl();
+                false
             }
-            SelectedField::Body(field) => {
-                match field {
-                    BodyField::Type => {
-                        self.ui_state.selected_field = SelectedField::Body(BodyField::Content);
-                    }
-                    BodyField::Content => {
-                        // Already at the bottom, do nothing
-                    }
-                }
+            // Reformat the request body: pretty-print or minify
+            Action::ToggleBodyFormat => {
+                self.toggle_body_format();
+                false
             }
-            SelectedField::Options(idx) => {
-                // Get the total number of options (active + command line options)
-                let curl_options = crate::command::options::CurlOptions::new();
-                let command_line_options = curl_options.get_options_by_category(
-                    &crate::command::options::OptionCategory::CommandLine
-                );
-                
-                // Sort command line options by flag to ensure stable ordering
-                let mut sorted_command_line_options = command_line_options.clone();
-                sorted_command_line_options.sort_by(|a, b| a.flag.cmp(&b.flag));
-                
-                let total_options = self.current_command.options.len() + sorted_command_line_options.len();
-                
-                if idx < total_options - 1 {
-                    // Update the selected field
-                    self.ui_state.selected_field = SelectedField::Options(idx + 1);
-                    
-                    // Calculate visible rows (approximate)
-                    // This is a rough estimate - we'll refine this in the render method
-                    let visible_rows = 10; // Approximate number of visible rows
-                    
-                    // Adjust scroll offset if needed
-                    if idx >= self.ui_state.options_scroll_offset + visible_rows - 2 {
-                        self.ui_state.options_scroll_offset += 1;
-                    }
+            // Cycle the output panel's view mode
+            Action::ToggleOutputFormat => {
+                use crate::execution::output::OutputFormat;
+                self.ui_state.output_format = match self.ui_state.output_format {
+                    OutputFormat::Formatted => OutputFormat::Raw,
+                    OutputFormat::Raw => OutputFormat::Json,
+                    OutputFormat::Json => OutputFormat::Pretty,
+                    OutputFormat::Pretty => OutputFormat::Formatted,
+                };
+                false
+            }
+            // Collapse or expand the disabled-entries group in the active list
+            Action::ToggleFoldDisabled => {
+                self.ui_state.fold_disabled = !self.ui_state.fold_disabled;
+                false
+            }
+            // Temporarily reveal secret variables' real values in the
+            // generated command preview
+            Action::ToggleRevealSecrets => {
+                self.ui_state.reveal_secrets = !self.ui_state.reveal_secrets;
+                false
+            }
+            // Start filtering the templates tree, if it's focused
+            Action::FilterTemplates => {
+                if self.ui_state.selected_template.is_some() {
+                    self.state = AppState::FilteringTemplates;
                 }
+                false
             }
-        }
-    }
-
-    /// Navigate to the field to the left of the current one
-    fn navigate_field_left(&mut self) {
-        // Navigate left through different UI sections: Templates ← Method ← URL Container
-        match &self.ui_state.selected_field {
-            SelectedField::Url(UrlField::Url) => {
-                // From URL field, go to Method
-                self.ui_state.selected_field = SelectedField::Url(UrlField::Method);
+            // Cycle the generated command preview's target format
+            Action::CycleGenerator => {
+                self.ui_state.current_generator = self.ui_state.current_generator.next();
+                false
             }
-            SelectedField::Url(UrlField::Method) => {
-                // From Method, go to Templates (always select templates, even if empty)
-                self.ui_state.selected_template = Some(0);
-                // Don't set a selected field when templates are focused - templates take precedence
+            // Reflow JSON response bodies in the output panel, or show the
+            // server's exact raw bytes again
+            Action::ToggleOutputPretty => {
+                self.ui_state.output_pretty_print = !self.ui_state.output_pretty_print;
+                self.refresh_output_search();
+                false
             }
-            // From any field in the URL container (except URL and Method), go back to Method
-            SelectedField::Headers(_) | SelectedField::Body(_) | SelectedField::Options(_) => {
-                self.ui_state.selected_field = SelectedField::Url(UrlField::Method);
+            // Scroll the output panel by one line
+            Action::OutputScrollUp => {
+                self.ui_state.output_scroll_offset = self.ui_state.output_scroll_offset.saturating_sub(1);
+                false
             }
-            SelectedField::Url(UrlField::QueryParam(_)) => {
-                // From query params, also go back to Method to maintain navigation consistency
-                self.ui_state.selected_field = SelectedField::Url(UrlField::Method);
+            Action::OutputScrollDown => {
+                self.scroll_output_by(1);
+                false
+            }
+            // Scroll the output panel by one page
+            Action::OutputPageUp => {
+                let visible_rows = 10; // Approximate number of visible rows
+                self.ui_state.output_scroll_offset = self.ui_state.output_scroll_offset.saturating_sub(visible_rows);
+                false
+            }
+            Action::OutputPageDown => {
+                let visible_rows = 10; // Approximate number of visible rows
+                self.scroll_output_by(visible_rows);
+                false
+            }
+            // Jump the output panel's scroll to the top or bottom
+            Action::OutputScrollTop => {
+                self.ui_state.output_scroll_offset = 0;
+                false
+            }
+            Action::OutputScrollBottom => {
+                self.ui_state.output_scroll_offset = self.max_output_scroll_offset();
+                false
+            }
+            // Start an incremental search over the output panel
+            Action::OpenOutputSearch => {
+                self.ui_state.output_search_query.clear();
+                self.refresh_output_search();
+                self.state = AppState::SearchingOutput;
+                false
+            }
+            // Jump the output scroll to the next/previous search match
+            Action::OutputSearchNext => {
+                self.jump_to_output_match(1);
+                false
+            }
+            Action::OutputSearchPrev => {
+                self.jump_to_output_match(-1);
+                false
+            }
+            // Cycle the output panel between showing headers+body, just
+            // headers, or just the body
+            Action::CycleOutputViewMode => {
+                self.ui_state.output_view_mode = self.ui_state.output_view_mode.next();
+                self.ui_state.output_scroll_offset = 0;
+                self.refresh_output_search();
+                false
+            }
+            Action::ToggleOptionPreview => {
+                self.ui_state.options_grid_preview_visible = !self.ui_state.options_grid_preview_visible;
+                false
             }
         }
     }
 
-    /// Navigate to the field to the right of the current one
-    fn navigate_field_right(&mut self) {
-        // Navigate right through different UI sections: Templates → Method → URL Container
-        match &self.ui_state.selected_field {
-            SelectedField::Url(UrlField::Method) => {
-                // From Method, go to the appropriate field based on active tab
-                match self.ui_state.active_tab {
-                    Tab::Url => {
-                        self.ui_state.selected_field = SelectedField::Url(UrlField::Url);
-                    }
-                    Tab::Headers => {
-                        self.ui_state.selected_field = SelectedField::Headers(0);
-                    }
+    /// The highest `output_scroll_offset` that still shows a full final
+    /// page, so scrolling can't go past the end of the output panel's text
+    fn max_output_scroll_offset(&self) -> u16 {
+        let visible_rows = 10; // Approximate number of visible rows
+        (self.ui_state.output_line_count as u16).saturating_sub(visible_rows)
+    }
+
+    /// Scroll the output panel down by `delta` lines, clamped so the view
+    /// never scrolls past the last page
+    fn scroll_output_by(&mut self, delta: u16) {
+        let max_offset = self.max_output_scroll_offset();
+        self.ui_state.output_scroll_offset = self.ui_state.output_scroll_offset.saturating_add(delta).min(max_offset);
+    }
+
+    /// Move `output_match_selected` by `delta` (wrapping) and scroll so
+    /// that match's line is at the top of the viewport
+    fn jump_to_output_match(&mut self, delta: i32) {
+        let match_count = self.ui_state.output_match_lines.len();
+        if match_count == 0 {
+            return;
+        }
+
+        let current = self.ui_state.output_match_selected as i32;
+        let next = (current + delta).rem_euclid(match_count as i32) as usize;
+        self.ui_state.output_match_selected = next;
+        self.ui_state.output_scroll_offset = self.ui_state.output_match_lines[next].min(self.max_output_scroll_offset() as usize) as u16;
+    }
+
+    /// Handle a key press while typing an incremental search query over
+    /// the output panel, mirroring `handle_filtering_templates_key`
+    fn handle_searching_output_key(&mut self, key_event: &crossterm::event::KeyEvent) -> bool {
+        use crossterm::event::KeyCode;
+
+        match key_event.code {
+            KeyCode::Esc => {
+                self.ui_state.output_search_query.clear();
+                self.ui_state.output_match_lines.clear();
+                self.ui_state.output_match_selected = 0;
+                self.state = AppState::Normal;
+                false
+            }
+            KeyCode::Enter => {
+                self.state = AppState::Normal;
+                false
+            }
+            KeyCode::Char(c) => {
+                self.ui_state.output_search_query.push(c);
+                self.refresh_output_search();
+                false
+            }
+            KeyCode::Backspace => {
+                self.ui_state.output_search_query.pop();
+                self.refresh_output_search();
+                false
+            }
+            _ => false,
+        }
+    }
+
+    /// Recompute `output_line_count` and `output_match_lines` against the
+    /// lines the output panel's Formatted view emits (headers/preamble
+    /// plus, when `output_pretty_print` is set, the pretty-printed body,
+    /// narrowed by `output_view_mode`), then jump the scroll to the first
+    /// match. Called whenever the search query, the view mode or the
+    /// displayed output changes. The Raw/JSON/Pretty formats render
+    /// `format_response` text instead, which is not what is searched.
+    fn refresh_output_search(&mut self) {
+        let displayed = self.output.as_deref().map(|output| crate::syntax::display_output(output, self.ui_state.output_pretty_print));
+        let visible: &[String] = match &displayed {
+            Some(displayed) => match (self.ui_state.output_view_mode, displayed.header_end) {
+                (OutputViewMode::All, _) | (OutputViewMode::HeadersOnly, None) => &displayed.lines,
+                (OutputViewMode::HeadersOnly, Some(end)) => &displayed.lines[..=end],
+                (OutputViewMode::BodyOnly, Some(end)) => &displayed.lines[end + 1..],
+                (OutputViewMode::BodyOnly, None) => &[],
+            },
+            None => &[],
+        };
+        self.ui_state.output_line_count = visible.len();
+
+        let query = self.ui_state.output_search_query.to_lowercase();
+        self.ui_state.output_match_lines = match &displayed {
+            Some(_) if !query.is_empty() => visible
+                .iter()
+                .enumerate()
+                .filter(|(_, line)| line.to_lowercase().contains(&query))
+                .map(|(idx, _)| idx)
+                .collect(),
+            _ => Vec::new(),
+        };
+        self.ui_state.output_match_selected = 0;
+
+        if let Some(&first) = self.ui_state.output_match_lines.first() {
+            self.ui_state.output_scroll_offset = first.min(self.max_output_scroll_offset() as usize) as u16;
+        }
+    }
+
+    /// Open the command palette with an empty query and every candidate
+    /// ranked (an empty query matches everything with a zero score, so
+    /// candidates appear in their natural order until the user types)
+    fn open_command_palette(&mut self) {
+        self.ui_state.palette_query.clear();
+        self.ui_state.palette_selected = 0;
+        self.update_palette_results();
+        self.state = AppState::CommandPalette;
+    }
+
+    /// Re-rank palette candidates against the current query: template
+    /// names, past commands in history, and all known curl flags
+    fn update_palette_results(&mut self) {
+        let query = self.ui_state.palette_query.clone();
+        let mut scored: Vec<(i32, usize, PaletteItem)> = Vec::new();
+
+        for (idx, template) in self.templates.iter().enumerate() {
+            if let Some(matched) = crate::fuzzy::score_subsequence(&query, &template.name) {
+                scored.push((matched.score, template.name.len(), PaletteItem::Template(idx)));
+            }
+        }
+
+        for (idx, command) in self.history.iter().enumerate() {
+            let label = if command.name.is_empty() { &command.url } else { &command.name };
+            if let Some(matched) = crate::fuzzy::score_subsequence(&query, label) {
+                scored.push((matched.score, label.len(), PaletteItem::History(idx)));
+            }
+        }
+
+        let curl_options = crate::command::options::CurlOptions::new();
+        for option in curl_options.all_options() {
+            let label = option.long_flag.as_deref().unwrap_or(&option.flag);
+            if let Some(matched) = crate::fuzzy::score_subsequence(&query, label) {
+                scored.push((matched.score, label.len(), PaletteItem::Option(option.flag.clone())));
+            }
+        }
+
+        // Highest score first, ties broken by shorter candidate length
+        scored.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+
+        self.ui_state.palette_results = scored.into_iter().map(|(_, _, item)| item).collect();
+        self.ui_state.palette_selected = 0;
+    }
+
+    /// Apply the currently highlighted palette item, then return to normal
+    /// mode
+    fn apply_selected_palette_item(&mut self) {
+        let Some(item) = self.ui_state.palette_results.get(self.ui_state.palette_selected).cloned() else {
+            self.state = AppState::Normal;
+            return;
+        };
+
+        match item {
+            PaletteItem::Template(idx) => {
+                self.load_template(idx);
+                if matches!(self.state, AppState::CommandPalette) {
+                    self.state = AppState::Normal;
+                }
+            }
+            PaletteItem::History(idx) => {
+                if let Some(command) = self.history.get(idx) {
+                    self.current_command = command.clone();
+                }
+                self.state = AppState::Normal;
+            }
+            PaletteItem::Option(flag) => {
+                let curl_options = crate::command::options::CurlOptions::new();
+                if let Some(option_def) = curl_options.get_option(&flag) {
+                    let already_exists = !option_def.repeatable
+                        && self.current_command.options.iter().any(|o| o.flag == flag);
+                    if !already_exists {
+                        let value = if option_def.takes_value { Some(String::new()) } else { None };
+                        self.current_command.add_option(flag, value);
+                    }
+                }
+                self.state = AppState::Normal;
+            }
+        }
+    }
+
+    /// Handle key events while the command palette is open
+    fn handle_command_palette_key(&mut self, key_event: &crossterm::event::KeyEvent) -> bool {
+        use crossterm::event::KeyCode;
+
+        match key_event.code {
+            KeyCode::Esc => {
+                self.state = AppState::Normal;
+                false
+            }
+            KeyCode::Enter => {
+                self.apply_selected_palette_item();
+                false
+            }
+            KeyCode::Up => {
+                self.ui_state.palette_selected = self.ui_state.palette_selected.saturating_sub(1);
+                false
+            }
+            KeyCode::Down => {
+                if self.ui_state.palette_selected + 1 < self.ui_state.palette_results.len() {
+                    self.ui_state.palette_selected += 1;
+                }
+                false
+            }
+            KeyCode::Char(c) => {
+                self.ui_state.palette_query.push(c);
+                self.update_palette_results();
+                false
+            }
+            KeyCode::Backspace => {
+                self.ui_state.palette_query.pop();
+                self.update_palette_results();
+                false
+            }
+            _ => false,
+        }
+    }
+
+    /// Handle key events while typing the templates tree's fuzzy filter
+    /// query. Each keystroke re-filters immediately; `selected_template` is
+    /// reset to the top row so the selection always points at a visible
+    /// match rather than a row the filter just hid.
+    fn handle_filtering_templates_key(&mut self, key_event: &crossterm::event::KeyEvent) -> bool {
+        use crossterm::event::KeyCode;
+
+        match key_event.code {
+            KeyCode::Esc => {
+                self.ui_state.template_filter_query.clear();
+                self.ui_state.selected_template = Some(0);
+                self.state = AppState::Normal;
+                false
+            }
+            KeyCode::Enter => {
+                self.state = AppState::Normal;
+                false
+            }
+            KeyCode::Char(c) => {
+                self.ui_state.template_filter_query.push(c);
+                self.ui_state.selected_template = Some(0);
+                false
+            }
+            KeyCode::Backspace => {
+                self.ui_state.template_filter_query.pop();
+                self.ui_state.selected_template = Some(0);
+                false
+            }
+            _ => false,
+        }
+    }
+
+    /// Open the picker with an empty query and every candidate ranked (an
+    /// empty query matches everything with a zero score, so candidates
+    /// appear in their natural order until the user types)
+    fn open_picker(&mut self) {
+        self.ui_state.picker_query.clear();
+        self.ui_state.picker_selected = 0;
+        self.update_picker_results();
+        self.state = AppState::Picker;
+    }
+
+    /// Re-rank picker candidates against the current query: standard
+    /// headers, all known curl flags, and saved request templates
+    fn update_picker_results(&mut self) {
+        let query = self.ui_state.picker_query.clone();
+        let previous_top = self.ui_state.picker_results.get(self.ui_state.picker_selected).cloned();
+        let mut scored: Vec<(i32, usize, PickerItem)> = Vec::new();
+
+        for (idx, header) in crate::headers::STANDARD_HEADERS.iter().enumerate() {
+            if let Some(matched) = crate::fuzzy::score_subsequence(&query, header.name) {
+                scored.push((matched.score, header.name.len(), PickerItem::Header(idx)));
+            }
+        }
+
+        let curl_options = crate::command::options::CurlOptions::new();
+        for option in curl_options.all_options() {
+            let label = option.long_flag.as_deref().unwrap_or(&option.flag);
+            if let Some(matched) = crate::fuzzy::score_subsequence(&query, label) {
+                scored.push((matched.score, label.len(), PickerItem::Option(option.flag.clone())));
+            }
+        }
+
+        for (idx, template) in self.templates.iter().enumerate() {
+            if let Some(matched) = crate::fuzzy::score_subsequence(&query, &template.name) {
+                scored.push((matched.score, template.name.len(), PickerItem::SavedRequest(idx)));
+            }
+        }
+
+        // Highest score first, ties broken by shorter candidate length
+        scored.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+
+        self.ui_state.picker_results = scored.into_iter().map(|(_, _, item)| item).collect();
+        self.ui_state.picker_selected = 0;
+        // The cache is keyed by index, so drop it when a new query put a
+        // different item at the highlighted row
+        if self.ui_state.picker_results.first() != previous_top.as_ref() {
+            self.ui_state.picker_preview = None;
+        }
+        self.refresh_picker_preview();
+    }
+
+    /// Recompute the cached preview for the currently highlighted picker
+    /// item, if it isn't already cached for that index
+    fn refresh_picker_preview(&mut self) {
+        let index = self.ui_state.picker_selected;
+        if self.ui_state.picker_preview.as_ref().is_some_and(|preview| preview.for_index == index) {
+            return;
+        }
+
+        self.ui_state.picker_preview = self.ui_state.picker_results.get(index).map(|item| PickerPreview {
+            for_index: index,
+            lines: render_picker_item_preview(item, &self.templates, &self.current_command.options),
+        });
+    }
+
+    /// Apply the currently highlighted picker item, then return to normal
+    /// mode
+    fn apply_selected_picker_item(&mut self) {
+        let Some(item) = self.ui_state.picker_results.get(self.ui_state.picker_selected).cloned() else {
+            self.state = AppState::Normal;
+            return;
+        };
+
+        match item {
+            PickerItem::Header(idx) => {
+                if let Some(header) = crate::headers::STANDARD_HEADERS.get(idx) {
+                    let new_index = self.current_command.headers.len();
+                    self.current_command.add_header(header.name.to_string(), String::new());
+                    self.ui_state.active_tab = Tab::Headers;
+                    self.ui_state.selected_field = SelectedField::Headers(new_index);
+                    self.ui_state.edit_buffer.clear();
+                    self.state = AppState::Editing(EditField::HeaderValue(new_index));
+                    return;
+                }
+            }
+            PickerItem::Option(flag) => {
+                let curl_options = crate::command::options::CurlOptions::new();
+                if let Some(option_def) = curl_options.get_option(&flag) {
+                    let already_exists = !option_def.repeatable
+                        && self.current_command.options.iter().any(|o| o.flag == flag);
+                    if !already_exists {
+                        let value = if option_def.takes_value { Some(String::new()) } else { None };
+                        self.current_command.add_option(flag, value);
+                    }
+                }
+            }
+            PickerItem::SavedRequest(idx) => {
+                self.load_template(idx);
+            }
+        }
+
+        self.state = AppState::Normal;
+    }
+
+    /// Handle key events while the picker is open
+    fn handle_picker_key(&mut self, key_event: &crossterm::event::KeyEvent) -> bool {
+        use crossterm::event::KeyCode;
+
+        match key_event.code {
+            KeyCode::Esc => {
+                self.state = AppState::Normal;
+                false
+            }
+            KeyCode::Enter => {
+                self.apply_selected_picker_item();
+                false
+            }
+            KeyCode::Up => {
+                self.ui_state.picker_selected = self.ui_state.picker_selected.saturating_sub(1);
+                self.refresh_picker_preview();
+                false
+            }
+            KeyCode::Down => {
+                if self.ui_state.picker_selected + 1 < self.ui_state.picker_results.len() {
+                    self.ui_state.picker_selected += 1;
+                }
+                self.refresh_picker_preview();
+                false
+            }
+            KeyCode::Char(c) => {
+                self.ui_state.picker_query.push(c);
+                self.update_picker_results();
+                false
+            }
+            KeyCode::Backspace => {
+                self.ui_state.picker_query.pop();
+                self.update_picker_results();
+                false
+            }
+            _ => false,
+        }
+    }
+
+    /// Add a blank header or curl flag to the active tab and open the
+    /// inline completion popup to fill in its key/flag
+    fn start_adding_field(&mut self) {
+        match self.ui_state.active_tab {
+            Tab::Headers => {
+                let new_index = self.current_command.headers.len();
+                self.current_command.add_header(String::new(), String::new());
+                self.ui_state.selected_field = SelectedField::Headers(new_index);
+                self.ui_state.edit_buffer.clear();
+                self.state = AppState::Completing(EditField::HeaderKey(new_index));
+                self.update_completion_results();
+            }
+            Tab::Options => {
+                let new_index = self.current_command.options.len();
+                self.current_command.add_option(String::new(), None);
+                self.ui_state.selected_field = SelectedField::Options(new_index);
+                self.ui_state.edit_buffer.clear();
+                self.state = AppState::Completing(EditField::OptionFlag(new_index));
+                self.update_completion_results();
+            }
+            _ => {}
+        }
+    }
+
+    /// Re-rank completion candidates against the current edit buffer:
+    /// standard headers when completing a header key, standard MIME types
+    /// when completing a `Content-Type` header's value, an option's own
+    /// known values when completing an option's value, or all known curl
+    /// flags (tagged `Auth` for the Authentication category) otherwise
+    fn update_completion_results(&mut self) {
+        let query = self.ui_state.edit_buffer.clone();
+        let completing_header_key = matches!(self.state, AppState::Completing(EditField::HeaderKey(_)));
+        let completing_mime_value = match &self.state {
+            AppState::Completing(EditField::HeaderValue(idx)) => self
+                .current_command
+                .headers
+                .get(*idx)
+                .is_some_and(|header| header.key.eq_ignore_ascii_case("Content-Type")),
+            _ => false,
+        };
+        let completing_option_value = match &self.state {
+            AppState::Completing(EditField::OptionValue(idx)) => self
+                .current_command
+                .options
+                .get(*idx)
+                .and_then(|option| crate::command::options::CurlOptions::new().get_option(&option.flag).cloned())
+                .and_then(|option_def| option_def.known_values()),
+            _ => None,
+        };
+        let mut scored: Vec<(i32, usize, CompletionCandidate)> = Vec::new();
+
+        if completing_header_key {
+            for header in crate::headers::STANDARD_HEADERS {
+                if let Some(matched) = crate::fuzzy::score_subsequence(&query, header.name) {
+                    scored.push((matched.score, header.name.len(), CompletionCandidate {
+                        label: header.name.to_string(),
+                        kind: CompletionKind::Header,
+                        hint: header.description.to_string(),
+                    }));
+                }
+            }
+        } else if completing_mime_value {
+            for mime in crate::mime::STANDARD_MIME_TYPES {
+                if let Some(matched) = crate::fuzzy::score_subsequence(&query, mime.name) {
+                    scored.push((matched.score, mime.name.len(), CompletionCandidate {
+                        label: mime.name.to_string(),
+                        kind: CompletionKind::Mime,
+                        hint: mime.description.to_string(),
+                    }));
+                }
+            }
+        } else if let Some(known_values) = &completing_option_value {
+            for value in known_values {
+                if let Some(matched) = crate::fuzzy::score_subsequence(&query, value) {
+                    scored.push((matched.score, value.len(), CompletionCandidate {
+                        label: value.clone(),
+                        kind: CompletionKind::Value,
+                        hint: String::new(),
+                    }));
+                }
+            }
+        } else {
+            let curl_options = crate::command::options::CurlOptions::new();
+            for option in curl_options.all_options() {
+                let label = option.long_flag.as_deref().unwrap_or(&option.flag);
+                if let Some(matched) = crate::fuzzy::score_subsequence(&query, label) {
+                    let kind = if option.category == crate::command::options::OptionCategory::Authentication {
+                        CompletionKind::Auth
+                    } else {
+                        CompletionKind::Flag
+                    };
+                    scored.push((matched.score, label.len(), CompletionCandidate {
+                        label: label.to_string(),
+                        kind,
+                        hint: option.description.clone(),
+                    }));
+                }
+            }
+        }
+
+        // Highest score first, ties broken by shorter candidate length
+        scored.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+
+        self.ui_state.completion_results = scored.into_iter().map(|(_, _, candidate)| candidate).collect();
+        self.ui_state.completion_selected = 0;
+    }
+
+    /// Commit the highlighted completion candidate (or the raw edit buffer,
+    /// if nothing matches) into the header/option being completed
+    fn commit_completion(&mut self, field: EditField) {
+        let chosen = self
+            .ui_state
+            .completion_results
+            .get(self.ui_state.completion_selected)
+            .map(|candidate| candidate.label.clone())
+            .unwrap_or_else(|| self.ui_state.edit_buffer.clone());
+
+        match field {
+            EditField::HeaderKey(idx) => {
+                if chosen.is_empty() {
+                    if idx < self.current_command.headers.len() {
+                        self.current_command.headers.remove(idx);
+                    }
+                    self.state = AppState::Normal;
+                    return;
+                }
+                let is_content_type = chosen.eq_ignore_ascii_case("Content-Type");
+                if let Some(header) = self.current_command.headers.get_mut(idx) {
+                    header.key = chosen;
+                }
+                self.ui_state.edit_buffer.clear();
+                self.ui_state.edit_cursor = 0;
+                self.ui_state.visual_anchor = None;
+                self.ui_state.edit_mode = if self.vim_mode { EditMode::Normal } else { EditMode::Insert };
+                if is_content_type {
+                    self.state = AppState::Completing(EditField::HeaderValue(idx));
+                    self.update_completion_results();
+                } else {
+                    self.state = AppState::Editing(EditField::HeaderValue(idx));
+                }
+            }
+            EditField::HeaderValue(idx) => {
+                if let Some(header) = self.current_command.headers.get_mut(idx) {
+                    header.value = chosen;
+                }
+                self.state = AppState::Normal;
+            }
+            EditField::OptionFlag(idx) => {
+                if chosen.is_empty() {
+                    if idx < self.current_command.options.len() {
+                        self.current_command.options.remove(idx);
+                    }
+                    self.state = AppState::Normal;
+                    return;
+                }
+
+                let curl_options = crate::command::options::CurlOptions::new();
+                if let Some(option_def) = curl_options.get_option(&chosen) {
+                    let flag = option_def.flag.clone();
+                    let value = if option_def.takes_value { Some(String::new()) } else { None };
+                    if let Some(option) = self.current_command.options.get_mut(idx) {
+                        option.flag = flag;
+                        option.value = value;
+                    }
+                } else if let Some(option) = self.current_command.options.get_mut(idx) {
+                    option.flag = chosen;
+                    option.value = None;
+                }
+                self.state = AppState::Normal;
+            }
+            EditField::OptionValue(idx) => {
+                if let Some(option) = self.current_command.options.get_mut(idx) {
+                    option.value = Some(chosen);
+                }
+                self.state = AppState::Normal;
+            }
+            _ => {
+                self.state = AppState::Normal;
+            }
+        }
+    }
+
+    /// Cancel the inline completion popup, removing the blank
+    /// header/option it was filling in if it was never given a key/flag
+    fn cancel_completion(&mut self, field: &EditField) {
+        match field {
+            EditField::HeaderKey(idx) => {
+                if self.current_command.headers.get(*idx).is_some_and(|header| header.key.is_empty()) {
+                    self.current_command.headers.remove(*idx);
+                }
+            }
+            EditField::OptionFlag(idx) => {
+                if self.current_command.options.get(*idx).is_some_and(|option| option.flag.is_empty()) {
+                    self.current_command.options.remove(*idx);
+                }
+            }
+            _ => {}
+        }
+        self.state = AppState::Normal;
+    }
+
+    /// Handle key events while the inline completion popup is open
+    fn handle_completing_key(&mut self, key_event: &crossterm::event::KeyEvent, field: &EditField) -> bool {
+        use crossterm::event::KeyCode;
+
+        match key_event.code {
+            KeyCode::Esc => {
+                self.cancel_completion(field);
+                false
+            }
+            KeyCode::Enter => {
+                self.commit_completion(field.clone());
+                false
+            }
+            KeyCode::Up => {
+                self.ui_state.completion_selected = self.ui_state.completion_selected.saturating_sub(1);
+                false
+            }
+            KeyCode::Down => {
+                if self.ui_state.completion_selected + 1 < self.ui_state.completion_results.len() {
+                    self.ui_state.completion_selected += 1;
+                }
+                false
+            }
+            KeyCode::Char(c) => {
+                self.ui_state.edit_buffer.push(c);
+                self.update_completion_results();
+                false
+            }
+            KeyCode::Backspace => {
+                self.ui_state.edit_buffer.pop();
+                self.update_completion_results();
+                false
+            }
+            _ => false,
+        }
+    }
+
+    /// Navigate to the field above the current one
+    fn navigate_field_up(&mut self) {
+        // Extract the current field without borrowing
+        let current_field = self.ui_state.selected_field.clone();
+        
+        match current_field {
+            SelectedField::Url(field) => {
+                match field {
+                    UrlField::Url => {
+                        // Already at the top, do nothing
+                    }
+                    UrlField::Method => {
+                        self.ui_state.selected_field = SelectedField::Url(UrlField::Url);
+                    }
+                    UrlField::QueryParam(idx) => {
+                        if idx > 0 {
+                            self.ui_state.selected_field = SelectedField::Url(UrlField::QueryParam(idx - 1));
+                            if idx <= self.ui_state.query_params_scroll_offset {
+                                self.ui_state.query_params_scroll_offset = self.ui_state.query_params_scroll_offset.saturating_sub(1);
+                            }
+                        } else {
+                            self.ui_state.selected_field = SelectedField::Url(UrlField::Method);
+                        }
+                    }
+                }
+            }
+            SelectedField::Headers(idx) => {
+                if idx > 0 {
+                    self.ui_state.selected_field = SelectedField::Headers(idx - 1);
+                    if idx <= self.ui_state.headers_scroll_offset {
+                        self.ui_state.headers_scroll_offset = self.ui_state.headers_scroll_offset.saturating_sub(1);
+                    }
+                }
+            }
+            SelectedField::Body(field) => {
+                match field {
+                    BodyField::Type => {
+                        // Already at the top, do nothing
+                    }
+                    BodyField::Content => {
+                        self.ui_state.selected_field = SelectedField::Body(BodyField::Type);
+                    }
+                }
+            }
+            SelectedField::Options(idx) => {
+                let total_options = self.options_tab_total_len();
+                if total_options > 0 {
+                    // Wrap from the first row to the last, the way helix's
+                    // `Menu` cycles its cursor instead of stopping dead at
+                    // either end
+                    let new_idx = if idx == 0 { total_options - 1 } else { idx - 1 };
+                    self.ui_state.selected_field = SelectedField::Options(new_idx);
+
+                    if new_idx < idx {
+                        if idx <= self.ui_state.options_scroll_offset {
+                            self.ui_state.options_scroll_offset = self.ui_state.options_scroll_offset.saturating_sub(1);
+                        }
+                    } else {
+                        // Wrapped to the end; scroll so the last row is in view
+                        self.ui_state.options_scroll_offset = new_idx;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Total selectable rows in the Options tab: the currently-added
+    /// options plus the command-line-category catalog entries, the same
+    /// combined active+available count `navigate_field_up`/
+    /// `navigate_field_down` cycle the cursor through
+    fn options_tab_total_len(&self) -> usize {
+        let curl_options = crate::command::options::CurlOptions::new();
+        let command_line_options = curl_options.get_options_by_category(&crate::command::options::OptionCategory::CommandLine);
+        self.current_command.options.len() + command_line_options.len()
+    }
+
+    /// Navigate to the field below the current one
+    fn navigate_field_down(&mut self) {
+        // Extract the current field without borrowing
+        let current_field = self.ui_state.selected_field.clone();
+        
+        match current_field {
+            SelectedField::Url(field) => {
+                match field {
+                    UrlField::Url => {
+                        self.ui_state.selected_field = SelectedField::Url(UrlField::Method);
+                    }
+                    UrlField::Method => {
+                        if !self.current_command.query_params.is_empty() {
+                            self.ui_state.selected_field = SelectedField::Url(UrlField::QueryParam(0));
+                        }
+                    }
+                    UrlField::QueryParam(idx) => {
+                        if !self.current_command.query_params.is_empty() && idx < self.current_command.query_params.len() - 1 {
+                            self.ui_state.selected_field = SelectedField::Url(UrlField::QueryParam(idx + 1));
+
+                            let visible_rows = 10; // Approximate number of visible rows
+                            if idx >= self.ui_state.query_params_scroll_offset + visible_rows - 2 {
+                                self.ui_state.query_params_scroll_offset += 1;
+                            }
+                        }
+                    }
+                }
+            }
+            SelectedField::Headers(idx) => {
+                if !self.current_command.headers.is_empty() && idx < self.current_command.headers.len() - 1 {
+                    self.ui_state.selected_field = SelectedField::Headers(idx + 1);
+
+                    let visible_rows = 10; // Approximate number of visible rows
+                    if idx >= self.ui_state.headers_scroll_offset + visible_rows - 2 {
+                        self.ui_state.headers_scroll_offset += 1;
+                    }
+                }
+            }
+            SelectedField::Body(field) => {
+                match field {
+                    BodyField::Type => {
+                        self.ui_state.selected_field = SelectedField::Body(BodyField::Content);
+                    }
+                    BodyField::Content => {
+                        // Already at the bottom, do nothing
+                    }
+                }
+            }
+            SelectedField::Options(idx) => {
+                let total_options = self.options_tab_total_len();
+                if total_options > 0 {
+                    // Wrap from the last row back to the first, mirroring
+                    // `navigate_field_up`'s wrap the other way
+                    let new_idx = if idx + 1 >= total_options { 0 } else { idx + 1 };
+                    self.ui_state.selected_field = SelectedField::Options(new_idx);
+
+                    if new_idx > idx {
+                        let visible_rows = 10; // Approximate number of visible rows
+                        if idx >= self.ui_state.options_scroll_offset + visible_rows - 2 {
+                            self.ui_state.options_scroll_offset += 1;
+                        }
+                    } else {
+                        // Wrapped to the start
+                        self.ui_state.options_scroll_offset = 0;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Navigate to the field to the left of the current one
+    fn navigate_field_left(&mut self) {
+        // Navigate left through different UI sections: Templates ← Method ← URL Container
+        match &self.ui_state.selected_field {
+            SelectedField::Url(UrlField::Url) => {
+                // From URL field, go to Method
+                self.ui_state.selected_field = SelectedField::Url(UrlField::Method);
+            }
+            SelectedField::Url(UrlField::Method) => {
+                // From Method, go to Templates (always select templates, even if empty)
+                self.ui_state.selected_template = Some(0);
+                // Don't set a selected field when templates are focused - templates take precedence
+            }
+            // From any field in the URL container (except URL and Method), go back to Method
+            SelectedField::Headers(_) | SelectedField::Body(_) | SelectedField::Options(_) => {
+                self.ui_state.selected_field = SelectedField::Url(UrlField::Method);
+            }
+            SelectedField::Url(UrlField::QueryParam(_)) => {
+                // From query params, also go back to Method to maintain navigation consistency
+                self.ui_state.selected_field = SelectedField::Url(UrlField::Method);
+            }
+        }
+    }
+
+    /// Navigate to the field to the right of the current one
+    fn navigate_field_right(&mut self) {
+        // Navigate right through different UI sections: Templates → Method → URL Container
+        match &self.ui_state.selected_field {
+            SelectedField::Url(UrlField::Method) => {
+                // From Method, go to the appropriate field based on active tab
+                match self.ui_state.active_tab {
+                    Tab::Url => {
+                        self.ui_state.selected_field = SelectedField::Url(UrlField::Url);
+                    }
+                    Tab::Headers => {
+                        self.ui_state.selected_field = SelectedField::Headers(0);
+                    }
                     Tab::Body => {
                         self.ui_state.selected_field = SelectedField::Body(BodyField::Content);
                     }
@@ -627,6 +1929,25 @@ impl App {
         }
     }
 
+    /// Reformat the request body in place: pretty-print a minified JSON
+    /// payload, or minify a pretty-printed one. Edits the live `TextArea`
+    /// while the body is being edited, or the stored body otherwise.
+    /// Non-JSON content types are left unchanged.
+    fn toggle_body_format(&mut self) {
+        let content_type = crate::syntax::detect_content_type(&self.current_command.headers);
+
+        if matches!(self.state, AppState::Editing(EditField::Body)) {
+            let content = self.ui_state.body_textarea.lines().join("\n");
+            let reformatted = crate::syntax::toggle_format(content_type, &content);
+            self.ui_state.body_textarea = TextArea::from(reformatted.lines().map(|s| s.to_string()).collect::<Vec<_>>());
+            self.ui_state.body_textarea.set_cursor_style(ratatui::style::Style::default().bg(ratatui::style::Color::White).fg(ratatui::style::Color::Black));
+            self.ui_state.body_scroll_offset = 0;
+        } else if let Some(crate::models::command::RequestBody::Raw(content)) = &self.current_command.body {
+            let reformatted = crate::syntax::toggle_format(content_type, content);
+            self.current_command.body = Some(crate::models::command::RequestBody::Raw(reformatted));
+        }
+    }
+
     /// Start editing the current field
     fn start_editing_field(&mut self) -> bool {
         let edit_field = match &self.ui_state.selected_field {
@@ -654,6 +1975,14 @@ impl App {
             SelectedField::Headers(idx) => {
                 if let Some(header) = self.current_command.headers.get(*idx) {
                     self.ui_state.edit_buffer = header.value.clone();
+                    if header.key.eq_ignore_ascii_case("Content-Type") {
+                        // Route into the inline completion popup with MIME
+                        // type candidates instead of plain editing, the same
+                        // way a freehand header key or curl flag completes
+                        self.state = AppState::Completing(EditField::HeaderValue(*idx));
+                        self.update_completion_results();
+                        return false;
+                    }
                     EditField::HeaderValue(*idx)
                 } else {
                     return false;
@@ -681,7 +2010,8 @@ impl App {
                         
                         // Set cursor style to make it more visible
                         self.ui_state.body_textarea.set_cursor_style(ratatui::style::Style::default().bg(ratatui::style::Color::White).fg(ratatui::style::Color::Black));
-                        
+                        self.ui_state.body_scroll_offset = 0;
+
                         EditField::Body
                     }
                 }
@@ -695,6 +2025,15 @@ impl App {
                         if option_def.takes_value {
                             if let Some(value) = &option.value {
                                 self.ui_state.edit_buffer = value.clone();
+                                if option_def.known_values().is_some() {
+                                    // Route into the inline completion popup
+                                    // with the option's known values, the
+                                    // same way a Content-Type header value
+                                    // completes against MIME types
+                                    self.state = AppState::Completing(EditField::OptionValue(*idx));
+                                    self.update_completion_results();
+                                    return false;
+                                }
                                 EditField::OptionValue(*idx)
                             } else {
                                 return false;
@@ -713,6 +2052,15 @@ impl App {
             }
         };
 
+        // Reset modal editing state: vim users land in Normal mode (like
+        // opening a file in vim), non-vim users stay in the always-Insert
+        // behavior they had before vim mode existed
+        if !matches!(edit_field, EditField::Body) {
+            self.ui_state.edit_cursor = self.ui_state.edit_buffer.chars().count();
+            self.ui_state.visual_anchor = None;
+            self.ui_state.edit_mode = if self.vim_mode { EditMode::Normal } else { EditMode::Insert };
+        }
+
         self.state = AppState::Editing(edit_field);
         false
     }
@@ -736,66 +2084,36 @@ impl App {
                     self.state = AppState::Normal;
                     false
                 }
+                KeyCode::F(6) => {
+                    // Reformat the buffer: pretty-print or minify, detected
+                    // from whether it's currently multi-line
+                    self.toggle_body_format();
+                    false
+                }
                 _ => {
                     // Pass all other key events to the TextArea
                     self.ui_state.body_textarea.input(Input::from(key_event.clone()));
+
+                    // Keep the cursor's line within the viewport, matching
+                    // the approximate visible-rows estimate used for the
+                    // options tab's scroll offset
+                    let (cursor_row, _) = self.ui_state.body_textarea.cursor();
+                    let visible_rows = 10;
+                    if cursor_row < self.ui_state.body_scroll_offset {
+                        self.ui_state.body_scroll_offset = cursor_row;
+                    } else if cursor_row >= self.ui_state.body_scroll_offset + visible_rows {
+                        self.ui_state.body_scroll_offset = cursor_row + 1 - visible_rows;
+                    }
                     false
                 }
             }
+        } else if self.vim_mode {
+            self.handle_modal_editing_key(key_event, field)
         } else {
             // Handle other fields with simple edit buffer
             match key_event.code {
                 KeyCode::Enter => {
-                    // Save the edited value
-                    match field {
-                        EditField::Url => {
-                            self.current_command.url = self.ui_state.edit_buffer.clone();
-                        }
-                        EditField::Method => {
-                            // Parse method from string
-                            let method_str = self.ui_state.edit_buffer.to_uppercase();
-                            let method = match method_str.as_str() {
-                                "GET" => crate::models::command::HttpMethod::GET,
-                                "POST" => crate::models::command::HttpMethod::POST,
-                                "PUT" => crate::models::command::HttpMethod::PUT,
-                                "DELETE" => crate::models::command::HttpMethod::DELETE,
-                                "PATCH" => crate::models::command::HttpMethod::PATCH,
-                                "HEAD" => crate::models::command::HttpMethod::HEAD,
-                                "OPTIONS" => crate::models::command::HttpMethod::OPTIONS,
-                                _ => crate::models::command::HttpMethod::GET,
-                            };
-                            self.current_command.method = Some(method);
-                        }
-                        EditField::HeaderKey(idx) => {
-                            if let Some(header) = self.current_command.headers.get_mut(*idx) {
-                                header.key = self.ui_state.edit_buffer.clone();
-                            }
-                        }
-                        EditField::HeaderValue(idx) => {
-                            if let Some(header) = self.current_command.headers.get_mut(*idx) {
-                                header.value = self.ui_state.edit_buffer.clone();
-                            }
-                        }
-                        EditField::QueryParamKey(idx) => {
-                            if let Some(param) = self.current_command.query_params.get_mut(*idx) {
-                                param.key = self.ui_state.edit_buffer.clone();
-                            }
-                        }
-                        EditField::QueryParamValue(idx) => {
-                            if let Some(param) = self.current_command.query_params.get_mut(*idx) {
-                                param.value = self.ui_state.edit_buffer.clone();
-                            }
-                        }
-                        EditField::Body => {
-                            // This case is handled above
-                        }
-                        EditField::OptionValue(idx) => {
-                            if let Some(option) = self.current_command.options.get_mut(*idx) {
-                                option.value = Some(self.ui_state.edit_buffer.clone());
-                            }
-                        }
-                    }
-                    self.state = AppState::Normal;
+                    self.commit_edit_buffer(field);
                     false
                 }
                 KeyCode::Esc => {
@@ -818,6 +2136,293 @@ impl App {
         }
     }
 
+    /// Apply the edit buffer's value to `field`, returning `true` if the
+    /// value was accepted (and the app returned to `AppState::Normal`) or
+    /// `false` if validation failed and editing should continue. Shared
+    /// by the non-modal Enter handler and vim mode's Enter handling.
+    fn commit_edit_buffer(&mut self, field: &EditField) -> bool {
+        match field {
+            EditField::Url => {
+                self.current_command.url = self.ui_state.edit_buffer.clone();
+            }
+            EditField::Method => {
+                // Parse method from string
+                let method_str = self.ui_state.edit_buffer.to_uppercase();
+                let method = match method_str.as_str() {
+                    "GET" => crate::models::command::HttpMethod::GET,
+                    "POST" => crate::models::command::HttpMethod::POST,
+                    "PUT" => crate::models::command::HttpMethod::PUT,
+                    "DELETE" => crate::models::command::HttpMethod::DELETE,
+                    "PATCH" => crate::models::command::HttpMethod::PATCH,
+                    "HEAD" => crate::models::command::HttpMethod::HEAD,
+                    "OPTIONS" => crate::models::command::HttpMethod::OPTIONS,
+                    _ => crate::models::command::HttpMethod::GET,
+                };
+                self.current_command.method = Some(method);
+            }
+            EditField::HeaderKey(idx) => {
+                if let Some(header) = self.current_command.headers.get_mut(*idx) {
+                    header.key = self.ui_state.edit_buffer.clone();
+                }
+            }
+            EditField::HeaderValue(idx) => {
+                if let Some(header) = self.current_command.headers.get_mut(*idx) {
+                    header.value = self.ui_state.edit_buffer.clone();
+                }
+            }
+            EditField::QueryParamKey(idx) => {
+                if let Some(param) = self.current_command.query_params.get_mut(*idx) {
+                    param.key = self.ui_state.edit_buffer.clone();
+                }
+            }
+            EditField::QueryParamValue(idx) => {
+                if let Some(param) = self.current_command.query_params.get_mut(*idx) {
+                    param.value = self.ui_state.edit_buffer.clone();
+                }
+            }
+            EditField::Body => {
+                // This case is handled above
+            }
+            EditField::OptionFlag(_) => {
+                // Only reached via AppState::Completing, handled by commit_completion
+            }
+            EditField::OptionValue(idx) => {
+                let idx = *idx;
+                let candidate = self.ui_state.edit_buffer.clone();
+                let flag = self.current_command.options.get(idx).map(|o| o.flag.clone());
+
+                let validation = flag.and_then(|flag| {
+                    let curl_options = crate::command::options::CurlOptions::new();
+                    curl_options.get_option(&flag).map(|def| def.validate_value(&candidate))
+                });
+
+                match validation {
+                    Some(Err(err)) => {
+                        // Invalid value: keep editing and surface the error
+                        self.status_message = Some(err);
+                        return false;
+                    }
+                    _ => {
+                        self.status_message = None;
+                        if let Some(option) = self.current_command.options.get_mut(idx) {
+                            option.value = Some(candidate);
+                        }
+                    }
+                }
+            }
+        }
+        self.state = AppState::Normal;
+        true
+    }
+
+    /// Handle a key event while editing a field's `edit_buffer` in vim's
+    /// modal style (`App::vim_mode` is on): `Normal` mode moves the cursor
+    /// and operates on yank/paste and visual selections, `Insert` mode
+    /// types into the buffer at the cursor like the non-modal path, and
+    /// the visual modes extend a selection from `visual_anchor`.
+    fn handle_modal_editing_key(&mut self, key_event: &crossterm::event::KeyEvent, field: &EditField) -> bool {
+        use crossterm::event::KeyCode;
+
+        match self.ui_state.edit_mode {
+            EditMode::Insert => match key_event.code {
+                KeyCode::Esc => {
+                    self.ui_state.edit_mode = EditMode::Normal;
+                    self.ui_state.edit_cursor = self.ui_state.edit_cursor.min(self.edit_buffer_char_len().saturating_sub(1));
+                    false
+                }
+                KeyCode::Enter => {
+                    self.commit_edit_buffer(field);
+                    false
+                }
+                KeyCode::Char(c) => {
+                    let offset = self.edit_buffer_byte_offset(self.ui_state.edit_cursor);
+                    self.ui_state.edit_buffer.insert(offset, c);
+                    self.ui_state.edit_cursor += 1;
+                    false
+                }
+                KeyCode::Backspace => {
+                    if self.ui_state.edit_cursor > 0 {
+                        let offset = self.edit_buffer_byte_offset(self.ui_state.edit_cursor - 1);
+                        self.ui_state.edit_buffer.remove(offset);
+                        self.ui_state.edit_cursor -= 1;
+                    }
+                    false
+                }
+                _ => false,
+            },
+            EditMode::Normal => match key_event.code {
+                KeyCode::Esc => {
+                    // Cancel editing, matching the non-modal Esc behavior
+                    self.state = AppState::Normal;
+                    false
+                }
+                KeyCode::Enter => {
+                    self.commit_edit_buffer(field);
+                    false
+                }
+                KeyCode::Char('h') => { self.move_edit_cursor(-1); false }
+                KeyCode::Char('l') => { self.move_edit_cursor(1); false }
+                // No-op: field buffers are always single-line
+                KeyCode::Char('j') | KeyCode::Char('k') => false,
+                KeyCode::Char('0') => { self.ui_state.edit_cursor = 0; false }
+                KeyCode::Char('$') => { self.ui_state.edit_cursor = self.edit_buffer_char_len().saturating_sub(1); false }
+                KeyCode::Char('w') => { self.move_edit_cursor_to_next_word(); false }
+                KeyCode::Char('b') => { self.move_edit_cursor_to_prev_word(); false }
+                KeyCode::Char('i') => { self.ui_state.edit_mode = EditMode::Insert; false }
+                KeyCode::Char('a') => {
+                    self.ui_state.edit_cursor = (self.ui_state.edit_cursor + 1).min(self.edit_buffer_char_len());
+                    self.ui_state.edit_mode = EditMode::Insert;
+                    false
+                }
+                KeyCode::Char('o') => {
+                    // No concept of a line below in a single-line buffer;
+                    // append at the end instead
+                    self.ui_state.edit_cursor = self.edit_buffer_char_len();
+                    self.ui_state.edit_mode = EditMode::Insert;
+                    false
+                }
+                KeyCode::Char('v') => {
+                    self.ui_state.visual_anchor = Some(self.ui_state.edit_cursor);
+                    self.ui_state.edit_mode = EditMode::VisualChar;
+                    false
+                }
+                KeyCode::Char('V') => {
+                    self.ui_state.visual_anchor = Some(self.ui_state.edit_cursor);
+                    self.ui_state.edit_mode = EditMode::VisualLine;
+                    false
+                }
+                KeyCode::Char('x') => {
+                    if self.ui_state.edit_cursor < self.edit_buffer_char_len() {
+                        let offset = self.edit_buffer_byte_offset(self.ui_state.edit_cursor);
+                        self.ui_state.edit_buffer.remove(offset);
+                    }
+                    false
+                }
+                KeyCode::Char('p') => { self.paste_register_at_cursor(); false }
+                _ => false,
+            },
+            EditMode::VisualChar | EditMode::VisualLine => match key_event.code {
+                KeyCode::Esc => {
+                    self.ui_state.visual_anchor = None;
+                    self.ui_state.edit_mode = EditMode::Normal;
+                    false
+                }
+                KeyCode::Char('h') => { self.move_edit_cursor(-1); false }
+                KeyCode::Char('l') => { self.move_edit_cursor(1); false }
+                KeyCode::Char('0') => { self.ui_state.edit_cursor = 0; false }
+                KeyCode::Char('$') => { self.ui_state.edit_cursor = self.edit_buffer_char_len().saturating_sub(1); false }
+                KeyCode::Char('w') => { self.move_edit_cursor_to_next_word(); false }
+                KeyCode::Char('b') => { self.move_edit_cursor_to_prev_word(); false }
+                KeyCode::Char('y') => { self.yank_selection(); false }
+                KeyCode::Char('d') | KeyCode::Char('x') => { self.delete_selection(); false }
+                KeyCode::Char('c') => {
+                    self.delete_selection();
+                    self.ui_state.edit_mode = EditMode::Insert;
+                    false
+                }
+                _ => false,
+            },
+        }
+    }
+
+    /// Number of characters (not bytes) in the active `edit_buffer`
+    fn edit_buffer_char_len(&self) -> usize {
+        self.ui_state.edit_buffer.chars().count()
+    }
+
+    /// Byte offset of the `char_idx`-th character in the active
+    /// `edit_buffer`, clamped to the buffer's length
+    fn edit_buffer_byte_offset(&self, char_idx: usize) -> usize {
+        self.ui_state.edit_buffer
+            .char_indices()
+            .nth(char_idx)
+            .map(|(i, _)| i)
+            .unwrap_or(self.ui_state.edit_buffer.len())
+    }
+
+    /// Move the vim-mode cursor by `delta` characters, clamped to the
+    /// buffer's bounds
+    fn move_edit_cursor(&mut self, delta: isize) {
+        let len = self.edit_buffer_char_len();
+        let cursor = self.ui_state.edit_cursor as isize + delta;
+        self.ui_state.edit_cursor = cursor.clamp(0, len.saturating_sub(1) as isize) as usize;
+    }
+
+    /// Move the cursor to the start of the next word (`w`), where a word
+    /// is a run of non-whitespace characters
+    fn move_edit_cursor_to_next_word(&mut self) {
+        let chars: Vec<char> = self.ui_state.edit_buffer.chars().collect();
+        let mut idx = self.ui_state.edit_cursor;
+
+        while idx < chars.len() && !chars[idx].is_whitespace() {
+            idx += 1;
+        }
+        while idx < chars.len() && chars[idx].is_whitespace() {
+            idx += 1;
+        }
+
+        self.ui_state.edit_cursor = idx.min(chars.len().saturating_sub(1));
+    }
+
+    /// Move the cursor to the start of the previous word (`b`)
+    fn move_edit_cursor_to_prev_word(&mut self) {
+        let chars: Vec<char> = self.ui_state.edit_buffer.chars().collect();
+        let mut idx = self.ui_state.edit_cursor;
+
+        while idx > 0 && chars[idx - 1].is_whitespace() {
+            idx -= 1;
+        }
+        while idx > 0 && !chars[idx - 1].is_whitespace() {
+            idx -= 1;
+        }
+
+        self.ui_state.edit_cursor = idx;
+    }
+
+    /// Yank the active visual selection into the shared register and
+    /// return to Normal mode, positioning the cursor at the selection start
+    fn yank_selection(&mut self) {
+        let Some((start, end)) = self.ui_state.visual_selection_range() else {
+            return;
+        };
+        let chars: Vec<char> = self.ui_state.edit_buffer.chars().collect();
+        self.register = chars[start..=end.min(chars.len().saturating_sub(1))].iter().collect();
+        self.ui_state.edit_cursor = start;
+        self.ui_state.visual_anchor = None;
+        self.ui_state.edit_mode = EditMode::Normal;
+    }
+
+    /// Delete the active visual selection, also yanking it into the shared
+    /// register, and return to Normal mode (the caller switches to
+    /// `Insert` afterwards for `c`)
+    fn delete_selection(&mut self) {
+        let Some((start, end)) = self.ui_state.visual_selection_range() else {
+            return;
+        };
+        let chars: Vec<char> = self.ui_state.edit_buffer.chars().collect();
+        let end = end.min(chars.len().saturating_sub(1));
+        self.register = chars[start..=end].iter().collect();
+
+        let start_byte = self.edit_buffer_byte_offset(start);
+        let end_byte = self.edit_buffer_byte_offset(end + 1);
+        self.ui_state.edit_buffer.replace_range(start_byte..end_byte, "");
+
+        self.ui_state.edit_cursor = start;
+        self.ui_state.visual_anchor = None;
+        self.ui_state.edit_mode = EditMode::Normal;
+    }
+
+    /// Paste the shared register into the active `edit_buffer` at the
+    /// cursor, moving the cursor to just after the inserted text
+    fn paste_register_at_cursor(&mut self) {
+        if self.register.is_empty() {
+            return;
+        }
+        let offset = self.edit_buffer_byte_offset(self.ui_state.edit_cursor);
+        self.ui_state.edit_buffer.insert_str(offset, &self.register);
+        self.ui_state.edit_cursor += self.register.chars().count();
+    }
+
     /// Handle key events in editing template name mode
     fn handle_editing_template_name_key(&mut self, key_event: &crossterm::event::KeyEvent) -> bool {
         use crossterm::event::KeyCode;
@@ -831,6 +2436,87 @@ impl App {
         }
     }
 
+    /// Begin reparenting the selected template: seed the edit buffer with
+    /// its current folder path and switch to `EditingTemplateFolder`
+    fn start_reparenting_selected_template(&mut self) {
+        let Some(row_idx) = self.ui_state.selected_template else {
+            return;
+        };
+        let Some(crate::models::collections::TreeRow::Template { index, .. }) =
+            self.visible_tree_rows().into_iter().nth(row_idx)
+        else {
+            return;
+        };
+
+        self.ui_state.edit_buffer = self.templates[index].category.clone().unwrap_or_default();
+        self.state = AppState::EditingTemplateFolder;
+    }
+
+    /// Handle key events while editing a template's folder path
+    fn handle_editing_template_folder_key(&mut self, key_event: &crossterm::event::KeyEvent) -> bool {
+        use crossterm::event::KeyCode;
+
+        match key_event.code {
+            KeyCode::Enter => {
+                let new_category = self.ui_state.edit_buffer.clone();
+                self.reparent_selected_template(new_category);
+                self.ui_state.edit_buffer.clear();
+                self.state = AppState::Normal;
+                false
+            }
+            KeyCode::Esc => {
+                self.ui_state.edit_buffer.clear();
+                self.state = AppState::Normal;
+                false
+            }
+            KeyCode::Char(c) => {
+                self.ui_state.edit_buffer.push(c);
+                false
+            }
+            KeyCode::Backspace => {
+                self.ui_state.edit_buffer.pop();
+                false
+            }
+            _ => false,
+        }
+    }
+
+    /// Begin importing a pasted curl command: clear the edit buffer and
+    /// switch to `ImportingCurlCommand`
+    fn start_importing_curl_command(&mut self) {
+        self.ui_state.edit_buffer.clear();
+        self.state = AppState::ImportingCurlCommand;
+    }
+
+    /// Handle key events while pasting a curl command to import
+    fn handle_importing_curl_command_key(&mut self, key_event: &crossterm::event::KeyEvent) -> bool {
+        use crossterm::event::KeyCode;
+
+        match key_event.code {
+            KeyCode::Enter => {
+                let pasted = self.ui_state.edit_buffer.clone();
+                self.current_command = CurlCommand::from_curl(&pasted);
+                self.ui_state.edit_buffer.clear();
+                self.state = AppState::Normal;
+                false
+            }
+            KeyCode::Esc => {
+                self.ui_state.edit_buffer.clear();
+                self.state = AppState::Normal;
+                false
+            }
+            KeyCode::Char(c) => {
+                self.ui_state.edit_buffer.push(c);
+                false
+            }
+            KeyCode::Backspace => {
+                self.ui_state.edit_buffer.pop();
+                false
+            }
+            _ => false,
+        }
+    }
+
     /// Handle key events in editing environment mode
     fn handle_editing_environment_key(&mut self, key_event: &crossterm::event::KeyEvent) -> bool {
         use crossterm::event::KeyCode;
@@ -885,6 +2571,7 @@ impl App {
             .unwrap_or_else(|| Environment {
                 id: "default".to_string(),
                 name: "Default".to_string(),
+                parent: None,
                 variables: Vec::new(),
                 created_at: chrono::Utc::now(),
                 updated_at: chrono::Utc::now(),
@@ -932,6 +2619,7 @@ impl App {
                     stderr: stderr.clone(),
                     execution_time,
                     error: None,
+                    cancelled: false,
                 };
 
                 // Format output for display
@@ -961,13 +2649,18 @@ impl App {
                     display_output.push_str(&stderr);
                 }
 
-                self.output = Some(display_output);
-                self.execution_result = Some(result);
-
                 // Add to history if successful
                 if output.status.success() {
+                    let info = crate::execution::output::OutputParser::parse(&result);
+                    self.response_history.record(crate::execution::history::ResponseRecord::from_response_info(
+                        self.current_command.id.clone(),
+                        &info,
+                    ));
                     self.history.push(self.current_command.clone());
                 }
+
+                self.output = Some(display_output);
+                self.execution_result = Some(result);
             }
             Err(err) => {
                 let execution_time = start_time.elapsed();
@@ -980,12 +2673,20 @@ impl App {
                     stderr: String::new(),
                     execution_time,
                     error: Some(error_msg.clone()),
+                    cancelled: false,
                 };
 
                 self.output = Some(format!("Error: {}", error_msg));
                 self.execution_result = Some(result);
             }
         }
+
+        // Reset the output panel's view for the new response
+        self.ui_state.output_scroll_offset = 0;
+        self.ui_state.output_search_query.clear();
+        self.ui_state.output_match_lines.clear();
+        self.ui_state.output_match_selected = 0;
+        self.refresh_output_search();
     }
 
     /// Get a human-readable error message for curl exit codes
@@ -1095,6 +2796,7 @@ impl App {
             name,
             description: None,
             command: self.current_command.clone(),
+            variables: Vec::new(),
             category: None,
             created_at: chrono::Utc::now(),
             updated_at: chrono::Utc::now(),
@@ -1103,14 +2805,98 @@ impl App {
         self.templates.push(template);
     }
 
-    /// Load a template
+    /// Export the current command as a Hurl entry, writing it to a
+    /// `<command name>.hurl` file in the working directory so it can be
+    /// committed alongside a Hurl test suite and run in CI
+    pub fn export_to_hurl(&mut self) {
+        let hurl = crate::command::HurlExporter::export(&self.current_command);
+        let file_name = format!("{}.hurl", sanitize_file_name(&self.current_command.name));
+
+        match std::fs::write(&file_name, hurl) {
+            Ok(()) => self.status_message = Some(format!("Exported to {}", file_name)),
+            Err(err) => self.status_message = Some(format!("Failed to export Hurl file: {}", err)),
+        }
+    }
+
+    /// Load a template, resolving its variables against the current
+    /// environment. If any variable has neither an environment value nor a
+    /// declared default, prompt the user to fill them in before the
+    /// template populates `CommandBuilder`.
     pub fn load_template(&mut self, index: usize) {
-        if let Some(template) = self.templates.get(index) {
-            self.current_command = template.command.clone();
+        let Some(template) = self.templates.get(index) else {
+            return;
+        };
+
+        let environment = self.environments.get(&self.current_environment).cloned().unwrap_or_else(|| {
+            Environment::new(self.current_environment.clone())
+        });
+
+        let unbound = template.unbound_variables(&environment);
+        if unbound.is_empty() {
+            self.current_command = template.resolve(&environment, &HashMap::new());
+        } else {
+            self.ui_state.template_variable_prompt = Some(TemplateVariablePrompt {
+                template_index: index,
+                pending: unbound.into_iter().cloned().collect(),
+                current_index: 0,
+                values: HashMap::new(),
+            });
+            self.ui_state.edit_buffer.clear();
+            self.state = AppState::FillingTemplateVariables;
+        }
+    }
+
+    /// Handle key events while prompting for a template's unbound variables
+    fn handle_template_variable_prompt_key(&mut self, key_event: &crossterm::event::KeyEvent) -> bool {
+        use crossterm::event::KeyCode;
+
+        match key_event.code {
+            KeyCode::Enter => {
+                let Some(prompt) = &mut self.ui_state.template_variable_prompt else {
+                    self.state = AppState::Normal;
+                    return false;
+                };
+
+                if let Some(var) = prompt.pending.get(prompt.current_index) {
+                    prompt.values.insert(var.key.clone(), self.ui_state.edit_buffer.clone());
+                }
+                prompt.current_index += 1;
+                self.ui_state.edit_buffer.clear();
+
+                if prompt.current_index >= prompt.pending.len() {
+                    let template_index = prompt.template_index;
+                    let values = prompt.values.clone();
+                    self.ui_state.template_variable_prompt = None;
+                    self.state = AppState::Normal;
+
+                    if let Some(template) = self.templates.get(template_index) {
+                        let environment = self.environments.get(&self.current_environment).cloned().unwrap_or_else(|| {
+                            Environment::new(self.current_environment.clone())
+                        });
+                        self.current_command = template.resolve(&environment, &values);
+                    }
+                }
+                false
+            }
+            KeyCode::Esc => {
+                self.ui_state.template_variable_prompt = None;
+                self.ui_state.edit_buffer.clear();
+                self.state = AppState::Normal;
+                false
+            }
+            KeyCode::Char(c) => {
+                self.ui_state.edit_buffer.push(c);
+                false
+            }
+            KeyCode::Backspace => {
+                self.ui_state.edit_buffer.pop();
+                false
+            }
+            _ => false,
         }
     }
 
-    /// Navigate to the template above the current one
+    /// Navigate to the collections tree row above the current one
     fn navigate_template_up(&mut self) {
         if let Some(current_idx) = self.ui_state.selected_template {
             if current_idx > 0 {
@@ -1119,15 +2905,75 @@ impl App {
         }
     }
 
-    /// Navigate to the template below the current one
+    /// Navigate to the collections tree row below the current one
     fn navigate_template_down(&mut self) {
+        let row_count = self.visible_tree_rows().len();
         if let Some(current_idx) = self.ui_state.selected_template {
-            if current_idx < self.templates.len().saturating_sub(1) {
+            if current_idx < row_count.saturating_sub(1) {
                 self.ui_state.selected_template = Some(current_idx + 1);
             }
         }
     }
 
+    /// Build the collections tree from the current templates and flatten
+    /// it into the rows visible given which folders are expanded, or, while
+    /// `template_filter_query` is non-empty, into the rows matching it
+    /// instead
+    fn visible_tree_rows(&self) -> Vec<crate::models::collections::TreeRow> {
+        let tree = crate::models::collections::CollectionTree::build(&self.templates);
+        if self.ui_state.template_filter_query.is_empty() {
+            tree.visible_rows(&self.ui_state.expanded_folders)
+        } else {
+            tree.filtered_rows(&self.templates, &self.ui_state.template_filter_query, &self.ui_state.expanded_folders)
+        }
+    }
+
+    /// Activate the currently selected collections tree row: toggle a
+    /// folder's expansion, or load a template and return focus to the URL
+    /// field
+    fn activate_selected_tree_row(&mut self) {
+        let Some(row_idx) = self.ui_state.selected_template else {
+            return;
+        };
+        let Some(row) = self.visible_tree_rows().into_iter().nth(row_idx) else {
+            return;
+        };
+
+        match row {
+            crate::models::collections::TreeRow::Folder { path, .. } => {
+                if !self.ui_state.expanded_folders.remove(&path) {
+                    self.ui_state.expanded_folders.insert(path);
+                }
+            }
+            crate::models::collections::TreeRow::Template { index, .. } => {
+                self.load_template(index);
+                self.ui_state.selected_template = None;
+                self.ui_state.selected_field = SelectedField::Url(UrlField::Url);
+            }
+        }
+    }
+
+    /// Move the currently selected template into a different folder by
+    /// rewriting its category path
+    fn reparent_selected_template(&mut self, new_category: String) {
+        let Some(row_idx) = self.ui_state.selected_template else {
+            return;
+        };
+        let Some(crate::models::collections::TreeRow::Template { index, .. }) =
+            self.visible_tree_rows().into_iter().nth(row_idx)
+        else {
+            return;
+        };
+
+        if let Some(template) = self.templates.get_mut(index) {
+            if new_category.trim().is_empty() {
+                template.category = None;
+            } else {
+                template.set_category(new_category);
+            }
+        }
+    }
+
     /// Open the method dropdown
     fn open_method_dropdown(&mut self) {
         // Set the current method index in the dropdown
@@ -1195,7 +3041,10 @@ impl App {
     }
 
     /// Parse command arguments properly respecting quotes
-    fn parse_command_args(command: &str) -> Vec<String> {
+    pub(crate) fn parse_command_args(command: &str) -> Vec<String> {
+        // Strip every carriage return so CRLF line endings in pasted
+        // Windows snippets tokenize the same as Unix ones
+        let command = command.replace('\r', "");
         let mut args = Vec::new();
         let mut current_arg = String::new();
         let mut in_single_quote = false;
@@ -1222,7 +3071,30 @@ impl App {
                         in_double_quote = true;
                     }
                 }
-                ' ' | '\t' if !in_single_quote && !in_double_quote => {
+                '\\' if !in_single_quote && !in_double_quote => {
+                    // Unquoted backslash: a backslash-newline is a line
+                    // continuation (both discarded); otherwise the next
+                    // character is taken literally, which is how the `'\''`
+                    // idiom `shell_quote` emits yields a single quote
+                    match chars.next() {
+                        Some('\n') => {}
+                        Some(next_ch) => current_arg.push(next_ch),
+                        None => current_arg.push(ch),
+                    }
+                }
+                '#' if !in_single_quote && !in_double_quote && current_arg.is_empty() => {
+                    // Unquoted comment at a word boundary (POSIX shells only
+                    // treat a `#` as a comment start there, not mid-word, so
+                    // e.g. `https://example.com#frag` keeps its fragment):
+                    // discard through end of line
+                    while let Some(&next_ch) = chars.peek() {
+                        if next_ch == '\n' {
+                            break;
+                        }
+                        chars.next();
+                    }
+                }
+                ' ' | '\t' | '\n' if !in_single_quote && !in_double_quote => {
                     // Whitespace outside quotes - end current argument
                     if !current_arg.is_empty() {
                         args.push(current_arg.clone());
@@ -1230,7 +3102,7 @@ impl App {
                     }
                     // Skip additional whitespace
                     while let Some(&next_ch) = chars.peek() {
-                        if next_ch == ' ' || next_ch == '\t' {
+                        if next_ch == ' ' || next_ch == '\t' || next_ch == '\n' {
                             chars.next();
                         } else {
                             break;
@@ -1311,9 +3183,10 @@ impl App {
             // Get the option definition
             let option_def = &sorted_command_line_options[cmd_option_idx];
             
-            // Check if this option is already in the current command
-            let already_exists = self.current_command.options.iter()
-                .any(|o| o.flag == option_def.flag);
+            // Check if this option is already in the current command; repeatable
+            // options (e.g. --resolve) may be added more than once
+            let already_exists = !option_def.repeatable
+                && self.current_command.options.iter().any(|o| o.flag == option_def.flag);
             
             // If not already added, add it to the current command
             if !already_exists {
@@ -1349,6 +3222,14 @@ impl App {
     }
 }
 
+/// Turn a display name into a safe file name by replacing anything but
+/// alphanumerics, `-`, and `_` with an underscore
+fn sanitize_file_name(name: &str) -> String {
+    name.chars()
+        .map(|c| if c.is_alphanumeric() || c == '-' || c == '_' { c } else { '_' })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1391,4 +3272,334 @@ mod tests {
             "Content-Type: application/json"
         ]);
     }
+
+    #[test]
+    fn test_parse_command_args_handles_backslash_line_continuations() {
+        let multi_line = "curl \\\n  -X POST \\\n  -H \"Content-Type: application/json\" \\\n  -d '{\"key\": \"value\"}' \\\n  https://example.com";
+        let single_line = "curl -X POST -H \"Content-Type: application/json\" -d '{\"key\": \"value\"}' https://example.com";
+
+        assert_eq!(App::parse_command_args(multi_line), App::parse_command_args(single_line));
+    }
+
+    #[test]
+    fn test_parse_command_args_strips_crlf_continuations() {
+        let crlf = "curl \\\r\n  -X POST \\\r\n  https://example.com";
+        assert_eq!(App::parse_command_args(crlf), vec!["curl", "-X", "POST", "https://example.com"]);
+    }
+
+    #[test]
+    fn test_parse_command_args_drops_unquoted_comments() {
+        let args = App::parse_command_args("curl https://example.com # fetch the homepage");
+        assert_eq!(args, vec!["curl", "https://example.com"]);
+
+        // A '#' inside quotes is literal, not a comment
+        let args = App::parse_command_args("curl -d '#not-a-comment' https://example.com");
+        assert_eq!(args, vec!["curl", "-d", "#not-a-comment", "https://example.com"]);
+
+        // A '#' mid-word (e.g. a URL fragment) only starts a comment at a
+        // word boundary, matching POSIX shells
+        let args = App::parse_command_args("curl https://example.com#frag");
+        assert_eq!(args, vec!["curl", "https://example.com#frag"]);
+    }
+
+    #[test]
+    fn test_palette_ranks_template_match_above_unrelated_option() {
+        let mut app = App::new();
+        app.templates.push(CommandTemplate::new("Location Probe".to_string(), CurlCommand::default()));
+        app.ui_state.palette_query = "locprobe".to_string();
+
+        app.update_palette_results();
+
+        assert!(!app.ui_state.palette_results.is_empty());
+        assert!(matches!(app.ui_state.palette_results[0], PaletteItem::Template(_)));
+    }
+
+    #[test]
+    fn test_picker_ranks_exact_header_match_first() {
+        let mut app = App::new();
+        app.ui_state.picker_query = "content-type".to_string();
+
+        app.update_picker_results();
+
+        assert!(!app.ui_state.picker_results.is_empty());
+        assert!(matches!(app.ui_state.picker_results[0], PickerItem::Header(_)));
+    }
+
+    #[test]
+    fn test_picker_selecting_header_inserts_it_and_starts_editing_value() {
+        let mut app = App::new();
+        app.ui_state.picker_query = "content-type".to_string();
+        app.update_picker_results();
+        app.state = AppState::Picker;
+
+        app.apply_selected_picker_item();
+
+        let header = app.current_command.headers.last().expect("header was inserted");
+        assert_eq!(header.key, "Content-Type");
+        assert!(matches!(app.state, AppState::Editing(EditField::HeaderValue(_))));
+    }
+
+    #[test]
+    fn test_picker_preview_is_cached_until_selection_changes() {
+        let mut app = App::new();
+        app.update_picker_results();
+        let first = app.ui_state.picker_preview.clone();
+
+        // Re-running the preview refresh for the same selection should be a
+        // no-op rather than recomputing
+        app.refresh_picker_preview();
+        assert_eq!(app.ui_state.picker_preview, first);
+
+        app.ui_state.picker_selected += 1;
+        app.refresh_picker_preview();
+        assert_ne!(app.ui_state.picker_preview, first);
+    }
+
+    #[test]
+    fn test_option_picker_preview_shows_whether_it_takes_a_value() {
+        let mut app = App::new();
+        app.ui_state.picker_query = "bearer".to_string();
+        app.update_picker_results();
+
+        let preview = app.ui_state.picker_preview.as_ref().expect("preview computed");
+        assert!(preview.lines.iter().any(|line| line.starts_with("Takes a value:")));
+    }
+
+    #[test]
+    fn test_adding_header_field_opens_completion_with_all_headers() {
+        let mut app = App::new();
+        app.ui_state.active_tab = Tab::Headers;
+
+        app.start_adding_field();
+
+        assert!(matches!(app.state, AppState::Completing(EditField::HeaderKey(_))));
+        assert!(!app.ui_state.completion_results.is_empty());
+        assert!(app.ui_state.completion_results.iter().all(|c| c.kind == CompletionKind::Header));
+    }
+
+    #[test]
+    fn test_completion_commits_header_key_and_starts_editing_value() {
+        let mut app = App::new();
+        app.ui_state.active_tab = Tab::Headers;
+        app.start_adding_field();
+        app.ui_state.edit_buffer = "accept".to_string();
+        app.update_completion_results();
+
+        app.commit_completion(EditField::HeaderKey(0));
+
+        let header = app.current_command.headers.first().expect("header was added");
+        assert_eq!(header.key, "Accept");
+        assert!(matches!(app.state, AppState::Editing(EditField::HeaderValue(0))));
+    }
+
+    #[test]
+    fn test_completion_commits_content_type_header_key_and_opens_mime_completion() {
+        let mut app = App::new();
+        app.ui_state.active_tab = Tab::Headers;
+        app.start_adding_field();
+        app.ui_state.edit_buffer = "content-type".to_string();
+        app.update_completion_results();
+
+        app.commit_completion(EditField::HeaderKey(0));
+
+        let header = app.current_command.headers.first().expect("header was added");
+        assert_eq!(header.key, "Content-Type");
+        assert!(matches!(app.state, AppState::Completing(EditField::HeaderValue(0))));
+        assert!(!app.ui_state.completion_results.is_empty());
+        assert!(app.ui_state.completion_results.iter().all(|c| c.kind == CompletionKind::Mime));
+    }
+
+    #[test]
+    fn test_selecting_content_type_header_value_opens_mime_completion() {
+        let mut app = App::new();
+        app.current_command.add_header("Content-Type".to_string(), String::new());
+        app.ui_state.selected_field = SelectedField::Headers(0);
+
+        app.start_editing_field();
+
+        assert!(matches!(app.state, AppState::Completing(EditField::HeaderValue(0))));
+        assert!(app.ui_state.completion_results.iter().any(|c| c.label == "application/json"));
+    }
+
+    #[test]
+    fn test_selecting_other_header_value_still_uses_plain_editing() {
+        let mut app = App::new();
+        app.current_command.add_header("Accept".to_string(), "text/plain".to_string());
+        app.ui_state.selected_field = SelectedField::Headers(0);
+
+        app.start_editing_field();
+
+        assert!(matches!(app.state, AppState::Editing(EditField::HeaderValue(0))));
+        assert_eq!(app.ui_state.edit_buffer, "text/plain");
+    }
+
+    #[test]
+    fn test_commit_mime_completion_sets_header_value() {
+        let mut app = App::new();
+        app.current_command.add_header("Content-Type".to_string(), String::new());
+        app.ui_state.selected_field = SelectedField::Headers(0);
+        app.start_editing_field();
+        app.ui_state.edit_buffer = "json".to_string();
+        app.update_completion_results();
+
+        app.commit_completion(EditField::HeaderValue(0));
+
+        let header = app.current_command.headers.first().expect("header exists");
+        assert_eq!(header.value, "application/json");
+        assert!(matches!(app.state, AppState::Normal));
+    }
+
+    #[test]
+    fn test_completion_commits_option_flag_tagged_as_auth() {
+        let mut app = App::new();
+        app.ui_state.active_tab = Tab::Options;
+        app.start_adding_field();
+        app.ui_state.edit_buffer = "bearer".to_string();
+        app.update_completion_results();
+
+        assert!(app.ui_state.completion_results.iter().any(|c| c.kind == CompletionKind::Auth));
+
+        app.commit_completion(EditField::OptionFlag(0));
+
+        let option = app.current_command.options.first().expect("option was added");
+        assert!(!option.flag.is_empty());
+        assert!(matches!(app.state, AppState::Normal));
+    }
+
+    #[test]
+    fn test_cancel_completion_removes_blank_header() {
+        let mut app = App::new();
+        app.ui_state.active_tab = Tab::Headers;
+        app.start_adding_field();
+        assert_eq!(app.current_command.headers.len(), 1);
+
+        app.cancel_completion(&EditField::HeaderKey(0));
+
+        assert!(app.current_command.headers.is_empty());
+        assert!(matches!(app.state, AppState::Normal));
+    }
+
+    fn key(c: char) -> crossterm::event::KeyEvent {
+        crossterm::event::KeyEvent::new(crossterm::event::KeyCode::Char(c), crossterm::event::KeyModifiers::NONE)
+    }
+
+    #[test]
+    fn test_vim_mode_normal_motions_move_cursor() {
+        let mut app = App::new();
+        app.vim_mode = true;
+        app.ui_state.edit_buffer = "hello world".to_string();
+        app.ui_state.edit_cursor = 0;
+        app.ui_state.edit_mode = EditMode::Normal;
+
+        app.handle_modal_editing_key(&key('w'), &EditField::Url);
+        assert_eq!(app.ui_state.edit_cursor, 6);
+
+        app.handle_modal_editing_key(&key('$'), &EditField::Url);
+        assert_eq!(app.ui_state.edit_cursor, 10);
+
+        app.handle_modal_editing_key(&key('b'), &EditField::Url);
+        assert_eq!(app.ui_state.edit_cursor, 6);
+
+        app.handle_modal_editing_key(&key('0'), &EditField::Url);
+        assert_eq!(app.ui_state.edit_cursor, 0);
+    }
+
+    #[test]
+    fn test_vim_mode_yank_and_paste_across_fields() {
+        let mut app = App::new();
+        app.vim_mode = true;
+
+        // Select and yank the whole buffer of one field
+        app.ui_state.edit_buffer = "hello world".to_string();
+        app.ui_state.edit_cursor = 0;
+        app.ui_state.edit_mode = EditMode::Normal;
+        app.handle_modal_editing_key(&key('v'), &EditField::HeaderValue(0));
+        app.handle_modal_editing_key(&key('$'), &EditField::HeaderValue(0));
+        app.handle_modal_editing_key(&key('y'), &EditField::HeaderValue(0));
+        assert_eq!(app.register, "hello world");
+        assert!(matches!(app.ui_state.edit_mode, EditMode::Normal));
+
+        let yanked = app.register.clone();
+
+        // Paste the shared register into a different field's buffer
+        app.ui_state.edit_buffer = "x".to_string();
+        app.ui_state.edit_cursor = 0;
+        app.handle_modal_editing_key(&key('p'), &EditField::QueryParamValue(0));
+
+        assert!(app.ui_state.edit_buffer.contains(&yanked));
+    }
+
+    #[test]
+    fn test_vim_mode_insert_types_at_cursor_position() {
+        let mut app = App::new();
+        app.vim_mode = true;
+        app.ui_state.edit_buffer = "ac".to_string();
+        app.ui_state.edit_cursor = 1;
+        app.ui_state.edit_mode = EditMode::Insert;
+
+        app.handle_modal_editing_key(&key('b'), &EditField::Url);
+
+        assert_eq!(app.ui_state.edit_buffer, "abc");
+        assert_eq!(app.ui_state.edit_cursor, 2);
+    }
+
+    #[test]
+    fn test_vim_mode_off_keeps_non_modal_editing_behavior() {
+        let mut app = App::new();
+        assert!(!app.vim_mode);
+        app.ui_state.edit_buffer = "ac".to_string();
+
+        let handled = app.handle_editing_field_key(&key('b'), &EditField::Url);
+
+        // Non-modal editing always appends, ignoring any cursor position
+        assert!(!handled);
+        assert_eq!(app.ui_state.edit_buffer, "acb");
+    }
+
+    fn mouse_click(column: u16, row: u16) -> crossterm::event::MouseEvent {
+        crossterm::event::MouseEvent {
+            kind: crossterm::event::MouseEventKind::Down(crossterm::event::MouseButton::Left),
+            column,
+            row,
+            modifiers: crossterm::event::KeyModifiers::NONE,
+        }
+    }
+
+    #[test]
+    fn test_clicking_a_tab_title_switches_active_tab() {
+        let mut app = App::new();
+        app.ui_state.click_regions.tabs = vec![
+            (Tab::Url, Rect { x: 0, y: 0, width: 10, height: 3 }),
+            (Tab::Headers, Rect { x: 10, y: 0, width: 10, height: 3 }),
+        ];
+
+        app.handle_mouse_event(&mouse_click(15, 1));
+
+        assert!(matches!(app.ui_state.active_tab, Tab::Headers));
+        assert!(matches!(app.ui_state.selected_field, SelectedField::Headers(0)));
+    }
+
+    #[test]
+    fn test_clicking_the_url_box_selects_it_and_double_click_starts_editing() {
+        let mut app = App::new();
+        app.ui_state.click_regions.url = Some(Rect { x: 0, y: 0, width: 20, height: 3 });
+
+        app.handle_mouse_event(&mouse_click(5, 1));
+        assert!(matches!(app.ui_state.selected_field, SelectedField::Url(UrlField::Url)));
+        assert!(matches!(app.state, AppState::Normal));
+
+        app.handle_mouse_event(&mouse_click(5, 1));
+        assert!(matches!(app.state, AppState::Editing(EditField::Url)));
+    }
+
+    #[test]
+    fn test_clicking_a_header_row_selects_it() {
+        let mut app = App::new();
+        app.current_command.add_header("Accept".to_string(), "text/plain".to_string());
+        app.ui_state.click_regions.rows = vec![(SelectedField::Headers(0), Rect { x: 1, y: 1, width: 30, height: 1 })];
+
+        app.handle_mouse_event(&mouse_click(5, 1));
+
+        assert!(matches!(app.ui_state.selected_field, SelectedField::Headers(0)));
+    }
 }
\ No newline at end of file
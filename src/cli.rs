@@ -0,0 +1,212 @@
+use crate::command::{CommandBuilder, CommandValidator, ValidationResult};
+use crate::execution::executor::CommandExecutor;
+use crate::execution::output::{OutputFormat, OutputParser};
+use crate::models::environment::Environment;
+use crate::models::template::CommandTemplate;
+use crate::persistence;
+use clap::{Parser, Subcommand};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// Command-line entry point, parsed via `clap`. Running lazycurl with no
+/// subcommand launches the interactive TUI (the historical behavior);
+/// `run` executes a saved template headlessly, for scripting and CI.
+#[derive(Debug, Parser)]
+#[command(name = "lazycurl", version, about = "A terminal UI for building and running curl commands")]
+pub struct Cli {
+    /// Force a specific theme ("dark" or "light") instead of auto-detecting
+    #[arg(long, global = true)]
+    pub theme: Option<String>,
+
+    #[command(subcommand)]
+    pub command: Option<Commands>,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Commands {
+    /// Run a saved template non-interactively and print the result
+    Run {
+        /// Name of the saved template to run
+        template_name: String,
+
+        /// Print the assembled curl command instead of executing it
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Load the template from this JSON file instead of the persisted
+        /// templates list
+        #[arg(long)]
+        template_file: Option<PathBuf>,
+
+        /// How to print the response: "raw" (default, curl's own stdout),
+        /// "formatted" (status, headers, and body), "pretty" (just the
+        /// content-type-aware pretty-printed body), or "json"
+        #[arg(long, default_value = "raw")]
+        format: String,
+    },
+}
+
+/// Parse the `--format` flag into an `OutputFormat`, falling back to `Raw`
+/// for an unrecognized value
+fn parse_output_format(format: &str) -> OutputFormat {
+    match format.to_lowercase().as_str() {
+        "formatted" => OutputFormat::Formatted,
+        "pretty" => OutputFormat::Pretty,
+        "json" => OutputFormat::Json,
+        _ => OutputFormat::Raw,
+    }
+}
+
+/// Run the `run` subcommand headlessly, returning the process exit code
+pub fn run_headless(template_name: &str, dry_run: bool, template_file: Option<&Path>, format: &str) -> i32 {
+    let format = parse_output_format(format);
+    let template = match template_file {
+        Some(path) => match load_template_file(path) {
+            Ok(template) => template,
+            Err(err) => {
+                eprintln!("Failed to load template file {}: {}", path.display(), err);
+                return 1;
+            }
+        },
+        None => {
+            let state = persistence::load();
+            match state.templates.iter().find(|t| t.name == template_name) {
+                Some(template) => template.clone(),
+                None => {
+                    eprintln!("No saved template named \"{}\"", template_name);
+                    return 1;
+                }
+            }
+        }
+    };
+
+    let state = persistence::load();
+    let environment = state
+        .environments
+        .get(&state.current_environment)
+        .cloned()
+        .unwrap_or_else(|| Environment::new(state.current_environment.clone()));
+
+    let command = template.resolve(&environment, &HashMap::new());
+    let curl_command = CommandBuilder::build(&command, &environment);
+
+    if dry_run {
+        println!("{}", curl_command);
+        return 0;
+    }
+
+    match CommandValidator::validate(&command) {
+        ValidationResult::Error(errors) => {
+            for error in errors {
+                eprintln!("error: {}", error);
+            }
+            1
+        }
+        validation => {
+            for warning in validation.warnings() {
+                eprintln!("warning: {}", warning);
+            }
+            execute_and_report(&curl_command, format)
+        }
+    }
+}
+
+/// Execute `curl_command` via `CommandExecutor` and print its result in the
+/// requested `format`, returning the process exit code
+fn execute_and_report(curl_command: &str, format: OutputFormat) -> i32 {
+    let executor = match CommandExecutor::new() {
+        Ok(executor) => executor,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            return 1;
+        }
+    };
+
+    let runtime = match tokio::runtime::Runtime::new() {
+        Ok(runtime) => runtime,
+        Err(err) => {
+            eprintln!("error: failed to start async runtime: {}", err);
+            return 1;
+        }
+    };
+
+    let result = runtime.block_on(executor.execute(curl_command));
+
+    match format {
+        OutputFormat::Raw => {
+            print!("{}", result.stdout);
+            if !result.stderr.is_empty() {
+                eprint!("{}", result.stderr);
+            }
+        }
+        OutputFormat::Formatted | OutputFormat::Json | OutputFormat::Pretty => {
+            let info = OutputParser::parse(&result);
+            println!("{}", OutputParser::format_response(&info, format));
+        }
+    }
+    if let Some(error) = &result.error {
+        eprintln!("error: {}", error);
+        return 1;
+    }
+
+    match result.exit_code {
+        Some(0) => 0,
+        Some(code) => code,
+        None => 1,
+    }
+}
+
+/// Load a single template from a standalone JSON file, as opposed to the
+/// persisted templates list
+fn load_template_file(path: &Path) -> Result<CommandTemplate, String> {
+    let contents = std::fs::read_to_string(path).map_err(|err| err.to_string())?;
+    serde_json::from_str(&contents).map_err(|err| err.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::CommandFactory;
+
+    #[test]
+    fn test_cli_parses_run_subcommand_with_flags() {
+        let cli = Cli::parse_from(["lazycurl", "run", "my-template", "--dry-run"]);
+        match cli.command {
+            Some(Commands::Run { template_name, dry_run, template_file, format }) => {
+                assert_eq!(template_name, "my-template");
+                assert!(dry_run);
+                assert!(template_file.is_none());
+                assert_eq!(format, "raw");
+            }
+            _ => panic!("expected Run subcommand"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parses_format_flag() {
+        let cli = Cli::parse_from(["lazycurl", "run", "my-template", "--format", "json"]);
+        match cli.command {
+            Some(Commands::Run { format, .. }) => assert_eq!(format, "json"),
+            _ => panic!("expected Run subcommand"),
+        }
+    }
+
+    #[test]
+    fn test_parse_output_format_defaults_to_raw_for_unknown_value() {
+        assert_eq!(parse_output_format("nonsense"), OutputFormat::Raw);
+        assert_eq!(parse_output_format("json"), OutputFormat::Json);
+        assert_eq!(parse_output_format("formatted"), OutputFormat::Formatted);
+        assert_eq!(parse_output_format("pretty"), OutputFormat::Pretty);
+    }
+
+    #[test]
+    fn test_cli_with_no_subcommand_falls_back_to_interactive() {
+        let cli = Cli::parse_from(["lazycurl"]);
+        assert!(cli.command.is_none());
+    }
+
+    #[test]
+    fn test_cli_definition_is_valid() {
+        Cli::command().debug_assert();
+    }
+}
@@ -1,26 +1,114 @@
-use crate::models::command::{CurlCommand, RequestBody};
+use crate::models::command::{ApiKeyLocation, Auth, CurlCommand, FormFieldKind, RequestBody};
 use crate::models::environment::Environment;
-use regex::Regex;
+use chrono::Utc;
+use rand::rngs::OsRng;
+use rand::RngCore;
+
+/// Shell-quote a single argument for safe inclusion in a POSIX shell
+/// command line. Arguments made up only of characters that never need
+/// quoting are left bare; everything else is single-quoted by default,
+/// with embedded single quotes escaped via the standard `'\''` idiom
+/// (close the quote, emit an escaped quote, reopen). This is the inverse
+/// of the quote handling in `App::parse_command_args`.
+pub fn shell_quote(arg: &str) -> String {
+    let is_safe = !arg.is_empty()
+        && arg.chars().all(|c| c.is_ascii_alphanumeric() || "-_./:=@%,+".contains(c));
+    if is_safe {
+        arg.to_string()
+    } else {
+        format!("'{}'", arg.replace('\'', "'\\''"))
+    }
+}
+
+/// Shell a generated command's display text should be valid for. Affects
+/// how [`CommandBuilder::build`]/`build_masked` quote arguments and break
+/// long lines, so the result can be copied straight into that shell.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TargetShell {
+    /// Bash/zsh/sh-style quoting, via [`shell_quote`]
+    Posix,
+    /// Windows PowerShell-style quoting, via [`powershell_quote`]
+    PowerShell,
+}
+
+/// Shell-quote a single argument for safe inclusion in a PowerShell command
+/// line. Arguments made up only of characters that never need quoting are
+/// left bare; everything else is double-quoted, with embedded double
+/// quotes, backticks, and `$` (which PowerShell would otherwise expand as a
+/// variable or subexpression) escaped with a leading backtick.
+pub fn powershell_quote(arg: &str) -> String {
+    let is_safe = !arg.is_empty()
+        && arg.chars().all(|c| c.is_ascii_alphanumeric() || "-_./:=@%,+".contains(c));
+    if is_safe {
+        arg.to_string()
+    } else {
+        let escaped: String = arg
+            .chars()
+            .flat_map(|c| match c {
+                '"' | '`' | '$' => vec!['`', c],
+                other => vec![other],
+            })
+            .collect();
+        format!("\"{}\"", escaped)
+    }
+}
 
 /// Command builder for generating curl commands
 pub struct CommandBuilder;
 
 impl CommandBuilder {
-    /// Build a curl command string from a CurlCommand and Environment
+    /// Parse a raw `curl ...` invocation (e.g. pasted from browser devtools)
+    /// into a [`CurlCommand`]. This is the inverse of [`CommandBuilder::build`];
+    /// flags this parser doesn't recognize are preserved verbatim in
+    /// `extra_args` so round-tripping back through `build` is lossless.
+    pub fn parse(input: &str) -> CurlCommand {
+        crate::command::parser::parse(input)
+    }
+
+    /// Build a curl command string from a CurlCommand and Environment,
+    /// quoted for a POSIX shell
     pub fn build(command: &CurlCommand, environment: &Environment) -> String {
+        Self::build_for_shell(command, environment, TargetShell::Posix)
+    }
+
+    /// Same as `build`, but quoted for `shell` instead of always assuming
+    /// POSIX, so the result can be copied into the shell the user actually
+    /// runs
+    pub fn build_for_shell(command: &CurlCommand, environment: &Environment, shell: TargetShell) -> String {
+        Self::build_with_substitution(command, shell, |s| Self::substitute_env_vars(s, environment))
+    }
+
+    /// Same as `build`, except every `is_secret` environment variable's
+    /// value is replaced with `***` instead of its real value. Intended for
+    /// the generated-command preview so it can be displayed or shared
+    /// without leaking credentials; the execution path always goes through
+    /// `build` instead.
+    pub fn build_masked(command: &CurlCommand, environment: &Environment) -> String {
+        Self::build_masked_for_shell(command, environment, TargetShell::Posix)
+    }
+
+    /// Same as `build_masked`, but quoted for `shell` instead of always
+    /// assuming POSIX
+    pub fn build_masked_for_shell(command: &CurlCommand, environment: &Environment, shell: TargetShell) -> String {
+        Self::build_with_substitution(command, shell, |s| Self::substitute_env_vars_masked(s, environment))
+    }
+
+    /// Shared assembly logic for `build`/`build_masked`: lowers `command`
+    /// into curl args, substituting environment variables via `sub`, then
+    /// quotes and line-wraps the result for `shell`.
+    fn build_with_substitution(command: &CurlCommand, shell: TargetShell, sub: impl Fn(&str) -> String + Copy) -> String {
         let mut args = vec!["curl".to_string()];
-        
+
         // Add enabled options
         for option in &command.options {
             if option.enabled {
                 args.push(option.flag.clone());
                 if let Some(value) = &option.value {
-                    let value_with_env = Self::substitute_env_vars(value, environment);
-                    args.push(value_with_env);
+                    args.push(sub(value));
                 }
             }
         }
-        
+
         // Add method if specified and not GET
         if let Some(method) = &command.method {
             if method.to_string() != "GET" {
@@ -28,23 +116,26 @@ impl CommandBuilder {
                 args.push(method.to_string());
             }
         }
-        
+
+        // Add authentication
+        args.extend(Self::auth_args(&command.auth, sub));
+
         // Add headers
         for header in &command.headers {
             if header.enabled {
-                let header_value = Self::substitute_env_vars(&header.value, environment);
+                let header_value = sub(&header.value);
                 args.push("-H".to_string());
                 args.push(format!("{}: {}", header.key, header_value));
             }
         }
-        
+
         // Add request body if applicable
         if let Some(body) = &command.body {
             match body {
                 RequestBody::Raw(content) => {
                     // Only add -d flag if content is not empty
                     if !content.trim().is_empty() {
-                        let content_with_env = Self::substitute_env_vars(content, environment);
+                        let content_with_env = sub(content);
                         args.push("-d".to_string());
                         args.push(content_with_env);
                     }
@@ -53,8 +144,23 @@ impl CommandBuilder {
                     for item in items {
                         if item.enabled {
                             args.push("-F".to_string());
-                            let value = Self::substitute_env_vars(&item.value, environment);
-                            args.push(format!("{}={}", item.key, value));
+                            let key = sub(&item.key);
+                            let field = match &item.kind {
+                                FormFieldKind::Text(value) => {
+                                    format!("{}={}", key, sub(value))
+                                }
+                                FormFieldKind::File { path, content_type, filename } => {
+                                    let mut spec = format!("@{}", path.display());
+                                    if let Some(content_type) = content_type {
+                                        spec.push_str(&format!(";type={}", content_type));
+                                    }
+                                    if let Some(filename) = filename {
+                                        spec.push_str(&format!(";filename={}", filename));
+                                    }
+                                    format!("{}={}", key, spec)
+                                }
+                            };
+                            args.push(field);
                         }
                     }
                 },
@@ -65,35 +171,77 @@ impl CommandBuilder {
                 RequestBody::None => {}
             }
         }
-        
+
+        // Add any unrecognized flags preserved verbatim from a pasted command
+        for extra_arg in &command.extra_args {
+            args.push(extra_arg.clone());
+        }
+
         // Add URL with environment variable substitution
-        let url_with_query = Self::build_url_with_query(command, environment);
+        let url_with_query = Self::build_url_with_query(command, sub);
         args.push(url_with_query);
-        
+
         // Format the command for display
-        Self::format_curl_command(&args)
+        Self::format_curl_command(&args, shell)
     }
 
-    /// Build URL with query parameters
-    fn build_url_with_query(command: &CurlCommand, environment: &Environment) -> String {
-        let base_url = Self::substitute_env_vars(&command.url, environment);
-        
-        // If no query params or none enabled, return the base URL
-        if command.query_params.is_empty() || !command.query_params.iter().any(|p| p.enabled) {
+    /// Lower an `Auth` scheme to curl args: `-u user:pass` for Basic, a
+    /// bearer `Authorization` header for Bearer/OAuth2, and an `-H` header
+    /// for a header-located API key. A query-param-located API key isn't
+    /// included here since it needs to be folded into the URL's query
+    /// string instead; see `build_url_with_query`/`build_url_raw`.
+    fn auth_args(auth: &Auth, sub: impl Fn(&str) -> String) -> Vec<String> {
+        match auth {
+            Auth::None => Vec::new(),
+            Auth::Basic { username, password } => {
+                vec!["-u".to_string(), format!("{}:{}", sub(username), sub(password))]
+            }
+            Auth::Bearer(token) | Auth::OAuth2 { token, .. } => {
+                vec!["-H".to_string(), format!("Authorization: Bearer {}", sub(token))]
+            }
+            Auth::ApiKey { location: ApiKeyLocation::Header, name, value } => {
+                vec!["-H".to_string(), format!("{}: {}", name, sub(value))]
+            }
+            Auth::ApiKey { location: ApiKeyLocation::QueryParam, .. } => Vec::new(),
+        }
+    }
+
+    /// An `Auth::ApiKey` located in the query string, as a `(name, value)`
+    /// pair to fold into the URL alongside the command's query params
+    fn auth_query_param(auth: &Auth) -> Option<(&str, &str)> {
+        match auth {
+            Auth::ApiKey { location: ApiKeyLocation::QueryParam, name, value } => Some((name, value)),
+            _ => None,
+        }
+    }
+
+    /// Build URL with query parameters. Shared with
+    /// `crate::command::generator`'s non-curl generators, which all render
+    /// the same URL+query regardless of target.
+    pub(crate) fn build_url_with_query(command: &CurlCommand, sub: impl Fn(&str) -> String) -> String {
+        let base_url = sub(&command.url);
+
+        let mut pairs: Vec<(String, String)> = command.query_params
+            .iter()
+            .filter(|p| p.enabled)
+            .map(|p| (p.key.clone(), sub(&p.value)))
+            .collect();
+
+        if let Some((name, value)) = Self::auth_query_param(&command.auth) {
+            pairs.push((name.to_string(), sub(value)));
+        }
+
+        if pairs.is_empty() {
             return base_url;
         }
-        
+
         // Build query string
-        let query_string: String = command.query_params
+        let query_string: String = pairs
             .iter()
-            .filter(|p| p.enabled)
-            .map(|p| {
-                let value = Self::substitute_env_vars(&p.value, environment);
-                format!("{}={}", p.key, urlencoding::encode(&value))
-            })
+            .map(|(key, value)| format!("{}={}", key, urlencoding::encode(value)))
             .collect::<Vec<String>>()
             .join("&");
-        
+
         // Append query string to URL
         if base_url.contains('?') {
             format!("{}&{}", base_url, query_string)
@@ -102,63 +250,301 @@ impl CommandBuilder {
         }
     }
 
-    /// Substitute environment variables in a string
+    /// Substitute `{{...}}` template regions in `input` with environment
+    /// variables or built-in helper output. A single left-to-right scan
+    /// tokenizes each `{{...}}` region once and writes its resolved
+    /// replacement straight into the output buffer without re-scanning it,
+    /// so a variable value that itself contains `{{` is emitted verbatim
+    /// instead of being mistaken for another template region.
+    ///
+    /// A region is either a variable lookup, `{{name}}` or
+    /// `{{name:default}}`, or a helper call, `{{helper arg1 arg2...}}`
+    /// (recognized by its first whitespace-separated word; see
+    /// `resolve_helper_call`). Unknown variables/helpers fall back to the
+    /// `:default` clause if present, or the untouched `{{...}}` literal
+    /// otherwise.
     pub fn substitute_env_vars(input: &str, environment: &Environment) -> String {
-        let mut result = input.to_string();
-        
-        // Regular expression to match {{variable}} patterns
-        let re = Regex::new(r"\{\{([^:}]+)(?::([^}]+))?\}\}").unwrap();
-        
-        while let Some(captures) = re.captures(&result) {
-            let full_match = captures.get(0).unwrap().as_str();
-            let var_name = captures.get(1).unwrap().as_str();
-            let default_value = captures.get(2).map(|m| m.as_str());
-            
-            // Look up variable in environment
-            let replacement = environment.variables
-                .iter()
-                .find(|v| v.key == var_name)
-                .map(|v| v.value.clone())
-                .or_else(|| default_value.map(|s| s.to_string()))
-                .unwrap_or_else(|| full_match.to_string());
-            
-            result = result.replacen(full_match, &replacement, 1);
+        Self::scan_templates(input, |token| Self::resolve_template_token(token, environment))
+    }
+
+    /// Same as `substitute_env_vars`, except any `is_secret` variable's
+    /// value is replaced with `***` instead of its real value. Helper calls
+    /// (`{{uuid}}` and friends) never read a secret, so they're resolved
+    /// exactly as `substitute_env_vars` would. Used to build a command
+    /// preview that's safe to display or share without leaking credentials;
+    /// the execution path always uses `substitute_env_vars` instead.
+    pub fn substitute_env_vars_masked(input: &str, environment: &Environment) -> String {
+        Self::scan_templates(input, |token| {
+            let first_word = token.split_whitespace().next().unwrap_or("");
+            if Self::is_helper_name(first_word) {
+                return Self::resolve_template_token(token, environment);
+            }
+
+            let var_name = token.split_once(':').map(|(name, _)| name).unwrap_or(token);
+            if environment.is_secret(var_name) {
+                "***".to_string()
+            } else {
+                Self::resolve_template_token(token, environment)
+            }
+        })
+    }
+
+    /// Shared left-to-right `{{...}}` scan used by `substitute_env_vars`
+    /// and `substitute_env_vars_masked`: tokenizes each region once and
+    /// writes `resolve`'s result straight into the output buffer without
+    /// re-scanning it, so a replacement that itself contains `{{` is
+    /// emitted verbatim instead of being mistaken for another region.
+    fn scan_templates(input: &str, mut resolve: impl FnMut(&str) -> String) -> String {
+        let mut output = String::with_capacity(input.len());
+        let mut rest = input;
+
+        while let Some(start) = rest.find("{{") {
+            output.push_str(&rest[..start]);
+            let after_open = &rest[start + 2..];
+
+            match after_open.find("}}") {
+                Some(end) => {
+                    let token = &after_open[..end];
+                    output.push_str(&resolve(token));
+                    rest = &after_open[end + 2..];
+                }
+                None => {
+                    // Unterminated `{{`: nothing left to scan, emit it as-is
+                    output.push_str(&rest[start..]);
+                    rest = "";
+                }
+            }
         }
-        
-        result
+
+        output.push_str(rest);
+        output
+    }
+
+    /// Resolve the interior of a single `{{...}}` region (braces already
+    /// stripped) to its replacement text
+    fn resolve_template_token(token: &str, environment: &Environment) -> String {
+        let first_word = token.split_whitespace().next().unwrap_or("");
+        if Self::is_helper_name(first_word) {
+            let args: Vec<&str> = token.split_whitespace().skip(1).collect();
+            if let Some(result) = Self::resolve_helper_call(first_word, &args) {
+                return result;
+            }
+            return format!("{{{{{}}}}}", token);
+        }
+
+        let (var_name, default_value) = match token.split_once(':') {
+            Some((name, default)) => (name, Some(default)),
+            None => (token, None),
+        };
+
+        environment
+            .get_variable(var_name)
+            .or_else(|| default_value.map(|s| s.to_string()))
+            .unwrap_or_else(|| format!("{{{{{}}}}}", token))
+    }
+
+    /// Whether `name` is a recognized built-in helper, as opposed to a
+    /// plain environment variable name
+    fn is_helper_name(name: &str) -> bool {
+        matches!(name, "uuid" | "timestamp" | "isoDate" | "randomInt" | "base64")
     }
 
-    /// Format a curl command for display
-    fn format_curl_command(args: &[String]) -> String {
-        // Format the command for better readability
-        // This could include line breaks for long commands
+    /// Invoke the built-in helper `name` with its whitespace-split `args`,
+    /// returning `None` if the arguments don't fit the helper so the
+    /// caller can fall back to the untouched literal
+    fn resolve_helper_call(name: &str, args: &[&str]) -> Option<String> {
+        match name {
+            "uuid" => Some(uuid::Uuid::new_v4().to_string()),
+            "timestamp" => Some(Utc::now().timestamp().to_string()),
+            "isoDate" => Some(Utc::now().to_rfc3339()),
+            "randomInt" => {
+                let min = args.first()?.parse::<i64>().ok()?;
+                let max = args.get(1)?.parse::<i64>().ok()?;
+                if max < min {
+                    return None;
+                }
+                let span = (max - min + 1) as u64;
+                let offset = OsRng.next_u64() % span;
+                Some((min + offset as i64).to_string())
+            }
+            "base64" => Some(Self::base64_encode(args.join(" ").as_bytes())),
+            _ => None,
+        }
+    }
+
+    /// Encode `bytes` as standard base64 with `=` padding. Hand-rolled
+    /// rather than pulling in a dependency for the single `{{base64 ...}}`
+    /// template helper; mirrors the minimal decoder in
+    /// `command::parser::decode_basic_auth`. Also reused by
+    /// `crate::command::generator`'s PowerShell generator, which has no
+    /// native Basic-auth flag and so encodes the `Authorization` header
+    /// itself.
+    pub(crate) fn base64_encode(bytes: &[u8]) -> String {
+        const ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+        let mut output = String::with_capacity((bytes.len() + 2) / 3 * 4);
+
+        for chunk in bytes.chunks(3) {
+            let b0 = chunk[0];
+            let b1 = chunk.get(1).copied();
+            let b2 = chunk.get(2).copied();
+
+            output.push(ALPHABET[(b0 >> 2) as usize] as char);
+            output.push(ALPHABET[(((b0 & 0x03) << 4) | (b1.unwrap_or(0) >> 4)) as usize] as char);
+            output.push(match b1 {
+                Some(b1) => ALPHABET[(((b1 & 0x0f) << 2) | (b2.unwrap_or(0) >> 6)) as usize] as char,
+                None => '=',
+            });
+            output.push(match b2 {
+                Some(b2) => ALPHABET[(b2 & 0x3f) as usize] as char,
+                None => '=',
+            });
+        }
+
+        output
+    }
+
+    /// Serialize a command's raw fields (no environment substitution) into
+    /// a single-line, shell-quoted curl invocation. This is the inverse of
+    /// `App::parse_command_args`: every argument is passed through
+    /// `shell_quote`, so round-tripping the result back through
+    /// `parse_command_args` reproduces the original tokens exactly.
+    pub fn to_shell_command(command: &CurlCommand) -> String {
+        let mut args = vec!["curl".to_string()];
+
+        for option in &command.options {
+            if option.enabled {
+                args.push(option.flag.clone());
+                if let Some(value) = &option.value {
+                    args.push(value.clone());
+                }
+            }
+        }
+
+        if let Some(method) = &command.method {
+            if method.to_string() != "GET" {
+                args.push("-X".to_string());
+                args.push(method.to_string());
+            }
+        }
+
+        args.extend(Self::auth_args(&command.auth, |s| s.to_string()));
+
+        for header in &command.headers {
+            if header.enabled {
+                args.push("-H".to_string());
+                args.push(format!("{}: {}", header.key, header.value));
+            }
+        }
+
+        if let Some(body) = &command.body {
+            match body {
+                RequestBody::Raw(content) => {
+                    if !content.trim().is_empty() {
+                        args.push("-d".to_string());
+                        args.push(content.clone());
+                    }
+                }
+                RequestBody::FormData(items) => {
+                    for item in items {
+                        if item.enabled {
+                            args.push("-F".to_string());
+                            let field = match &item.kind {
+                                FormFieldKind::Text(value) => format!("{}={}", item.key, value),
+                                FormFieldKind::File { path, content_type, filename } => {
+                                    let mut spec = format!("@{}", path.display());
+                                    if let Some(content_type) = content_type {
+                                        spec.push_str(&format!(";type={}", content_type));
+                                    }
+                                    if let Some(filename) = filename {
+                                        spec.push_str(&format!(";filename={}", filename));
+                                    }
+                                    format!("{}={}", item.key, spec)
+                                }
+                            };
+                            args.push(field);
+                        }
+                    }
+                }
+                RequestBody::Binary(path) => {
+                    args.push("--data-binary".to_string());
+                    args.push(format!("@{}", path.display()));
+                }
+                RequestBody::None => {}
+            }
+        }
+
+        for extra_arg in &command.extra_args {
+            args.push(extra_arg.clone());
+        }
+
+        args.push(Self::build_url_raw(command));
+
+        args.iter().map(|arg| shell_quote(arg)).collect::<Vec<String>>().join(" ")
+    }
+
+    /// Build the URL with query parameters appended, without any
+    /// environment substitution (used by `to_shell_command`)
+    fn build_url_raw(command: &CurlCommand) -> String {
+        let mut pairs: Vec<(&str, &str)> = command.query_params
+            .iter()
+            .filter(|p| p.enabled)
+            .map(|p| (p.key.as_str(), p.value.as_str()))
+            .collect();
+
+        if let Some(auth_pair) = Self::auth_query_param(&command.auth) {
+            pairs.push(auth_pair);
+        }
+
+        if pairs.is_empty() {
+            return command.url.clone();
+        }
+
+        let query_string: String = pairs
+            .iter()
+            .map(|(key, value)| format!("{}={}", key, urlencoding::encode(value)))
+            .collect::<Vec<String>>()
+            .join("&");
+
+        if command.url.contains('?') {
+            format!("{}&{}", command.url, query_string)
+        } else {
+            format!("{}?{}", command.url, query_string)
+        }
+    }
+
+    /// Format a curl command for display, quoting each argument for
+    /// `shell` and line-wrapping after quoting so a wrapped line is still
+    /// valid once pasted into that shell
+    fn format_curl_command(args: &[String], shell: TargetShell) -> String {
+        let continuation = match shell {
+            TargetShell::Posix => " \\\n      ",
+            TargetShell::PowerShell => " `\n      ",
+        };
+
         let mut formatted = String::new();
         let mut current_line_length = 0;
         let max_line_length = 80;
-        
+
         for (i, arg) in args.iter().enumerate() {
-            if i > 0 && current_line_length + arg.len() > max_line_length {
-                formatted.push_str(" \\\n      ");
+            let quoted = match shell {
+                TargetShell::Posix => shell_quote(arg),
+                TargetShell::PowerShell => powershell_quote(arg),
+            };
+
+            if i > 0 && current_line_length + quoted.len() > max_line_length {
+                formatted.push_str(continuation);
                 current_line_length = 6;
             }
-            
+
             if i > 0 {
                 formatted.push(' ');
                 current_line_length += 1;
             }
-            
-            // Handle arguments that need quoting
-            if arg.contains(' ') && !arg.starts_with('"') && !arg.starts_with('\'') {
-                formatted.push('"');
-                formatted.push_str(arg);
-                formatted.push('"');
-                current_line_length += arg.len() + 2;
-            } else {
-                formatted.push_str(arg);
-                current_line_length += arg.len();
-            }
+
+            formatted.push_str(&quoted);
+            current_line_length += quoted.len();
         }
-        
+
         formatted
     }
 }
@@ -210,7 +596,28 @@ mod tests {
         let environment = Environment::new("test".to_string());
         
         let result = CommandBuilder::build(&command, &environment);
-        assert_eq!(result, "curl -H \"Content-Type: application/json\" https://example.com");
+        assert_eq!(result, "curl -H 'Content-Type: application/json' https://example.com");
+    }
+
+    #[test]
+    fn test_build_masked_replaces_secret_header_value_with_stars() {
+        let mut command = CurlCommand::default();
+        command.url = "https://example.com".to_string();
+        command.headers.push(Header {
+            id: "1".to_string(),
+            key: "Authorization".to_string(),
+            value: "Bearer {{token}}".to_string(),
+            enabled: true,
+        });
+
+        let mut environment = Environment::new("test".to_string());
+        environment.add_variable("token".to_string(), "sk-super-secret".to_string(), true);
+
+        let result = CommandBuilder::build_masked(&command, &environment);
+        assert_eq!(result, "curl -H 'Authorization: Bearer ***' https://example.com");
+
+        let revealed = CommandBuilder::build(&command, &environment);
+        assert_eq!(revealed, "curl -H 'Authorization: Bearer sk-super-secret' https://example.com");
     }
 
     #[test]
@@ -257,10 +664,241 @@ mod tests {
     #[test]
     fn test_substitute_env_vars_with_default() {
         let environment = Environment::new("test".to_string());
-        
+
         let input = "{{api_url:https://default.example.com}}/users";
         let result = CommandBuilder::substitute_env_vars(input, &environment);
-        
+
         assert_eq!(result, "https://default.example.com/users");
     }
+
+    #[test]
+    fn test_substitute_env_vars_value_containing_braces_is_not_rescanned() {
+        let mut environment = Environment::new("test".to_string());
+        environment.add_variable("payload".to_string(), "{{not a var}}".to_string(), false);
+
+        let input = "{{payload}}/done";
+        let result = CommandBuilder::substitute_env_vars(input, &environment);
+
+        assert_eq!(result, "{{not a var}}/done");
+    }
+
+    #[test]
+    fn test_substitute_env_vars_uuid_helper_produces_a_uuid() {
+        let environment = Environment::new("test".to_string());
+
+        let result = CommandBuilder::substitute_env_vars("{{uuid}}", &environment);
+
+        assert_eq!(result.len(), 36);
+        assert_eq!(result.matches('-').count(), 4);
+    }
+
+    #[test]
+    fn test_substitute_env_vars_timestamp_helper_produces_digits() {
+        let environment = Environment::new("test".to_string());
+
+        let result = CommandBuilder::substitute_env_vars("{{timestamp}}", &environment);
+
+        assert!(!result.is_empty());
+        assert!(result.chars().all(|c| c.is_ascii_digit()));
+    }
+
+    #[test]
+    fn test_substitute_env_vars_random_int_helper_stays_in_range() {
+        let environment = Environment::new("test".to_string());
+
+        for _ in 0..20 {
+            let result = CommandBuilder::substitute_env_vars("{{randomInt 5 7}}", &environment);
+            let value: i64 = result.parse().expect("randomInt should produce an integer");
+            assert!((5..=7).contains(&value));
+        }
+    }
+
+    #[test]
+    fn test_substitute_env_vars_base64_helper_encodes_its_args() {
+        let environment = Environment::new("test".to_string());
+
+        let result = CommandBuilder::substitute_env_vars("{{base64 hello}}", &environment);
+
+        assert_eq!(result, "aGVsbG8=");
+    }
+
+    #[test]
+    fn test_substitute_env_vars_unknown_helper_args_fall_back_to_literal() {
+        let environment = Environment::new("test".to_string());
+
+        let result = CommandBuilder::substitute_env_vars("{{randomInt not-a-number}}", &environment);
+
+        assert_eq!(result, "{{randomInt not-a-number}}");
+    }
+
+    #[test]
+    fn test_substitute_env_vars_masked_replaces_secret_values_with_stars() {
+        let mut environment = Environment::new("test".to_string());
+        environment.add_variable("api_url".to_string(), "https://api.example.com".to_string(), false);
+        environment.add_variable("api_key".to_string(), "secret-key".to_string(), true);
+
+        let input = "{{api_url}}/users?key={{api_key}}";
+        let result = CommandBuilder::substitute_env_vars_masked(input, &environment);
+
+        assert_eq!(result, "https://api.example.com/users?key=***");
+    }
+
+    #[test]
+    fn test_substitute_env_vars_masked_still_resolves_helper_calls() {
+        let environment = Environment::new("test".to_string());
+
+        let result = CommandBuilder::substitute_env_vars_masked("{{base64 hello}}", &environment);
+
+        assert_eq!(result, "aGVsbG8=");
+    }
+
+    #[test]
+    fn test_build_command_with_basic_auth() {
+        let mut command = CurlCommand::default();
+        command.url = "https://example.com".to_string();
+        command.auth = Auth::Basic { username: "alice".to_string(), password: "secret".to_string() };
+
+        let environment = Environment::new("test".to_string());
+
+        let result = CommandBuilder::build(&command, &environment);
+        assert_eq!(result, "curl -u alice:secret https://example.com");
+    }
+
+    #[test]
+    fn test_build_command_with_api_key_query_param() {
+        let mut command = CurlCommand::default();
+        command.url = "https://example.com".to_string();
+        command.auth = Auth::ApiKey {
+            location: ApiKeyLocation::QueryParam,
+            name: "api_key".to_string(),
+            value: "abc123".to_string(),
+        };
+
+        let environment = Environment::new("test".to_string());
+
+        let result = CommandBuilder::build(&command, &environment);
+        assert_eq!(result, "curl https://example.com?api_key=abc123");
+    }
+
+    #[test]
+    fn test_effective_url_appends_enabled_query_params() {
+        let mut command = CurlCommand::default();
+        command.url = "https://example.com/search".to_string();
+        command.query_params.push(QueryParam {
+            id: "1".to_string(),
+            key: "q".to_string(),
+            value: "a b".to_string(),
+            enabled: true,
+        });
+        command.query_params.push(QueryParam {
+            id: "2".to_string(),
+            key: "disabled".to_string(),
+            value: "x".to_string(),
+            enabled: false,
+        });
+
+        assert_eq!(command.effective_url(), "https://example.com/search?q=a+b");
+    }
+
+    #[test]
+    fn test_effective_url_percent_encodes_the_key_too() {
+        let mut command = CurlCommand::default();
+        command.url = "https://example.com/search".to_string();
+        command.query_params.push(QueryParam {
+            id: "1".to_string(),
+            key: "a&b=c".to_string(),
+            value: "v".to_string(),
+            enabled: true,
+        });
+
+        assert_eq!(command.effective_url(), "https://example.com/search?a%26b%3Dc=v");
+    }
+
+    #[test]
+    fn test_sync_query_from_url_splits_and_strips() {
+        let mut command = CurlCommand::default();
+        command.url = "https://example.com/search?q=a+b&page=2".to_string();
+
+        command.sync_query_from_url();
+
+        assert_eq!(command.url, "https://example.com/search");
+        assert_eq!(command.query_params.len(), 2);
+        assert_eq!(command.query_params[0].key, "q");
+        assert_eq!(command.query_params[0].value, "a b");
+        assert_eq!(command.query_params[1].key, "page");
+        assert_eq!(command.query_params[1].value, "2");
+    }
+
+    #[test]
+    fn test_shell_quote_leaves_safe_args_bare() {
+        assert_eq!(shell_quote("https://example.com/path"), "https://example.com/path");
+        assert_eq!(shell_quote("-X"), "-X");
+    }
+
+    #[test]
+    fn test_shell_quote_escapes_embedded_single_quotes() {
+        assert_eq!(shell_quote("it's a test"), "'it'\\''s a test'");
+        assert_eq!(shell_quote("a value with $dollar and `backtick`"), "'a value with $dollar and `backtick`'");
+    }
+
+    #[test]
+    fn test_powershell_quote_leaves_safe_args_bare() {
+        assert_eq!(powershell_quote("https://example.com/path"), "https://example.com/path");
+        assert_eq!(powershell_quote("-X"), "-X");
+    }
+
+    #[test]
+    fn test_powershell_quote_escapes_quotes_backticks_and_dollar_signs() {
+        assert_eq!(powershell_quote("Bearer abc"), "\"Bearer abc\"");
+        assert_eq!(powershell_quote("say \"hi\""), "\"say `\"hi`\"\"");
+        assert_eq!(powershell_quote("$env and `backtick`"), "\"`$env and ``backtick``\"");
+    }
+
+    #[test]
+    fn test_build_for_shell_quotes_headers_for_powershell() {
+        let mut command = CurlCommand::default();
+        command.url = "https://example.com".to_string();
+        command.headers.push(Header {
+            id: "1".to_string(),
+            key: "Content-Type".to_string(),
+            value: "application/json".to_string(),
+            enabled: true,
+        });
+
+        let environment = Environment::new("test".to_string());
+
+        let result = CommandBuilder::build_for_shell(&command, &environment, TargetShell::PowerShell);
+        assert_eq!(result, "curl -H \"Content-Type: application/json\" https://example.com");
+    }
+
+    #[test]
+    fn test_to_shell_command_round_trips_through_parse_command_args() {
+        let mut command = CurlCommand::default();
+        command.url = "https://example.com/search".to_string();
+        command.method = Some(HttpMethod::POST);
+        command.headers.push(Header {
+            id: "1".to_string(),
+            key: "X-Test".to_string(),
+            value: "a value with spaces and $dollar `backtick`".to_string(),
+            enabled: true,
+        });
+        command.body = Some(RequestBody::Raw("{\"q\": \"it's a test\"}".to_string()));
+
+        let shell_command = command.to_shell_command();
+        let tokens = crate::app::App::parse_command_args(&shell_command);
+
+        assert_eq!(
+            tokens,
+            vec![
+                "curl".to_string(),
+                "-X".to_string(),
+                "POST".to_string(),
+                "-H".to_string(),
+                "X-Test: a value with spaces and $dollar `backtick`".to_string(),
+                "-d".to_string(),
+                "{\"q\": \"it's a test\"}".to_string(),
+                "https://example.com/search".to_string(),
+            ]
+        );
+    }
 }
\ No newline at end of file
@@ -0,0 +1,466 @@
+use crate::command::builder::{powershell_quote, shell_quote, CommandBuilder};
+use crate::models::command::{ApiKeyLocation, Auth, CurlCommand, FormFieldKind, RequestBody};
+use crate::models::environment::Environment;
+
+/// A target tool/library a [`CurlCommand`] can be rendered into, beyond the
+/// default `curl` invocation. Cycled via `Action::CycleGenerator`, selecting
+/// which [`CommandGenerator`] impl [`generate`] dispatches to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GeneratorKind {
+    Curl,
+    Wget,
+    Httpie,
+    PowerShell,
+    PythonRequests,
+}
+
+impl Default for GeneratorKind {
+    fn default() -> Self {
+        Self::Curl
+    }
+}
+
+impl GeneratorKind {
+    /// Label shown in the command display panel's title
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Curl => "curl",
+            Self::Wget => "wget",
+            Self::Httpie => "HTTPie",
+            Self::PowerShell => "PowerShell",
+            Self::PythonRequests => "Python requests",
+        }
+    }
+
+    /// The next format in the cycle, wrapping back to `Curl` after the last
+    pub fn next(&self) -> Self {
+        match self {
+            Self::Curl => Self::Wget,
+            Self::Wget => Self::Httpie,
+            Self::Httpie => Self::PowerShell,
+            Self::PowerShell => Self::PythonRequests,
+            Self::PythonRequests => Self::Curl,
+        }
+    }
+}
+
+/// Render `command` in the format selected by `kind`, substituting `{{...}}`
+/// template variables from `environment`. Secret variables are masked as
+/// `***` unless `reveal_secrets` is set, mirroring
+/// `CommandBuilder::build`/`build_masked`.
+pub fn generate(kind: GeneratorKind, command: &CurlCommand, environment: &Environment, reveal_secrets: bool) -> String {
+    match kind {
+        GeneratorKind::Curl => CurlGenerator.build(command, environment, reveal_secrets),
+        GeneratorKind::Wget => WgetGenerator.build(command, environment, reveal_secrets),
+        GeneratorKind::Httpie => HttpieGenerator.build(command, environment, reveal_secrets),
+        GeneratorKind::PowerShell => PowerShellGenerator.build(command, environment, reveal_secrets),
+        GeneratorKind::PythonRequests => PythonRequestsGenerator.build(command, environment, reveal_secrets),
+    }
+}
+
+/// Renders a `CurlCommand` into one target tool's invocation syntax. One
+/// implementation per `GeneratorKind`, dispatched via a plain `match` in
+/// `generate` above rather than a dispatch-helper crate: this repo has no
+/// `Cargo.toml` to add one to, and its own precedent for "target variant"
+/// dispatch (`TargetShell` in `CommandBuilder`) is always a plain enum and
+/// match.
+trait CommandGenerator {
+    fn build(&self, command: &CurlCommand, environment: &Environment, reveal_secrets: bool) -> String;
+}
+
+/// Build the `{{...}}` substitution closure shared by every generator:
+/// real values when `reveal_secrets` is set, `***` over secrets otherwise.
+fn substitution(environment: &Environment, reveal_secrets: bool) -> impl Fn(&str) -> String + '_ {
+    move |s: &str| {
+        if reveal_secrets {
+            CommandBuilder::substitute_env_vars(s, environment)
+        } else {
+            CommandBuilder::substitute_env_vars_masked(s, environment)
+        }
+    }
+}
+
+struct CurlGenerator;
+
+impl CommandGenerator for CurlGenerator {
+    fn build(&self, command: &CurlCommand, environment: &Environment, reveal_secrets: bool) -> String {
+        if reveal_secrets {
+            CommandBuilder::build(command, environment)
+        } else {
+            CommandBuilder::build_masked(command, environment)
+        }
+    }
+}
+
+struct WgetGenerator;
+
+impl CommandGenerator for WgetGenerator {
+    fn build(&self, command: &CurlCommand, environment: &Environment, reveal_secrets: bool) -> String {
+        let sub = substitution(environment, reveal_secrets);
+        let mut args = vec!["wget".to_string()];
+
+        if let Some(method) = &command.method {
+            if method.to_string() != "GET" {
+                args.push(format!("--method={}", method));
+            }
+        }
+
+        match &command.auth {
+            Auth::Basic { username, password } => {
+                args.push(format!("--http-user={}", sub(username)));
+                args.push(format!("--http-password={}", sub(password)));
+            }
+            Auth::Bearer(token) | Auth::OAuth2 { token, .. } => {
+                args.push(format!("--header=Authorization: Bearer {}", sub(token)));
+            }
+            Auth::ApiKey { location: ApiKeyLocation::Header, name, value } => {
+                args.push(format!("--header={}: {}", name, sub(value)));
+            }
+            Auth::ApiKey { location: ApiKeyLocation::QueryParam, .. } | Auth::None => {}
+        }
+
+        for header in &command.headers {
+            if header.enabled {
+                args.push(format!("--header={}: {}", header.key, sub(&header.value)));
+            }
+        }
+
+        match &command.body {
+            Some(RequestBody::Raw(content)) if !content.trim().is_empty() => {
+                args.push(format!("--body-data={}", sub(content)));
+            }
+            Some(RequestBody::Binary(path)) => {
+                args.push(format!("--body-file={}", path.display()));
+            }
+            _ => {}
+        }
+
+        args.push(CommandBuilder::build_url_with_query(command, &sub));
+
+        format_posix_command(&args)
+    }
+}
+
+struct HttpieGenerator;
+
+impl CommandGenerator for HttpieGenerator {
+    fn build(&self, command: &CurlCommand, environment: &Environment, reveal_secrets: bool) -> String {
+        let sub = substitution(environment, reveal_secrets);
+        let mut args = vec!["http".to_string()];
+
+        if let Some(method) = &command.method {
+            if method.to_string() != "GET" {
+                args.push(method.to_string());
+            }
+        }
+
+        args.push(CommandBuilder::build_url_with_query(command, &sub));
+
+        for header in &command.headers {
+            if header.enabled {
+                args.push(format!("{}:{}", header.key, sub(&header.value)));
+            }
+        }
+
+        match &command.auth {
+            Auth::Basic { username, password } => {
+                args.push("-a".to_string());
+                args.push(format!("{}:{}", sub(username), sub(password)));
+            }
+            Auth::Bearer(token) | Auth::OAuth2 { token, .. } => {
+                args.push(format!("Authorization:Bearer {}", sub(token)));
+            }
+            Auth::ApiKey { location: ApiKeyLocation::Header, name, value } => {
+                args.push(format!("{}:{}", name, sub(value)));
+            }
+            Auth::ApiKey { location: ApiKeyLocation::QueryParam, .. } | Auth::None => {}
+        }
+
+        if let Some(RequestBody::Raw(content)) = &command.body {
+            if !content.trim().is_empty() {
+                args.push("--raw".to_string());
+                args.push(sub(content));
+            }
+        }
+
+        format_posix_command(&args)
+    }
+}
+
+/// Shared POSIX-style formatting for wget/httpie: quote each arg for a POSIX
+/// shell and wrap after 80 columns with a `\` continuation, mirroring
+/// `CommandBuilder::format_curl_command`'s own curl formatting.
+fn format_posix_command(args: &[String]) -> String {
+    let mut formatted = String::new();
+    let mut current_line_length = 0;
+    let max_line_length = 80;
+
+    for (i, arg) in args.iter().enumerate() {
+        let quoted = shell_quote(arg);
+
+        if i > 0 && current_line_length + quoted.len() > max_line_length {
+            formatted.push_str(" \\\n      ");
+            current_line_length = 6;
+        } else if i > 0 {
+            formatted.push(' ');
+            current_line_length += 1;
+        }
+
+        formatted.push_str(&quoted);
+        current_line_length += quoted.len();
+    }
+
+    formatted
+}
+
+struct PowerShellGenerator;
+
+impl CommandGenerator for PowerShellGenerator {
+    fn build(&self, command: &CurlCommand, environment: &Environment, reveal_secrets: bool) -> String {
+        let sub = substitution(environment, reveal_secrets);
+        let url = CommandBuilder::build_url_with_query(command, &sub);
+
+        let mut parts = vec![format!("Invoke-WebRequest -Uri {}", powershell_quote(&url))];
+
+        if let Some(method) = &command.method {
+            if method.to_string() != "GET" {
+                parts.push(format!("-Method {}", method));
+            }
+        }
+
+        let mut header_pairs: Vec<(String, String)> = command
+            .headers
+            .iter()
+            .filter(|header| header.enabled)
+            .map(|header| (header.key.clone(), sub(&header.value)))
+            .collect();
+
+        // `Invoke-WebRequest` has no dedicated Basic-auth flag that doesn't
+        // require building a `PSCredential`, so Basic is folded into an
+        // `Authorization` header the same way every other generator folds
+        // Bearer/OAuth2/header-API-key auth in
+        match &command.auth {
+            Auth::Basic { username, password } => {
+                let token = CommandBuilder::base64_encode(format!("{}:{}", sub(username), sub(password)).as_bytes());
+                header_pairs.push(("Authorization".to_string(), format!("Basic {}", token)));
+            }
+            Auth::Bearer(token) | Auth::OAuth2 { token, .. } => {
+                header_pairs.push(("Authorization".to_string(), format!("Bearer {}", sub(token))));
+            }
+            Auth::ApiKey { location: ApiKeyLocation::Header, name, value } => {
+                header_pairs.push((name.clone(), sub(value)));
+            }
+            Auth::ApiKey { location: ApiKeyLocation::QueryParam, .. } | Auth::None => {}
+        }
+
+        if !header_pairs.is_empty() {
+            let hashtable = header_pairs
+                .iter()
+                .map(|(key, value)| format!("{} = {}", powershell_quote(key), powershell_quote(value)))
+                .collect::<Vec<String>>()
+                .join("; ");
+            parts.push(format!("-Headers @{{ {} }}", hashtable));
+        }
+
+        if let Some(RequestBody::Raw(content)) = &command.body {
+            if !content.trim().is_empty() {
+                parts.push(format!("-Body {}", powershell_quote(&sub(content))));
+            }
+        }
+
+        parts.join(" `\n      ")
+    }
+}
+
+struct PythonRequestsGenerator;
+
+impl CommandGenerator for PythonRequestsGenerator {
+    fn build(&self, command: &CurlCommand, environment: &Environment, reveal_secrets: bool) -> String {
+        let sub = substitution(environment, reveal_secrets);
+        let url = CommandBuilder::build_url_with_query(command, &sub);
+        let method = command.method.clone().unwrap_or_default().to_string();
+
+        let mut lines = vec!["import requests".to_string(), String::new()];
+
+        let mut header_pairs: Vec<(String, String)> = command
+            .headers
+            .iter()
+            .filter(|header| header.enabled)
+            .map(|header| (header.key.clone(), sub(&header.value)))
+            .collect();
+
+        match &command.auth {
+            Auth::Bearer(token) | Auth::OAuth2 { token, .. } => {
+                header_pairs.push(("Authorization".to_string(), format!("Bearer {}", sub(token))));
+            }
+            Auth::ApiKey { location: ApiKeyLocation::Header, name, value } => {
+                header_pairs.push((name.clone(), sub(value)));
+            }
+            Auth::Basic { .. } | Auth::ApiKey { location: ApiKeyLocation::QueryParam, .. } | Auth::None => {}
+        }
+
+        if !header_pairs.is_empty() {
+            let body = header_pairs
+                .iter()
+                .map(|(key, value)| format!("    {}: {},", python_str(key), python_str(value)))
+                .collect::<Vec<String>>()
+                .join("\n");
+            lines.push(format!("headers = {{\n{}\n}}", body));
+        }
+
+        if let Auth::Basic { username, password } = &command.auth {
+            lines.push(format!("auth = ({}, {})", python_str(&sub(username)), python_str(&sub(password))));
+        }
+
+        match &command.body {
+            Some(RequestBody::Raw(content)) if !content.trim().is_empty() => {
+                lines.push(format!("data = {}", python_str(&sub(content))));
+            }
+            Some(RequestBody::FormData(items)) => {
+                let fields: Vec<String> = items
+                    .iter()
+                    .filter(|item| item.enabled)
+                    .filter_map(|item| match &item.kind {
+                        FormFieldKind::Text(value) => {
+                            Some(format!("    {}: {},", python_str(&item.key), python_str(&sub(value))))
+                        }
+                        // A file part needs an open file handle, which can't
+                        // be expressed as a dict literal; left as a comment
+                        // rather than silently dropped.
+                        FormFieldKind::File { path, .. } => Some(format!(
+                            "    # {}: file upload from {} not included",
+                            python_str(&item.key),
+                            path.display()
+                        )),
+                    })
+                    .collect();
+                if !fields.is_empty() {
+                    lines.push(format!("files = {{\n{}\n}}", fields.join("\n")));
+                }
+            }
+            Some(RequestBody::Binary(path)) => {
+                lines.push(format!("data = open({}, \"rb\").read()", python_str(&path.display().to_string())));
+            }
+            _ => {}
+        }
+
+        lines.push(String::new());
+
+        let mut call_args = vec![format!("    {},", python_str(&method.to_lowercase())), format!("    {},", python_str(&url))];
+        if !header_pairs.is_empty() {
+            call_args.push("    headers=headers,".to_string());
+        }
+        if matches!(&command.auth, Auth::Basic { .. }) {
+            call_args.push("    auth=auth,".to_string());
+        }
+        if matches!(&command.body, Some(RequestBody::Raw(content)) if !content.trim().is_empty())
+            || matches!(&command.body, Some(RequestBody::Binary(_)))
+        {
+            call_args.push("    data=data,".to_string());
+        }
+        if matches!(&command.body, Some(RequestBody::FormData(items)) if items.iter().any(|item| item.enabled && matches!(item.kind, FormFieldKind::Text(_))))
+        {
+            call_args.push("    files=files,".to_string());
+        }
+
+        lines.push("response = requests.request(".to_string());
+        lines.extend(call_args);
+        lines.push(")".to_string());
+        lines.push(String::new());
+        lines.push("print(response.text)".to_string());
+
+        lines.join("\n")
+    }
+}
+
+/// Render `s` as a Python double-quoted string literal
+fn python_str(s: &str) -> String {
+    let escaped = s.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n");
+    format!("\"{}\"", escaped)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::command::{Header, HttpMethod};
+
+    fn environment() -> Environment {
+        Environment::new("test".to_string())
+    }
+
+    #[test]
+    fn test_generator_kind_cycles_through_every_format_and_back_to_curl() {
+        let mut kind = GeneratorKind::Curl;
+        let mut seen = vec![kind];
+        for _ in 0..4 {
+            kind = kind.next();
+            seen.push(kind);
+        }
+        assert_eq!(kind.next(), GeneratorKind::Curl);
+        assert_eq!(seen.len(), 5);
+    }
+
+    #[test]
+    fn test_wget_generator_includes_method_and_header() {
+        let mut command = CurlCommand::default();
+        command.url = "https://example.com".to_string();
+        command.method = Some(HttpMethod::POST);
+        command.headers.push(Header {
+            id: "1".to_string(),
+            key: "X-Test".to_string(),
+            value: "value".to_string(),
+            enabled: true,
+        });
+
+        let result = generate(GeneratorKind::Wget, &command, &environment(), true);
+
+        assert!(result.starts_with("wget"));
+        assert!(result.contains("--method=POST"));
+        assert!(result.contains("--header=X-Test: value"));
+        assert!(result.contains("https://example.com"));
+    }
+
+    #[test]
+    fn test_httpie_generator_renders_header_pair_syntax() {
+        let mut command = CurlCommand::default();
+        command.url = "https://example.com".to_string();
+        command.headers.push(Header {
+            id: "1".to_string(),
+            key: "X-Test".to_string(),
+            value: "value".to_string(),
+            enabled: true,
+        });
+
+        let result = generate(GeneratorKind::Httpie, &command, &environment(), true);
+
+        assert!(result.starts_with("http "));
+        assert!(result.contains("X-Test:value"));
+    }
+
+    #[test]
+    fn test_powershell_generator_folds_basic_auth_into_authorization_header() {
+        let mut command = CurlCommand::default();
+        command.url = "https://example.com".to_string();
+        command.auth = Auth::Basic { username: "alice".to_string(), password: "secret".to_string() };
+
+        let result = generate(GeneratorKind::PowerShell, &command, &environment(), true);
+
+        assert!(result.starts_with("Invoke-WebRequest"));
+        assert!(result.contains("Authorization"));
+        assert!(result.contains("Basic"));
+    }
+
+    #[test]
+    fn test_python_requests_generator_includes_method_url_and_print() {
+        let mut command = CurlCommand::default();
+        command.url = "https://example.com".to_string();
+        command.method = Some(HttpMethod::POST);
+
+        let result = generate(GeneratorKind::PythonRequests, &command, &environment(), true);
+
+        assert!(result.contains("import requests"));
+        assert!(result.contains("requests.request("));
+        assert!(result.contains("\"post\""));
+        assert!(result.contains("print(response.text)"));
+    }
+}
@@ -0,0 +1,190 @@
+use crate::models::command::{CurlCommand, FormFieldKind, HttpMethod, RequestBody};
+
+/// Map a curl flag to the option name Hurl uses in its `[Options]` section,
+/// mirroring the subset of flags Hurl's own curl importer recognizes.
+/// Unrecognized flags are simply omitted from the exported entry.
+fn hurl_option_name(flag: &str) -> Option<&'static str> {
+    match flag {
+        "-L" | "--location" => Some("follow-redirect"),
+        "-k" | "--insecure" => Some("insecure"),
+        "--max-redirs" => Some("max-redirs"),
+        "--resolve" => Some("resolve"),
+        _ => None,
+    }
+}
+
+/// Exports a [`CurlCommand`] as a Hurl (`.hurl`) entry
+pub struct HurlExporter;
+
+impl HurlExporter {
+    /// Render `command` as a single Hurl entry: the method and URL on the
+    /// first line, each enabled header as `Name: value`, an `[Options]`
+    /// block for flags like follow-redirect/insecure/max-redirs/resolve,
+    /// and finally the body (a fenced ` ``` ` section, or `[FormParams]`
+    /// when the body is form-encoded)
+    pub fn export(command: &CurlCommand) -> String {
+        let mut lines = Vec::new();
+
+        let method = command.method.clone().unwrap_or(HttpMethod::GET);
+        lines.push(format!("{} {}", method, Self::request_url(command)));
+
+        for header in &command.headers {
+            if header.enabled {
+                lines.push(format!("{}: {}", header.key, header.value));
+            }
+        }
+
+        let option_lines = Self::option_lines(command);
+        if !option_lines.is_empty() {
+            lines.push("[Options]".to_string());
+            lines.extend(option_lines);
+        }
+
+        match command.body.as_ref() {
+            Some(RequestBody::Raw(content)) if !content.trim().is_empty() => {
+                lines.push("```".to_string());
+                lines.push(content.clone());
+                lines.push("```".to_string());
+            }
+            Some(RequestBody::FormData(items)) if !items.is_empty() => {
+                // Hurl only has a dedicated section for file uploads, so any
+                // file part bumps the whole body to `[MultipartFormData]`
+                let has_file_part = items.iter().any(|item| matches!(item.kind, FormFieldKind::File { .. }));
+                lines.push(if has_file_part { "[MultipartFormData]" } else { "[FormParams]" }.to_string());
+                for item in items {
+                    if !item.enabled {
+                        continue;
+                    }
+                    match &item.kind {
+                        FormFieldKind::Text(value) => lines.push(format!("{}: {}", item.key, value)),
+                        FormFieldKind::File { path, content_type, .. } => {
+                            let field = match content_type {
+                                Some(content_type) => format!("{}: file,{}; {}", item.key, path.display(), content_type),
+                                None => format!("{}: file,{};", item.key, path.display()),
+                            };
+                            lines.push(field);
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        lines.join("\n")
+    }
+
+    /// The request URL with enabled query params appended; curl's
+    /// `{{variable}}` templating is left as-is since Hurl understands the
+    /// same syntax natively
+    fn request_url(command: &CurlCommand) -> String {
+        if command.query_params.is_empty() || !command.query_params.iter().any(|p| p.enabled) {
+            return command.url.clone();
+        }
+
+        let query_string: String = command
+            .query_params
+            .iter()
+            .filter(|p| p.enabled)
+            .map(|p| format!("{}={}", p.key, urlencoding::encode(&p.value)))
+            .collect::<Vec<String>>()
+            .join("&");
+
+        if command.url.contains('?') {
+            format!("{}&{}", command.url, query_string)
+        } else {
+            format!("{}?{}", command.url, query_string)
+        }
+    }
+
+    /// Collect `[Options]` block lines from both structured options and
+    /// raw extra args, for flags Hurl recognizes
+    fn option_lines(command: &CurlCommand) -> Vec<String> {
+        let mut option_lines = Vec::new();
+
+        for option in &command.options {
+            if !option.enabled {
+                continue;
+            }
+            if let Some(name) = hurl_option_name(&option.flag) {
+                match &option.value {
+                    Some(value) => option_lines.push(format!("{}: {}", name, value)),
+                    None => option_lines.push(format!("{}: true", name)),
+                }
+            }
+        }
+
+        for extra in &command.extra_args {
+            let (flag, value) = match extra.split_once(' ') {
+                Some((flag, value)) => (flag, Some(value)),
+                None => (extra.as_str(), None),
+            };
+            if let Some(name) = hurl_option_name(flag) {
+                match value {
+                    Some(value) => option_lines.push(format!("{}: {}", name, value)),
+                    None => option_lines.push(format!("{}: true", name)),
+                }
+            }
+        }
+
+        option_lines
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::command::{CurlOption, Header};
+
+    #[test]
+    fn test_export_simple_get() {
+        let mut command = CurlCommand::default();
+        command.url = "https://example.com".to_string();
+        command.method = Some(HttpMethod::GET);
+
+        assert_eq!(HurlExporter::export(&command), "GET https://example.com");
+    }
+
+    #[test]
+    fn test_export_includes_headers_and_raw_body() {
+        let mut command = CurlCommand::default();
+        command.url = "https://example.com/users".to_string();
+        command.method = Some(HttpMethod::POST);
+        command.headers.push(Header {
+            id: "1".to_string(),
+            key: "Content-Type".to_string(),
+            value: "application/json".to_string(),
+            enabled: true,
+        });
+        command.body = Some(RequestBody::Raw("{\"name\": \"Ada\"}".to_string()));
+
+        let hurl = HurlExporter::export(&command);
+        assert_eq!(
+            hurl,
+            "POST https://example.com/users\nContent-Type: application/json\n```\n{\"name\": \"Ada\"}\n```"
+        );
+    }
+
+    #[test]
+    fn test_export_maps_follow_redirect_and_insecure_to_options_block() {
+        let mut command = CurlCommand::default();
+        command.url = "https://example.com".to_string();
+        command.options.push(CurlOption {
+            id: "1".to_string(),
+            flag: "-L".to_string(),
+            value: None,
+            enabled: true,
+        });
+        command.options.push(CurlOption {
+            id: "2".to_string(),
+            flag: "-k".to_string(),
+            value: None,
+            enabled: true,
+        });
+
+        let hurl = HurlExporter::export(&command);
+        assert_eq!(
+            hurl,
+            "GET https://example.com\n[Options]\nfollow-redirect: true\ninsecure: true"
+        );
+    }
+}
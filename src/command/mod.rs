@@ -1,7 +1,14 @@
 pub mod builder;
+pub mod generator;
+pub mod hurl;
 pub mod options;
+pub mod parser;
+pub mod spec;
 pub mod validation;
 
-pub use builder::CommandBuilder;
-pub use options::{CurlOptions, OptionCategory, OptionDefinition, OptionTier};
+pub use builder::{shell_quote, CommandBuilder};
+pub use generator::{generate, GeneratorKind};
+pub use hurl::HurlExporter;
+pub use options::{ArgumentKind, CurlOptions, OptionCategory, OptionDefinition, OptionTier, SelectionIssue};
+pub use spec::{CliSpec, FlagSpec, GenericCommand, GenericOption};
 pub use validation::{CommandValidator, ValidationResult};
\ No newline at end of file
@@ -22,6 +22,8 @@ pub enum OptionCategory {
     Output,
     /// Command Line options
     CommandLine,
+    /// Transport/protocol-version options
+    Protocol,
 }
 
 impl std::fmt::Display for OptionCategory {
@@ -36,6 +38,7 @@ impl std::fmt::Display for OptionCategory {
             OptionCategory::Proxy => write!(f, "Proxy Options"),
             OptionCategory::Output => write!(f, "Output Options"),
             OptionCategory::CommandLine => write!(f, "Command Line Options"),
+            OptionCategory::Protocol => write!(f, "Protocol Options"),
         }
     }
 }
@@ -51,6 +54,36 @@ pub enum OptionTier {
     Expert,
 }
 
+/// The HTTP methods accepted by `ArgumentKind::HttpMethod`, shared between
+/// `OptionDefinition::validate_value` and `OptionDefinition::known_values`
+const HTTP_METHODS: &[&str] = &["GET", "POST", "PUT", "DELETE", "PATCH", "HEAD", "OPTIONS", "TRACE", "CONNECT"];
+
+/// The expected kind of a curl option's argument, used to drive inline
+/// validation as the user types a value
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum ArgumentKind {
+    /// The option takes no argument
+    Flag,
+    /// Free-form text
+    Text,
+    /// A `Key: Value` header string
+    HeaderString,
+    /// A URL
+    Url,
+    /// A path to a file on disk
+    FilePath,
+    /// An HTTP method name
+    HttpMethod,
+    /// A non-negative integer (e.g. a retry count)
+    Integer,
+    /// A non-negative duration in seconds, fractions allowed (e.g. a timeout)
+    Duration,
+    /// One of a fixed, case-insensitive set of values
+    Enum(Vec<String>),
+    /// curl's `--resolve` shape: `[+|-]HOST:PORT:ADDRESS`
+    ResolveEntry,
+}
+
 /// Curl option definition
 #[derive(Debug, Clone)]
 pub struct OptionDefinition {
@@ -66,32 +99,234 @@ pub struct OptionDefinition {
     pub category: OptionCategory,
     /// Option complexity tier
     pub tier: OptionTier,
+    /// Expected kind of the option's argument, used for completion/validation
+    pub arg_kind: ArgumentKind,
+    /// Whether this option may be passed more than once in the same
+    /// command (e.g. curl's repeatable `--resolve`); most options are not
+    pub repeatable: bool,
+    /// Canonical flags that cannot be enabled at the same time as this one
+    pub conflicts_with: Vec<String>,
+    /// Canonical flags that must also be enabled for this one to be valid
+    pub requires: Vec<String>,
+}
+
+impl OptionDefinition {
+    /// Validate a candidate value against this option's declared argument
+    /// kind, returning a human-readable error if it's malformed
+    pub fn validate_value(&self, value: &str) -> Result<(), String> {
+        match self.arg_kind {
+            ArgumentKind::Flag => Ok(()),
+            ArgumentKind::Text => Ok(()),
+            ArgumentKind::HeaderString => {
+                if value.contains(':') {
+                    Ok(())
+                } else {
+                    Err(format!("{} expects a \"Key: Value\" header, missing ':'", self.flag))
+                }
+            }
+            ArgumentKind::Url => {
+                if url::Url::parse(value).is_ok() {
+                    Ok(())
+                } else {
+                    Err(format!("{} expects a valid URL", self.flag))
+                }
+            }
+            ArgumentKind::FilePath => {
+                if value.trim().is_empty() {
+                    Err(format!("{} requires a file path", self.flag))
+                } else {
+                    Ok(())
+                }
+            }
+            ArgumentKind::HttpMethod => {
+                if HTTP_METHODS.contains(&value.to_uppercase().as_str()) {
+                    Ok(())
+                } else {
+                    Err(format!("{} expects an HTTP method, got \"{}\"", self.flag, value))
+                }
+            }
+            ArgumentKind::Integer => match value.parse::<i64>() {
+                Ok(n) if n >= 0 => Ok(()),
+                Ok(_) => Err(format!("{} expects a non-negative integer", self.flag)),
+                Err(_) => Err(format!("{} expects an integer, got \"{}\"", self.flag, value)),
+            },
+            ArgumentKind::Duration => match value.parse::<f64>() {
+                Ok(n) if n >= 0.0 => Ok(()),
+                Ok(_) => Err(format!("{} expects a non-negative duration", self.flag)),
+                Err(_) => Err(format!("{} expects a duration in seconds, got \"{}\"", self.flag, value)),
+            },
+            ArgumentKind::Enum(ref allowed) => {
+                if allowed.iter().any(|candidate| candidate.eq_ignore_ascii_case(value)) {
+                    Ok(())
+                } else {
+                    Err(format!("{} expects one of {:?}, got \"{}\"", self.flag, allowed, value))
+                }
+            }
+            ArgumentKind::ResolveEntry => {
+                let unprefixed = value.strip_prefix('+').or_else(|| value.strip_prefix('-')).unwrap_or(value);
+                let mut parts = unprefixed.splitn(3, ':');
+                match (parts.next(), parts.next(), parts.next()) {
+                    (Some(host), Some(port), Some(address))
+                        if !host.is_empty() && !address.is_empty() && port.parse::<u16>().is_ok() =>
+                    {
+                        Ok(())
+                    }
+                    _ => Err(format!(
+                        "{} expects host:port:address (optionally prefixed with + or -)",
+                        self.flag
+                    )),
+                }
+            }
+        }
+    }
+
+    /// Known candidate values for this option's argument, for the ones
+    /// where `arg_kind` represents a fixed set of choices -- drives the
+    /// inline value-completion popup the same way `complete` drives flag
+    /// completion. `None` for free-form kinds like `Text`/`Url`/`Integer`.
+    pub fn known_values(&self) -> Option<Vec<String>> {
+        match &self.arg_kind {
+            ArgumentKind::HttpMethod => Some(HTTP_METHODS.iter().map(|method| method.to_string()).collect()),
+            ArgumentKind::Enum(allowed) => Some(allowed.clone()),
+            _ => None,
+        }
+    }
+}
+
+/// A problem found between two or more selected options by `CurlOptions::check_selection`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SelectionIssue {
+    /// Two canonical flags are enabled that cannot be used together
+    Conflict { flag: String, conflicts_with: String },
+    /// A canonical flag is enabled but one of its required flags is not
+    MissingRequirement { flag: String, requires: String },
 }
 
 /// Curl options manager
 pub struct CurlOptions {
     /// Option definitions by flag
     options: HashMap<String, OptionDefinition>,
+    /// Every short flag, long flag, and alias (long forms folded to
+    /// lowercase) mapped to its canonical `OptionDefinition::flag`, so
+    /// `resolve_flag` doesn't need to linear-scan `options` for aliases
+    flag_index: HashMap<String, String>,
 }
 
 impl CurlOptions {
     /// Create a new curl options manager with all predefined options
     pub fn new() -> Self {
         let mut options = HashMap::new();
-        
+
         // Add all predefined options
         for option in Self::predefined_options() {
             options.insert(option.flag.clone(), option);
         }
-        
-        Self { options }
+
+        let mut flag_index = HashMap::new();
+        for option in options.values() {
+            flag_index.insert(Self::normalize_flag(&option.flag), option.flag.clone());
+            if let Some(long_flag) = &option.long_flag {
+                flag_index.insert(Self::normalize_flag(long_flag), option.flag.clone());
+            }
+        }
+
+        Self { options, flag_index }
+    }
+
+    /// Fold a long flag (`--foo`) to lowercase for case-insensitive lookup,
+    /// following the uncased matching Rocket uses for forwarded-protocol
+    /// values; a short flag (`-f`) is left as-is since curl's short flags
+    /// are case-sensitive (`-o` and `-O` are different options)
+    fn normalize_flag(flag: &str) -> String {
+        if flag.starts_with("--") {
+            flag.to_lowercase()
+        } else {
+            flag.to_string()
+        }
     }
-    
-    /// Get an option definition by flag
+
+    /// Resolve any short flag, long flag, or alias to its canonical
+    /// `OptionDefinition`, case-insensitively for long forms. This is the
+    /// one lookup path the command parser, validation, and UI search all
+    /// share, so a definition's flag/long_flag ordering (some options store
+    /// their short form in `long_flag`, e.g. `--max-time`'s `-m`) is an
+    /// internal detail callers never need to know.
+    pub fn resolve_flag(&self, any: &str) -> Option<&OptionDefinition> {
+        self.flag_index
+            .get(&Self::normalize_flag(any))
+            .and_then(|canonical| self.options.get(canonical))
+    }
+
+    /// Get an option definition by flag, short or long, case-insensitively
+    /// for long forms. An alias for `resolve_flag` kept under this name
+    /// since most call sites read more naturally as "get the option".
     pub fn get_option(&self, flag: &str) -> Option<&OptionDefinition> {
-        self.options.get(flag)
+        self.resolve_flag(flag)
     }
-    
+
+    /// Validate a `CurlOption` against its definition, e.g. so the option
+    /// picker can surface an error before a command is ever run. An unknown
+    /// flag or a value-taking option with no value is an error; a flag
+    /// option is always valid regardless of `opt.value`.
+    pub fn validate(&self, opt: &CurlOption) -> Result<(), String> {
+        let def = self
+            .get_option(&opt.flag)
+            .ok_or_else(|| format!("unknown option \"{}\"", opt.flag))?;
+
+        if !def.takes_value {
+            return Ok(());
+        }
+
+        match &opt.value {
+            Some(value) => def.validate_value(value),
+            None => Err(format!("{} requires a value", def.flag)),
+        }
+    }
+
+    /// Check a set of selected options against each option's declared
+    /// `conflicts_with`/`requires` relations, resolving aliases (`-s` vs.
+    /// `--silent`) to their canonical flag before comparing. Lets the
+    /// picker warn about an invalid combination before a command is built.
+    pub fn check_selection(&self, selected: &[CurlOption]) -> Vec<SelectionIssue> {
+        let enabled: Vec<String> = selected
+            .iter()
+            .filter(|opt| opt.enabled)
+            .filter_map(|opt| self.get_option(&opt.flag).map(|def| def.flag.clone()))
+            .collect();
+
+        let mut issues = Vec::new();
+        let mut reported_conflicts = std::collections::HashSet::new();
+
+        for flag in &enabled {
+            let Some(def) = self.get_option(flag) else { continue };
+
+            for other in &def.conflicts_with {
+                if !enabled.contains(other) {
+                    continue;
+                }
+                let mut pair = [flag.clone(), other.clone()];
+                pair.sort();
+                if reported_conflicts.insert(pair.clone()) {
+                    issues.push(SelectionIssue::Conflict {
+                        flag: pair[0].clone(),
+                        conflicts_with: pair[1].clone(),
+                    });
+                }
+            }
+
+            for required in &def.requires {
+                if !enabled.contains(required) {
+                    issues.push(SelectionIssue::MissingRequirement {
+                        flag: flag.clone(),
+                        requires: required.clone(),
+                    });
+                }
+            }
+        }
+
+        issues
+    }
+
     /// Get all options in a category
     pub fn get_options_by_category(&self, category: &OptionCategory) -> Vec<&OptionDefinition> {
         self.options
@@ -120,6 +355,36 @@ impl CurlOptions {
             .collect()
     }
     
+    /// All option definitions, in no particular order
+    pub fn all_options(&self) -> Vec<&OptionDefinition> {
+        self.options.values().collect()
+    }
+
+    /// Offer flag completions for a partial flag the user is typing (e.g.
+    /// "--loc" completes to "--location"), matching against both short and
+    /// long forms
+    pub fn complete(&self, partial: &str) -> Vec<&OptionDefinition> {
+        if partial.is_empty() {
+            return Vec::new();
+        }
+
+        let mut matches: Vec<&OptionDefinition> = self
+            .options
+            .values()
+            .filter(|def| {
+                def.flag.starts_with(partial)
+                    || def
+                        .long_flag
+                        .as_deref()
+                        .map(|long| long.starts_with(partial))
+                        .unwrap_or(false)
+            })
+            .collect();
+
+        matches.sort_by(|a, b| a.flag.cmp(&b.flag));
+        matches
+    }
+
     /// Create a curl option from a definition
     pub fn create_option(&self, flag: &str) -> Option<CurlOption> {
         self.get_option(flag).map(|def| CurlOption {
@@ -129,7 +394,147 @@ impl CurlOptions {
             enabled: true,
         })
     }
-    
+
+    /// Inverse of `create_option`: tokenize a pasted curl invocation and
+    /// reconstruct the structured option list, resolving both short
+    /// (`-L`) and long (`--location`) forms via `get_option` and
+    /// splitting combined boolean short flags like `-sS`. Unless `-q` /
+    /// `--disable` is present, the implicit `~/.curlrc` is read first (if
+    /// it exists) so its options are layered beneath the command line's
+    /// own, the same way curl itself applies config before arguments; an
+    /// explicit `-K/--config FILE` is read the same way, in place.
+    pub fn parse_command(&self, input: &str) -> Vec<CurlOption> {
+        let tokens = crate::command::parser::tokenize(input);
+        let mut iter = tokens.into_iter().peekable();
+
+        if matches!(iter.peek().map(|s| s.as_str()), Some("curl")) {
+            iter.next();
+        }
+
+        // `-q`/`--disable` never takes a value, so a plain whitespace scan
+        // over the raw input is equivalent to (and cheaper than) checking
+        // for it while tokenizing
+        let disable_curlrc = input.split_whitespace().any(|t| t == "-q" || t == "--disable");
+
+        let mut options = Vec::new();
+        if !disable_curlrc {
+            if let Some(path) = default_curlrc_path() {
+                if let Ok(contents) = std::fs::read_to_string(&path) {
+                    options.extend(self.parse_curlrc(&contents));
+                }
+            }
+        }
+
+        while let Some(token) = iter.next() {
+            if token == "-K" || token == "--config" {
+                if let Some(path) = iter.next() {
+                    if let Ok(contents) = std::fs::read_to_string(&path) {
+                        options.extend(self.parse_curlrc(&contents));
+                    }
+                }
+                continue;
+            }
+
+            if !token.starts_with('-') || token == "-" {
+                continue;
+            }
+
+            if let Some(stripped) = token.strip_prefix("--") {
+                let (flag, inline_value) = match stripped.split_once('=') {
+                    Some((name, value)) => (format!("--{}", name), Some(value.to_string())),
+                    None => (token.clone(), None),
+                };
+                if let Some(def) = self.get_option(&flag) {
+                    let value = if def.takes_value { inline_value.or_else(|| iter.next()) } else { None };
+                    options.push(CurlOption {
+                        id: uuid::Uuid::new_v4().to_string(),
+                        flag: def.flag.clone(),
+                        value,
+                        enabled: true,
+                    });
+                }
+                continue;
+            }
+
+            let chars: Vec<char> = token.trim_start_matches('-').chars().collect();
+            let mut i = 0;
+            while i < chars.len() {
+                let flag = format!("-{}", chars[i]);
+                let Some(def) = self.get_option(&flag) else {
+                    i += 1;
+                    continue;
+                };
+                if def.takes_value {
+                    let rest: String = chars[i + 1..].iter().collect();
+                    let value = if rest.is_empty() { iter.next() } else { Some(rest) };
+                    options.push(CurlOption {
+                        id: uuid::Uuid::new_v4().to_string(),
+                        flag: def.flag.clone(),
+                        value,
+                        enabled: true,
+                    });
+                    break;
+                }
+                options.push(CurlOption {
+                    id: uuid::Uuid::new_v4().to_string(),
+                    flag: def.flag.clone(),
+                    value: None,
+                    enabled: true,
+                });
+                i += 1;
+            }
+        }
+
+        options
+    }
+
+    /// Parse a `.curlrc`-style config file's one-option-per-line entries
+    /// (leading dashes optional, `key = value` or `key value`, `#`
+    /// comments) into options, skipping lines whose key doesn't resolve
+    /// to a known definition
+    fn parse_curlrc(&self, contents: &str) -> Vec<CurlOption> {
+        let mut options = Vec::new();
+
+        for line in contents.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let (key, value) = match line.split_once('=') {
+                Some((key, value)) => (key.trim(), Some(value.trim())),
+                None => match line.split_once(char::is_whitespace) {
+                    Some((key, value)) => (key.trim(), Some(value.trim())),
+                    None => (line, None),
+                },
+            };
+
+            let flag = if key.starts_with('-') {
+                key.to_string()
+            } else if key.chars().count() == 1 {
+                format!("-{}", key)
+            } else {
+                format!("--{}", key)
+            };
+
+            if let Some(def) = self.get_option(&flag) {
+                let value = if def.takes_value {
+                    value.map(|v| v.trim_matches('"').to_string())
+                } else {
+                    None
+                };
+                options.push(CurlOption {
+                    id: uuid::Uuid::new_v4().to_string(),
+                    flag: def.flag.clone(),
+                    value,
+                    enabled: true,
+                });
+            }
+        }
+
+        options
+    }
+
     /// Predefined curl options
     fn predefined_options() -> Vec<OptionDefinition> {
         vec![
@@ -139,24 +544,36 @@ impl CurlOptions {
                 long_flag: Some("--progress-bar".to_string()),
                 description: "Display transfer progress as a bar".to_string(),
                 takes_value: false,
+                arg_kind: ArgumentKind::Flag,
                 category: OptionCategory::Basic,
                 tier: OptionTier::Basic,
+                repeatable: false,
+                conflicts_with: Vec::new(),
+                requires: Vec::new(),
             },
             OptionDefinition {
                 flag: "-L".to_string(),
                 long_flag: Some("--location".to_string()),
                 description: "Follow redirects".to_string(),
                 takes_value: false,
+                arg_kind: ArgumentKind::Flag,
                 category: OptionCategory::Basic,
                 tier: OptionTier::Basic,
+                repeatable: false,
+                conflicts_with: Vec::new(),
+                requires: Vec::new(),
             },
             OptionDefinition {
                 flag: "-f".to_string(),
                 long_flag: Some("--fail".to_string()),
                 description: "Fail silently on server errors".to_string(),
                 takes_value: false,
+                arg_kind: ArgumentKind::Flag,
                 category: OptionCategory::Basic,
                 tier: OptionTier::Basic,
+                repeatable: false,
+                conflicts_with: Vec::new(),
+                requires: Vec::new(),
             },
             
             // Request Options
@@ -165,82 +582,122 @@ impl CurlOptions {
                 long_flag: Some("--request".to_string()),
                 description: "HTTP method to use".to_string(),
                 takes_value: true,
+                arg_kind: ArgumentKind::HttpMethod,
                 category: OptionCategory::Request,
                 tier: OptionTier::Basic,
+                repeatable: false,
+                conflicts_with: Vec::new(),
+                requires: Vec::new(),
             },
             OptionDefinition {
                 flag: "-d".to_string(),
                 long_flag: Some("--data".to_string()),
                 description: "HTTP POST data".to_string(),
                 takes_value: true,
+                arg_kind: ArgumentKind::Text,
                 category: OptionCategory::Request,
                 tier: OptionTier::Basic,
+                repeatable: false,
+                conflicts_with: Vec::new(),
+                requires: Vec::new(),
             },
             OptionDefinition {
                 flag: "--data-binary".to_string(),
                 long_flag: None,
                 description: "HTTP POST binary data".to_string(),
                 takes_value: true,
+                arg_kind: ArgumentKind::FilePath,
                 category: OptionCategory::Request,
                 tier: OptionTier::Advanced,
+                repeatable: false,
+                conflicts_with: Vec::new(),
+                requires: Vec::new(),
             },
             OptionDefinition {
                 flag: "--data-urlencode".to_string(),
                 long_flag: None,
                 description: "HTTP POST data url encoded".to_string(),
                 takes_value: true,
+                arg_kind: ArgumentKind::Text,
                 category: OptionCategory::Request,
                 tier: OptionTier::Advanced,
+                repeatable: false,
+                conflicts_with: Vec::new(),
+                requires: Vec::new(),
             },
             OptionDefinition {
                 flag: "-F".to_string(),
                 long_flag: Some("--form".to_string()),
                 description: "Specify multipart MIME data".to_string(),
                 takes_value: true,
+                arg_kind: ArgumentKind::Text,
                 category: OptionCategory::Request,
                 tier: OptionTier::Advanced,
+                repeatable: false,
+                conflicts_with: Vec::new(),
+                requires: Vec::new(),
             },
-            
+
             // Authentication Options
             OptionDefinition {
                 flag: "-u".to_string(),
                 long_flag: Some("--user".to_string()),
                 description: "Server user and password".to_string(),
                 takes_value: true,
+                arg_kind: ArgumentKind::Text,
                 category: OptionCategory::Authentication,
                 tier: OptionTier::Basic,
+                repeatable: false,
+                conflicts_with: Vec::new(),
+                requires: Vec::new(),
             },
             OptionDefinition {
                 flag: "--basic".to_string(),
                 long_flag: None,
                 description: "Use HTTP Basic Authentication".to_string(),
                 takes_value: false,
+                arg_kind: ArgumentKind::Flag,
                 category: OptionCategory::Authentication,
                 tier: OptionTier::Basic,
+                repeatable: false,
+                conflicts_with: vec!["--digest".to_string(), "--ntlm".to_string()],
+                requires: Vec::new(),
             },
             OptionDefinition {
                 flag: "--digest".to_string(),
                 long_flag: None,
                 description: "Use HTTP Digest Authentication".to_string(),
                 takes_value: false,
+                arg_kind: ArgumentKind::Flag,
                 category: OptionCategory::Authentication,
                 tier: OptionTier::Advanced,
+                repeatable: false,
+                conflicts_with: vec!["--basic".to_string(), "--ntlm".to_string()],
+                requires: Vec::new(),
             },
             OptionDefinition {
                 flag: "--ntlm".to_string(),
                 long_flag: None,
                 description: "Use HTTP NTLM authentication".to_string(),
                 takes_value: false,
+                arg_kind: ArgumentKind::Flag,
                 category: OptionCategory::Authentication,
                 tier: OptionTier::Advanced,
+                repeatable: false,
+                conflicts_with: vec!["--basic".to_string(), "--digest".to_string()],
+                requires: Vec::new(),
             },
             OptionDefinition {
                 flag: "--oauth2-bearer".to_string(),
                 long_flag: None,
                 description: "OAuth 2 Bearer Token".to_string(),
                 takes_value: true,
+                arg_kind: ArgumentKind::Text,
                 category: OptionCategory::Authentication,
                 tier: OptionTier::Basic,
+                repeatable: false,
+                conflicts_with: Vec::new(),
+                requires: Vec::new(),
             },
             
             // Connection Options
@@ -249,82 +706,134 @@ impl CurlOptions {
                 long_flag: Some("--insecure".to_string()),
                 description: "Allow insecure server connections".to_string(),
                 takes_value: false,
+                arg_kind: ArgumentKind::Flag,
                 category: OptionCategory::Connection,
                 tier: OptionTier::Basic,
+                repeatable: false,
+                conflicts_with: Vec::new(),
+                requires: Vec::new(),
             },
             OptionDefinition {
                 flag: "--connect-timeout".to_string(),
                 long_flag: None,
                 description: "Maximum time allowed for connection".to_string(),
                 takes_value: true,
+                arg_kind: ArgumentKind::Duration,
                 category: OptionCategory::Connection,
                 tier: OptionTier::Basic,
+                repeatable: false,
+                conflicts_with: Vec::new(),
+                requires: Vec::new(),
             },
             OptionDefinition {
                 flag: "--max-time".to_string(),
                 long_flag: Some("-m".to_string()),
                 description: "Maximum time allowed for the transfer".to_string(),
                 takes_value: true,
+                arg_kind: ArgumentKind::Duration,
                 category: OptionCategory::Connection,
                 tier: OptionTier::Basic,
+                repeatable: false,
+                conflicts_with: Vec::new(),
+                requires: Vec::new(),
             },
             OptionDefinition {
                 flag: "-4".to_string(),
                 long_flag: Some("--ipv4".to_string()),
                 description: "Resolve names to IPv4 addresses".to_string(),
                 takes_value: false,
+                arg_kind: ArgumentKind::Flag,
                 category: OptionCategory::Connection,
                 tier: OptionTier::Advanced,
+                repeatable: false,
+                conflicts_with: Vec::new(),
+                requires: Vec::new(),
             },
             OptionDefinition {
                 flag: "-6".to_string(),
                 long_flag: Some("--ipv6".to_string()),
                 description: "Resolve names to IPv6 addresses".to_string(),
                 takes_value: false,
+                arg_kind: ArgumentKind::Flag,
                 category: OptionCategory::Connection,
                 tier: OptionTier::Advanced,
+                repeatable: false,
+                conflicts_with: Vec::new(),
+                requires: Vec::new(),
             },
-            
+            OptionDefinition {
+                flag: "--resolve".to_string(),
+                long_flag: None,
+                description: "Provide a custom host:port:address resolution, bypassing DNS for that host".to_string(),
+                takes_value: true,
+                arg_kind: ArgumentKind::ResolveEntry,
+                category: OptionCategory::Connection,
+                tier: OptionTier::Advanced,
+                repeatable: true,
+                conflicts_with: Vec::new(),
+                requires: Vec::new(),
+            },
+
             // Header Options
             OptionDefinition {
                 flag: "-H".to_string(),
                 long_flag: Some("--header".to_string()),
                 description: "Pass custom header(s) to server".to_string(),
                 takes_value: true,
+                arg_kind: ArgumentKind::HeaderString,
                 category: OptionCategory::Header,
                 tier: OptionTier::Basic,
+                repeatable: false,
+                conflicts_with: Vec::new(),
+                requires: Vec::new(),
             },
             OptionDefinition {
                 flag: "-A".to_string(),
                 long_flag: Some("--user-agent".to_string()),
                 description: "Send User-Agent to server".to_string(),
                 takes_value: true,
+                arg_kind: ArgumentKind::Text,
                 category: OptionCategory::Header,
                 tier: OptionTier::Basic,
+                repeatable: false,
+                conflicts_with: Vec::new(),
+                requires: Vec::new(),
             },
             OptionDefinition {
                 flag: "-e".to_string(),
                 long_flag: Some("--referer".to_string()),
                 description: "Referer URL".to_string(),
                 takes_value: true,
+                arg_kind: ArgumentKind::Url,
                 category: OptionCategory::Header,
                 tier: OptionTier::Basic,
+                repeatable: false,
+                conflicts_with: Vec::new(),
+                requires: Vec::new(),
             },
             OptionDefinition {
                 flag: "-b".to_string(),
                 long_flag: Some("--cookie".to_string()),
                 description: "Send cookies from string/file".to_string(),
                 takes_value: true,
+                arg_kind: ArgumentKind::FilePath,
                 category: OptionCategory::Header,
                 tier: OptionTier::Basic,
+                repeatable: false,
+                conflicts_with: Vec::new(),
+                requires: Vec::new(),
             },
             OptionDefinition {
                 flag: "-c".to_string(),
                 long_flag: Some("--cookie-jar".to_string()),
                 description: "Write cookies to file after operation".to_string(),
                 takes_value: true,
+                arg_kind: ArgumentKind::FilePath,
                 category: OptionCategory::Header,
                 tier: OptionTier::Advanced,
+                repeatable: false,
+                conflicts_with: Vec::new(),
+                requires: Vec::new(),
             },
             
             // SSL/TLS Options
@@ -333,40 +842,65 @@ impl CurlOptions {
                 long_flag: None,
                 description: "CA certificate to verify peer against".to_string(),
                 takes_value: true,
+                arg_kind: ArgumentKind::FilePath,
                 category: OptionCategory::Ssl,
                 tier: OptionTier::Advanced,
+                repeatable: false,
+                conflicts_with: Vec::new(),
+                requires: Vec::new(),
             },
             OptionDefinition {
                 flag: "--cert".to_string(),
                 long_flag: None,
                 description: "Client certificate file".to_string(),
                 takes_value: true,
+                arg_kind: ArgumentKind::FilePath,
                 category: OptionCategory::Ssl,
                 tier: OptionTier::Advanced,
+                repeatable: false,
+                conflicts_with: Vec::new(),
+                requires: vec!["--key".to_string()],
             },
             OptionDefinition {
                 flag: "--key".to_string(),
                 long_flag: None,
                 description: "Private key file name".to_string(),
                 takes_value: true,
+                arg_kind: ArgumentKind::FilePath,
                 category: OptionCategory::Ssl,
                 tier: OptionTier::Advanced,
+                repeatable: false,
+                conflicts_with: Vec::new(),
+                requires: vec!["--cert".to_string()],
             },
             OptionDefinition {
                 flag: "--ciphers".to_string(),
                 long_flag: None,
                 description: "SSL ciphers to use".to_string(),
                 takes_value: true,
+                arg_kind: ArgumentKind::Text,
                 category: OptionCategory::Ssl,
                 tier: OptionTier::Expert,
+                repeatable: false,
+                conflicts_with: Vec::new(),
+                requires: Vec::new(),
             },
             OptionDefinition {
                 flag: "--tls-max".to_string(),
                 long_flag: None,
                 description: "Set maximum allowed TLS version".to_string(),
                 takes_value: true,
+                arg_kind: ArgumentKind::Enum(vec![
+                    "1.0".to_string(),
+                    "1.1".to_string(),
+                    "1.2".to_string(),
+                    "1.3".to_string(),
+                ]),
                 category: OptionCategory::Ssl,
                 tier: OptionTier::Expert,
+                repeatable: false,
+                conflicts_with: Vec::new(),
+                requires: Vec::new(),
             },
             
             // Proxy Options
@@ -375,82 +909,182 @@ impl CurlOptions {
                 long_flag: Some("--proxy".to_string()),
                 description: "Use proxy".to_string(),
                 takes_value: true,
+                arg_kind: ArgumentKind::Url,
                 category: OptionCategory::Proxy,
                 tier: OptionTier::Basic,
+                repeatable: false,
+                conflicts_with: Vec::new(),
+                requires: Vec::new(),
             },
             OptionDefinition {
                 flag: "--proxy-basic".to_string(),
                 long_flag: None,
                 description: "Use Basic authentication on the proxy".to_string(),
                 takes_value: false,
+                arg_kind: ArgumentKind::Flag,
                 category: OptionCategory::Proxy,
                 tier: OptionTier::Advanced,
+                repeatable: false,
+                conflicts_with: Vec::new(),
+                requires: vec!["-x".to_string()],
             },
             OptionDefinition {
                 flag: "--proxy-digest".to_string(),
                 long_flag: None,
                 description: "Use Digest authentication on the proxy".to_string(),
                 takes_value: false,
+                arg_kind: ArgumentKind::Flag,
                 category: OptionCategory::Proxy,
                 tier: OptionTier::Advanced,
+                repeatable: false,
+                conflicts_with: Vec::new(),
+                requires: vec!["-x".to_string()],
             },
             OptionDefinition {
                 flag: "--noproxy".to_string(),
                 long_flag: None,
                 description: "List of hosts which do not use proxy".to_string(),
                 takes_value: true,
+                arg_kind: ArgumentKind::Text,
                 category: OptionCategory::Proxy,
                 tier: OptionTier::Advanced,
+                repeatable: false,
+                conflicts_with: Vec::new(),
+                requires: Vec::new(),
             },
             OptionDefinition {
                 flag: "-p".to_string(),
                 long_flag: Some("--proxytunnel".to_string()),
                 description: "Operate through an HTTP proxy tunnel (using CONNECT)".to_string(),
                 takes_value: false,
+                arg_kind: ArgumentKind::Flag,
                 category: OptionCategory::Proxy,
                 tier: OptionTier::Advanced,
+                repeatable: false,
+                conflicts_with: Vec::new(),
+                requires: Vec::new(),
             },
-            
+            OptionDefinition {
+                flag: "--socks4".to_string(),
+                long_flag: None,
+                description: "Use SOCKS4 proxy".to_string(),
+                takes_value: true,
+                arg_kind: ArgumentKind::Text,
+                category: OptionCategory::Proxy,
+                tier: OptionTier::Advanced,
+                repeatable: false,
+                conflicts_with: Vec::new(),
+                requires: Vec::new(),
+            },
+            OptionDefinition {
+                flag: "--socks5".to_string(),
+                long_flag: None,
+                description: "Use SOCKS5 proxy, resolving hostnames locally".to_string(),
+                takes_value: true,
+                arg_kind: ArgumentKind::Text,
+                category: OptionCategory::Proxy,
+                tier: OptionTier::Advanced,
+                repeatable: false,
+                conflicts_with: Vec::new(),
+                requires: Vec::new(),
+            },
+            OptionDefinition {
+                flag: "--socks5-hostname".to_string(),
+                long_flag: None,
+                description: "Use SOCKS5 proxy, resolving hostnames on the proxy".to_string(),
+                takes_value: true,
+                arg_kind: ArgumentKind::Text,
+                category: OptionCategory::Proxy,
+                tier: OptionTier::Advanced,
+                repeatable: false,
+                conflicts_with: Vec::new(),
+                requires: Vec::new(),
+            },
+            OptionDefinition {
+                flag: "--preproxy".to_string(),
+                long_flag: None,
+                description: "Use a SOCKS proxy before connecting to the real --proxy".to_string(),
+                takes_value: true,
+                arg_kind: ArgumentKind::Text,
+                category: OptionCategory::Proxy,
+                tier: OptionTier::Expert,
+                repeatable: false,
+                conflicts_with: Vec::new(),
+                requires: Vec::new(),
+            },
+            OptionDefinition {
+                flag: "--proxy-header".to_string(),
+                long_flag: None,
+                description: "Pass custom header(s) to the proxy, the proxy-side analog of -H".to_string(),
+                takes_value: true,
+                arg_kind: ArgumentKind::HeaderString,
+                category: OptionCategory::Proxy,
+                tier: OptionTier::Expert,
+                repeatable: true,
+                conflicts_with: Vec::new(),
+                requires: Vec::new(),
+            },
+
             // Output Options
             OptionDefinition {
                 flag: "-o".to_string(),
                 long_flag: Some("--output".to_string()),
                 description: "Write to file instead of stdout".to_string(),
                 takes_value: true,
+                arg_kind: ArgumentKind::FilePath,
                 category: OptionCategory::Output,
                 tier: OptionTier::Basic,
+                repeatable: false,
+                conflicts_with: vec!["-O".to_string()],
+                requires: Vec::new(),
             },
             OptionDefinition {
                 flag: "-O".to_string(),
                 long_flag: Some("--remote-name".to_string()),
                 description: "Write output to a file named as the remote file".to_string(),
                 takes_value: false,
+                arg_kind: ArgumentKind::Flag,
                 category: OptionCategory::Output,
                 tier: OptionTier::Basic,
+                repeatable: false,
+                conflicts_with: vec!["-o".to_string()],
+                requires: Vec::new(),
             },
             OptionDefinition {
                 flag: "-J".to_string(),
                 long_flag: Some("--remote-header-name".to_string()),
                 description: "Use the header-provided filename".to_string(),
                 takes_value: false,
+                arg_kind: ArgumentKind::Flag,
                 category: OptionCategory::Output,
                 tier: OptionTier::Advanced,
+                repeatable: false,
+                conflicts_with: Vec::new(),
+                requires: vec!["-O".to_string()],
             },
             OptionDefinition {
                 flag: "--create-dirs".to_string(),
                 long_flag: None,
                 description: "Create necessary local directory hierarchy".to_string(),
                 takes_value: false,
+                arg_kind: ArgumentKind::Flag,
                 category: OptionCategory::Output,
                 tier: OptionTier::Advanced,
+                repeatable: false,
+                conflicts_with: Vec::new(),
+                requires: Vec::new(),
             },
             OptionDefinition {
                 flag: "-w".to_string(),
                 long_flag: Some("--write-out".to_string()),
                 description: "Use output FORMAT after completion".to_string(),
                 takes_value: true,
+                arg_kind: ArgumentKind::Text,
                 category: OptionCategory::Output,
                 tier: OptionTier::Advanced,
+                repeatable: false,
+                conflicts_with: Vec::new(),
+                requires: Vec::new(),
             },
             
             // Command Line Options
@@ -459,96 +1093,218 @@ impl CurlOptions {
                 long_flag: Some("--verbose".to_string()),
                 description: "Make the operation more talkative".to_string(),
                 takes_value: false,
+                arg_kind: ArgumentKind::Flag,
                 category: OptionCategory::CommandLine,
                 tier: OptionTier::Basic,
+                repeatable: false,
+                conflicts_with: Vec::new(),
+                requires: Vec::new(),
             },
             OptionDefinition {
                 flag: "-s".to_string(),
                 long_flag: Some("--silent".to_string()),
                 description: "Silent mode".to_string(),
                 takes_value: false,
+                arg_kind: ArgumentKind::Flag,
                 category: OptionCategory::CommandLine,
                 tier: OptionTier::Basic,
+                repeatable: false,
+                conflicts_with: Vec::new(),
+                requires: Vec::new(),
             },
             OptionDefinition {
                 flag: "-S".to_string(),
                 long_flag: Some("--show-error".to_string()),
                 description: "Show error even when silent".to_string(),
                 takes_value: false,
+                arg_kind: ArgumentKind::Flag,
                 category: OptionCategory::CommandLine,
                 tier: OptionTier::Basic,
+                repeatable: false,
+                conflicts_with: Vec::new(),
+                requires: Vec::new(),
             },
             OptionDefinition {
                 flag: "-i".to_string(),
                 long_flag: Some("--include".to_string()),
                 description: "Include protocol response headers in the output".to_string(),
                 takes_value: false,
+                arg_kind: ArgumentKind::Flag,
                 category: OptionCategory::CommandLine,
                 tier: OptionTier::Basic,
+                repeatable: false,
+                conflicts_with: Vec::new(),
+                requires: Vec::new(),
             },
             OptionDefinition {
                 flag: "-I".to_string(),
                 long_flag: Some("--head".to_string()),
                 description: "Show document info only".to_string(),
                 takes_value: false,
+                arg_kind: ArgumentKind::Flag,
                 category: OptionCategory::CommandLine,
                 tier: OptionTier::Basic,
+                repeatable: false,
+                conflicts_with: Vec::new(),
+                requires: Vec::new(),
             },
             OptionDefinition {
                 flag: "-q".to_string(),
                 long_flag: Some("--disable".to_string()),
                 description: "Disable .curlrc".to_string(),
                 takes_value: false,
+                arg_kind: ArgumentKind::Flag,
                 category: OptionCategory::CommandLine,
                 tier: OptionTier::Basic,
+                repeatable: false,
+                conflicts_with: Vec::new(),
+                requires: Vec::new(),
             },
             OptionDefinition {
                 flag: "-V".to_string(),
                 long_flag: Some("--version".to_string()),
                 description: "Show version number and quit".to_string(),
                 takes_value: false,
+                arg_kind: ArgumentKind::Flag,
                 category: OptionCategory::CommandLine,
                 tier: OptionTier::Basic,
+                repeatable: false,
+                conflicts_with: Vec::new(),
+                requires: Vec::new(),
             },
             OptionDefinition {
                 flag: "-h".to_string(),
                 long_flag: Some("--help".to_string()),
                 description: "Show help text".to_string(),
                 takes_value: false,
+                arg_kind: ArgumentKind::Flag,
                 category: OptionCategory::CommandLine,
                 tier: OptionTier::Basic,
+                repeatable: false,
+                conflicts_with: Vec::new(),
+                requires: Vec::new(),
             },
             OptionDefinition {
                 flag: "--trace".to_string(),
                 long_flag: None,
                 description: "Write a debug trace to FILE".to_string(),
                 takes_value: true,
+                arg_kind: ArgumentKind::FilePath,
                 category: OptionCategory::CommandLine,
                 tier: OptionTier::Basic,
+                repeatable: false,
+                conflicts_with: Vec::new(),
+                requires: Vec::new(),
             },
             OptionDefinition {
                 flag: "--trace-ascii".to_string(),
                 long_flag: None,
                 description: "Like --trace, but without hex output".to_string(),
                 takes_value: true,
+                arg_kind: ArgumentKind::FilePath,
                 category: OptionCategory::CommandLine,
                 tier: OptionTier::Basic,
+                repeatable: false,
+                conflicts_with: Vec::new(),
+                requires: Vec::new(),
             },
             OptionDefinition {
                 flag: "--trace-time".to_string(),
                 long_flag: None,
                 description: "Add time stamps to trace/verbose output".to_string(),
                 takes_value: false,
+                arg_kind: ArgumentKind::Flag,
                 category: OptionCategory::CommandLine,
                 tier: OptionTier::Basic,
+                repeatable: false,
+                conflicts_with: Vec::new(),
+                requires: Vec::new(),
             },
             OptionDefinition {
                 flag: "-K".to_string(),
                 long_flag: Some("--config".to_string()),
                 description: "Read config from a file".to_string(),
                 takes_value: true,
+                arg_kind: ArgumentKind::FilePath,
                 category: OptionCategory::CommandLine,
                 tier: OptionTier::Basic,
+                repeatable: false,
+                conflicts_with: Vec::new(),
+                requires: Vec::new(),
+            },
+
+            // Protocol Options
+            OptionDefinition {
+                flag: "--http1.1".to_string(),
+                long_flag: None,
+                description: "Use HTTP 1.1".to_string(),
+                takes_value: false,
+                arg_kind: ArgumentKind::Flag,
+                category: OptionCategory::Protocol,
+                tier: OptionTier::Basic,
+                repeatable: false,
+                conflicts_with: Vec::new(),
+                requires: Vec::new(),
+            },
+            OptionDefinition {
+                flag: "--http2".to_string(),
+                long_flag: None,
+                description: "Use HTTP 2".to_string(),
+                takes_value: false,
+                arg_kind: ArgumentKind::Flag,
+                category: OptionCategory::Protocol,
+                tier: OptionTier::Basic,
+                repeatable: false,
+                conflicts_with: Vec::new(),
+                requires: Vec::new(),
+            },
+            OptionDefinition {
+                flag: "--http2-prior-knowledge".to_string(),
+                long_flag: None,
+                description: "Use HTTP 2 without HTTP/1.1 Upgrade, i.e. HTTP/2 over cleartext (h2c); implies no TLS upgrade".to_string(),
+                takes_value: false,
+                arg_kind: ArgumentKind::Flag,
+                category: OptionCategory::Protocol,
+                tier: OptionTier::Expert,
+                repeatable: false,
+                conflicts_with: Vec::new(),
+                requires: Vec::new(),
+            },
+            OptionDefinition {
+                flag: "--http3".to_string(),
+                long_flag: None,
+                description: "Use HTTP 3".to_string(),
+                takes_value: false,
+                arg_kind: ArgumentKind::Flag,
+                category: OptionCategory::Protocol,
+                tier: OptionTier::Advanced,
+                repeatable: false,
+                conflicts_with: Vec::new(),
+                requires: Vec::new(),
+            },
+            OptionDefinition {
+                flag: "--compressed".to_string(),
+                long_flag: None,
+                description: "Request a compressed response and automatically decompress it".to_string(),
+                takes_value: false,
+                arg_kind: ArgumentKind::Flag,
+                category: OptionCategory::Protocol,
+                tier: OptionTier::Basic,
+                repeatable: false,
+                conflicts_with: Vec::new(),
+                requires: Vec::new(),
+            },
+            OptionDefinition {
+                flag: "--tr-encoding".to_string(),
+                long_flag: None,
+                description: "Request compressed transfer encoding".to_string(),
+                takes_value: false,
+                arg_kind: ArgumentKind::Flag,
+                category: OptionCategory::Protocol,
+                tier: OptionTier::Advanced,
+                repeatable: false,
+                conflicts_with: Vec::new(),
+                requires: Vec::new(),
             },
         ]
     }
@@ -558,4 +1314,202 @@ impl Default for CurlOptions {
     fn default() -> Self {
         Self::new()
     }
+}
+
+/// Path to the implicit `.curlrc` curl itself would read, honored by
+/// `CurlOptions::parse_command` unless `-q`/`--disable` is present
+fn default_curlrc_path() -> Option<std::path::PathBuf> {
+    let home = std::env::var("HOME").ok()?;
+    Some(std::path::PathBuf::from(home).join(".curlrc"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_complete_matches_short_and_long_flags() {
+        let options = CurlOptions::new();
+        let matches = options.complete("--loc");
+        assert!(matches.iter().any(|def| def.flag == "-L"));
+
+        let matches = options.complete("-X");
+        assert!(matches.iter().any(|def| def.flag == "-X"));
+    }
+
+    #[test]
+    fn test_validate_header_string() {
+        let options = CurlOptions::new();
+        let header_def = options.get_option("-H").unwrap();
+        assert!(header_def.validate_value("Content-Type: application/json").is_ok());
+        assert!(header_def.validate_value("missing-colon").is_err());
+    }
+
+    #[test]
+    fn test_validate_duration_rejects_negative() {
+        let options = CurlOptions::new();
+        let timeout_def = options.get_option("--max-time").unwrap();
+        assert!(timeout_def.validate_value("30").is_ok());
+        assert!(timeout_def.validate_value("0.5").is_ok());
+        assert!(timeout_def.validate_value("-1").is_err());
+        assert!(timeout_def.validate_value("not-a-number").is_err());
+    }
+
+    #[test]
+    fn test_validate_tls_max_enum_accepts_only_listed_versions() {
+        let options = CurlOptions::new();
+        let tls_max_def = options.get_option("--tls-max").unwrap();
+        assert!(tls_max_def.validate_value("1.2").is_ok());
+        assert!(tls_max_def.validate_value("1.3").is_ok());
+        assert!(tls_max_def.validate_value("not-a-version").is_err());
+    }
+
+    #[test]
+    fn test_check_selection_reports_conflicts_and_missing_requirements() {
+        use crate::models::command::CurlOption;
+
+        let options = CurlOptions::new();
+        let selected = vec![
+            CurlOption { id: "1".to_string(), flag: "--basic".to_string(), value: None, enabled: true },
+            CurlOption { id: "2".to_string(), flag: "--digest".to_string(), value: None, enabled: true },
+            CurlOption { id: "3".to_string(), flag: "--proxy-basic".to_string(), value: None, enabled: true },
+        ];
+
+        let issues = options.check_selection(&selected);
+        assert!(issues.contains(&SelectionIssue::Conflict {
+            flag: "--basic".to_string(),
+            conflicts_with: "--digest".to_string(),
+        }));
+        assert!(issues.contains(&SelectionIssue::MissingRequirement {
+            flag: "--proxy-basic".to_string(),
+            requires: "-x".to_string(),
+        }));
+    }
+
+    #[test]
+    fn test_check_selection_is_empty_for_compatible_options() {
+        use crate::models::command::CurlOption;
+
+        let options = CurlOptions::new();
+        let selected = vec![
+            CurlOption { id: "1".to_string(), flag: "--proxy-basic".to_string(), value: None, enabled: true },
+            CurlOption { id: "2".to_string(), flag: "-x".to_string(), value: Some("http://proxy.example.com".to_string()), enabled: true },
+        ];
+
+        assert!(options.check_selection(&selected).is_empty());
+    }
+
+    #[test]
+    fn test_curl_options_validate_rejects_missing_value_and_unknown_flag() {
+        use crate::models::command::CurlOption;
+
+        let options = CurlOptions::new();
+        let missing_value = CurlOption { id: "1".to_string(), flag: "--max-time".to_string(), value: None, enabled: true };
+        assert!(options.validate(&missing_value).is_err());
+
+        let unknown_flag = CurlOption { id: "2".to_string(), flag: "--not-a-real-flag".to_string(), value: None, enabled: true };
+        assert!(options.validate(&unknown_flag).is_err());
+
+        let valid = CurlOption { id: "3".to_string(), flag: "--max-time".to_string(), value: Some("30".to_string()), enabled: true };
+        assert!(options.validate(&valid).is_ok());
+    }
+
+    #[test]
+    fn test_validate_http_method() {
+        let options = CurlOptions::new();
+        let method_def = options.get_option("-X").unwrap();
+        assert!(method_def.validate_value("POST").is_ok());
+        assert!(method_def.validate_value("FOOBAR").is_err());
+    }
+
+    #[test]
+    fn test_get_option_matches_short_and_long_form() {
+        let options = CurlOptions::new();
+        assert_eq!(options.get_option("-k").unwrap().flag, "-k");
+        assert_eq!(options.get_option("--insecure").unwrap().flag, "-k");
+        assert!(options.get_option("--not-a-real-flag").is_none());
+    }
+
+    #[test]
+    fn test_resolve_flag_is_case_insensitive_for_long_forms_only() {
+        let options = CurlOptions::new();
+        assert_eq!(options.resolve_flag("--INSECURE").unwrap().flag, "-k");
+        assert_eq!(options.resolve_flag("--Location").unwrap().flag, "-L");
+        // Short flags stay case-sensitive: -o and -O are different options
+        assert_eq!(options.resolve_flag("-o").unwrap().flag, "-o");
+        assert_eq!(options.resolve_flag("-O").unwrap().flag, "-O");
+    }
+
+    #[test]
+    fn test_resolve_flag_handles_reversed_short_and_long_storage() {
+        // --max-time stores its short form "-m" in `long_flag`, the reverse
+        // of most definitions; resolve_flag must not care which way round it is
+        let options = CurlOptions::new();
+        assert_eq!(options.resolve_flag("-m").unwrap().flag, "--max-time");
+        assert_eq!(options.resolve_flag("--max-time").unwrap().flag, "--max-time");
+    }
+
+    #[test]
+    fn test_resolve_option_is_repeatable() {
+        let options = CurlOptions::new();
+        let resolve_def = options.get_option("--resolve").unwrap();
+        assert!(resolve_def.repeatable);
+    }
+
+    #[test]
+    fn test_parse_command_resolves_long_and_splits_short_flags() {
+        let options = CurlOptions::new();
+        let parsed = options.parse_command("curl -q -sS -X POST --location https://example.com");
+
+        assert!(parsed.iter().any(|o| o.flag == "-s"));
+        assert!(parsed.iter().any(|o| o.flag == "-S"));
+        assert_eq!(parsed.iter().find(|o| o.flag == "-X").unwrap().value, Some("POST".to_string()));
+        assert!(parsed.iter().any(|o| o.flag == "-L"));
+    }
+
+    #[test]
+    fn test_parse_command_reads_explicit_config_file() {
+        let options = CurlOptions::new();
+        let mut path = std::env::temp_dir();
+        path.push(format!("lazycurl-test-curlrc-{}", uuid::Uuid::new_v4()));
+        std::fs::write(&path, "max-time = 30\nlocation\n").unwrap();
+
+        let parsed = options.parse_command(&format!("curl -q -K {} https://example.com", path.display()));
+
+        assert_eq!(parsed.iter().find(|o| o.flag == "--max-time").unwrap().value, Some("30".to_string()));
+        assert!(parsed.iter().any(|o| o.flag == "-L"));
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_proxy_category_includes_socks_and_proxy_header() {
+        let options = CurlOptions::new();
+        let proxy_options = options.get_options_by_category(&OptionCategory::Proxy);
+        assert!(proxy_options.iter().any(|def| def.flag == "--socks5"));
+        assert!(proxy_options.iter().any(|def| def.flag == "--socks5-hostname"));
+        assert!(proxy_options.iter().any(|def| def.flag == "--preproxy"));
+        assert!(proxy_options.iter().any(|def| def.flag == "--proxy-header" && def.repeatable));
+    }
+
+    #[test]
+    fn test_protocol_category_includes_http_version_flags() {
+        let options = CurlOptions::new();
+        let protocol_options = options.get_options_by_category(&OptionCategory::Protocol);
+        assert!(protocol_options.iter().any(|def| def.flag == "--http2"));
+        assert!(protocol_options.iter().any(|def| def.flag == "--http3"));
+        assert!(protocol_options.iter().any(|def| def.flag == "--http2-prior-knowledge"));
+        assert!(protocol_options.iter().any(|def| def.flag == "--compressed"));
+    }
+
+    #[test]
+    fn test_validate_resolve_entry() {
+        let options = CurlOptions::new();
+        let resolve_def = options.get_option("--resolve").unwrap();
+        assert!(resolve_def.validate_value("example.com:443:127.0.0.1").is_ok());
+        assert!(resolve_def.validate_value("+example.com:443:127.0.0.1").is_ok());
+        assert!(resolve_def.validate_value("-example.com:443:127.0.0.1").is_ok());
+        assert!(resolve_def.validate_value("example.com:not-a-port:127.0.0.1").is_err());
+        assert!(resolve_def.validate_value("example.com:443").is_err());
+    }
 }
\ No newline at end of file
@@ -0,0 +1,523 @@
+use crate::command::options::CurlOptions;
+use crate::models::command::{Auth, CurlCommand, FormFieldKind, HttpMethod, RequestBody};
+
+/// Tokenize a raw curl invocation, honoring POSIX-style shell quoting
+/// (single quotes literal, double quotes with escape handling) and
+/// backslash line-continuations.
+pub fn tokenize(input: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    let mut has_current = false;
+    let mut in_single = false;
+    let mut in_double = false;
+    let mut chars = input.chars().peekable();
+
+    while let Some(ch) = chars.next() {
+        match ch {
+            '\'' if !in_double => {
+                in_single = !in_single;
+                has_current = true;
+            }
+            '"' if !in_single => {
+                in_double = !in_double;
+                has_current = true;
+            }
+            '\\' if in_single => {
+                // Single quotes take everything literally, including backslashes
+                current.push(ch);
+            }
+            '\\' if in_double => {
+                match chars.peek() {
+                    Some('"') | Some('\\') | Some('$') | Some('`') => {
+                        current.push(chars.next().unwrap());
+                    }
+                    _ => current.push(ch),
+                }
+            }
+            '\\' if !in_single && !in_double => {
+                match chars.peek() {
+                    // Backslash line-continuation: swallow the backslash, newline
+                    // and any leading whitespace on the next line
+                    Some('\n') => {
+                        chars.next();
+                        while matches!(chars.peek(), Some(' ') | Some('\t')) {
+                            chars.next();
+                        }
+                    }
+                    Some(&next_ch) => {
+                        current.push(next_ch);
+                        chars.next();
+                        has_current = true;
+                    }
+                    None => {}
+                }
+            }
+            c if c.is_whitespace() && !in_single && !in_double => {
+                if has_current {
+                    tokens.push(std::mem::take(&mut current));
+                    has_current = false;
+                }
+            }
+            c => {
+                current.push(c);
+                has_current = true;
+            }
+        }
+    }
+
+    if has_current || !current.is_empty() {
+        tokens.push(current);
+    }
+
+    tokens
+}
+
+/// Split a `--flag=value` style token into its flag and value, if present
+fn split_long_option(token: &str) -> (String, Option<String>) {
+    if token.starts_with("--") {
+        if let Some(eq_pos) = token.find('=') {
+            return (token[..eq_pos].to_string(), Some(token[eq_pos + 1..].to_string()));
+        }
+    }
+    (token.to_string(), None)
+}
+
+/// Parse a raw `curl ...` command string into a [`CurlCommand`], the inverse
+/// of [`crate::command::builder::CommandBuilder::build`]. Flags this parser
+/// doesn't recognize are kept rather than dropped: as a valueless option, or
+/// verbatim in `extra_args` when written `--flag=value`. A separate token
+/// after an unrecognized flag is read as a bare argument, not as its value.
+pub fn parse(input: &str) -> CurlCommand {
+    let known_options = CurlOptions::new();
+    let mut command = CurlCommand::default();
+    command.method = None;
+
+    let tokens = tokenize(input);
+    let mut iter = tokens.into_iter().peekable();
+
+    // Skip a leading "curl" token, if present
+    if matches!(iter.peek().map(|s| s.as_str()), Some("curl")) {
+        iter.next();
+    }
+
+    let mut form_items = Vec::new();
+    let mut url_set = false;
+    let mut fallback_url: Option<String> = None;
+
+    while let Some(token) = iter.next() {
+        let (flag, inline_value) = split_long_option(&token);
+
+        macro_rules! take_value {
+            () => {
+                inline_value.clone().or_else(|| iter.next())
+            };
+        }
+
+        match flag.as_str() {
+            "-X" | "--request" => {
+                if let Some(value) = take_value!() {
+                    command.method = Some(parse_method(&value));
+                }
+            }
+            "-H" | "--header" => {
+                if let Some(value) = take_value!() {
+                    if let Some((key, val)) = value.split_once(':') {
+                        let (key, val) = (key.trim(), val.trim());
+                        if !key.eq_ignore_ascii_case("authorization") || !fold_authorization_header(&mut command, val) {
+                            command.add_header(key.to_string(), val.to_string());
+                        }
+                    } else {
+                        command.extra_args.push(format!("-H {}", value));
+                    }
+                }
+            }
+            "--data-binary" => {
+                if let Some(value) = take_value!() {
+                    command.body = match value.strip_prefix('@') {
+                        Some(path) => Some(RequestBody::Binary(std::path::PathBuf::from(path))),
+                        None => Some(RequestBody::Raw(value)),
+                    };
+                    if command.method.is_none() {
+                        command.method = Some(HttpMethod::POST);
+                    }
+                }
+            }
+            "-d" | "--data" | "--data-raw" | "--data-ascii" | "--data-urlencode" => {
+                if let Some(value) = take_value!() {
+                    command.body = Some(RequestBody::Raw(value));
+                    if command.method.is_none() {
+                        command.method = Some(HttpMethod::POST);
+                    }
+                }
+            }
+            "-F" | "--form" => {
+                if let Some(value) = take_value!() {
+                    if let Some((key, val)) = value.split_once('=') {
+                        form_items.push(crate::models::command::FormDataItem {
+                            id: uuid::Uuid::new_v4().to_string(),
+                            key: key.to_string(),
+                            kind: parse_form_field_value(val),
+                            enabled: true,
+                        });
+                    } else {
+                        command.extra_args.push(format!("-F {}", value));
+                    }
+                    if command.method.is_none() {
+                        command.method = Some(HttpMethod::POST);
+                    }
+                }
+            }
+            "-u" | "--user" => {
+                if let Some(value) = take_value!() {
+                    let (username, password) = match value.split_once(':') {
+                        Some((username, password)) => (username.to_string(), password.to_string()),
+                        None => (value, String::new()),
+                    };
+                    command.auth = Auth::Basic { username, password };
+                }
+            }
+            "" => {}
+            _ if flag.starts_with('-') => {
+                // Classify against the known curl option table so flags like
+                // `-A`, `--compressed`, and `-k`/`--insecure` land as
+                // structured options rather than raw extra args; a flag the
+                // table doesn't know is preserved verbatim instead of dropped
+                match known_options.get_option(&flag) {
+                    Some(def) if def.takes_value => {
+                        if let Some(value) = take_value!() {
+                            command.add_option(def.flag.clone(), Some(value));
+                        } else {
+                            command.add_option(def.flag.clone(), None);
+                        }
+                    }
+                    Some(def) => {
+                        command.add_option(def.flag.clone(), None);
+                    }
+                    None => {
+                        if let Some(value) = inline_value {
+                            command.extra_args.push(format!("{} {}", flag, value));
+                        } else {
+                            command.add_option(flag, None);
+                        }
+                    }
+                }
+            }
+            _ => {
+                // Bare token: a token that looks like a URL (has a scheme or
+                // a host-like dotted name) always wins; otherwise the first
+                // bare token seen is kept as a fallback URL in case nothing
+                // else ever looks like one
+                if looks_like_url(&token) {
+                    command.url = token;
+                    url_set = true;
+                } else if !url_set && fallback_url.is_none() {
+                    fallback_url = Some(token);
+                } else {
+                    command.extra_args.push(token);
+                }
+            }
+        }
+    }
+
+    if !form_items.is_empty() {
+        command.body = Some(RequestBody::FormData(form_items));
+    }
+
+    if !url_set {
+        if let Some(fallback) = fallback_url {
+            command.url = fallback;
+        }
+    }
+
+    // A pasted command's query string is just inert text baked into the
+    // URL until it's split out into structured params
+    command.sync_query_from_url();
+
+    command
+}
+
+/// Whether a bare token looks like a URL: it has an explicit scheme
+/// (`scheme://`) or looks like a dotted host name (e.g. `example.com`)
+fn looks_like_url(token: &str) -> bool {
+    token.contains("://") || (!token.starts_with('-') && token.contains('.') && !token.contains(' '))
+}
+
+/// Parse an HTTP method name, defaulting to GET for anything unrecognized
+fn parse_method(value: &str) -> HttpMethod {
+    match value.to_uppercase().as_str() {
+        "GET" => HttpMethod::GET,
+        "POST" => HttpMethod::POST,
+        "PUT" => HttpMethod::PUT,
+        "DELETE" => HttpMethod::DELETE,
+        "PATCH" => HttpMethod::PATCH,
+        "HEAD" => HttpMethod::HEAD,
+        "OPTIONS" => HttpMethod::OPTIONS,
+        "TRACE" => HttpMethod::TRACE,
+        "CONNECT" => HttpMethod::CONNECT,
+        _ => HttpMethod::GET,
+    }
+}
+
+/// Parse a `-F` value into a text field, or, when it starts with `@`, a
+/// file field with curl's `;type=`/`;filename=` attributes
+fn parse_form_field_value(val: &str) -> FormFieldKind {
+    let Some(rest) = val.strip_prefix('@') else {
+        return FormFieldKind::Text(val.to_string());
+    };
+
+    let mut parts = rest.split(';');
+    let path = parts.next().unwrap_or("").to_string();
+    let mut content_type = None;
+    let mut filename = None;
+
+    for part in parts {
+        if let Some(value) = part.strip_prefix("type=") {
+            content_type = Some(value.to_string());
+        } else if let Some(value) = part.strip_prefix("filename=") {
+            filename = Some(value.to_string());
+        }
+    }
+
+    FormFieldKind::File { path: std::path::PathBuf::from(path), content_type, filename }
+}
+
+/// Fold an `Authorization` header's value into `command.auth` so a pasted
+/// curl command round-trips through structured auth instead of a
+/// hand-written header. Returns `false` (leaving `command` untouched) for
+/// a scheme we don't recognize, so the caller falls back to keeping it as
+/// a plain header.
+fn fold_authorization_header(command: &mut CurlCommand, value: &str) -> bool {
+    if let Some(token) = value.strip_prefix("Bearer ") {
+        command.auth = Auth::Bearer(token.to_string());
+        return true;
+    }
+
+    if let Some(encoded) = value.strip_prefix("Basic ") {
+        if let Some((username, password)) = decode_basic_auth(encoded) {
+            command.auth = Auth::Basic { username, password };
+            return true;
+        }
+    }
+
+    false
+}
+
+/// Minimally decode a base64 `username:password` pair from a `Basic`
+/// Authorization header's value. Pulling in a dependency for this single
+/// narrow use isn't worth it, so this handles just the standard alphabet
+/// with `=` padding; returns `None` for anything malformed.
+fn decode_basic_auth(encoded: &str) -> Option<(String, String)> {
+    const ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut bits: u32 = 0;
+    let mut bit_count = 0;
+    let mut bytes = Vec::new();
+
+    for c in encoded.trim_end_matches('=').bytes() {
+        let value = ALPHABET.iter().position(|&b| b == c)? as u32;
+        bits = (bits << 6) | value;
+        bit_count += 6;
+        if bit_count >= 8 {
+            bit_count -= 8;
+            bytes.push((bits >> bit_count) as u8);
+        }
+    }
+
+    let decoded = String::from_utf8(bytes).ok()?;
+    decoded.split_once(':').map(|(username, password)| (username.to_string(), password.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tokenize_simple() {
+        let tokens = tokenize("curl https://example.com");
+        assert_eq!(tokens, vec!["curl", "https://example.com"]);
+    }
+
+    #[test]
+    fn test_tokenize_quoted_header() {
+        let tokens = tokenize("curl -H 'Content-Type: application/json' https://example.com");
+        assert_eq!(
+            tokens,
+            vec!["curl", "-H", "Content-Type: application/json", "https://example.com"]
+        );
+    }
+
+    #[test]
+    fn test_parse_splits_query_string_into_params() {
+        let command = parse("curl \"https://example.com/search?q=hello+world&page=2\"");
+        assert_eq!(command.url, "https://example.com/search");
+        assert_eq!(command.query_params.len(), 2);
+        assert_eq!(command.query_params[0].key, "q");
+        assert_eq!(command.query_params[0].value, "hello world");
+        assert_eq!(command.query_params[1].key, "page");
+        assert_eq!(command.query_params[1].value, "2");
+    }
+
+    #[test]
+    fn test_curl_command_parse_rejects_empty_input() {
+        assert!(CurlCommand::parse("   ").is_err());
+    }
+
+    #[test]
+    fn test_curl_command_parse_ok() {
+        let command = CurlCommand::parse("curl -X POST https://example.com").unwrap();
+        assert_eq!(command.method, Some(HttpMethod::POST));
+        assert_eq!(command.url, "https://example.com");
+    }
+
+    #[test]
+    fn test_tokenize_line_continuation() {
+        let tokens = tokenize("curl -X POST \\\n  https://example.com");
+        assert_eq!(tokens, vec!["curl", "-X", "POST", "https://example.com"]);
+    }
+
+    #[test]
+    fn test_parse_method_and_url() {
+        let command = parse("curl -X POST https://example.com");
+        assert_eq!(command.method, Some(HttpMethod::POST));
+        assert_eq!(command.url, "https://example.com");
+    }
+
+    #[test]
+    fn test_parse_header() {
+        let command = parse("curl -H \"X-Custom: abc\" https://example.com");
+        assert_eq!(command.headers.len(), 1);
+        assert_eq!(command.headers[0].key, "X-Custom");
+        assert_eq!(command.headers[0].value, "abc");
+    }
+
+    #[test]
+    fn test_parse_user_sets_basic_auth() {
+        let command = parse("curl -u alice:secret https://example.com");
+        assert_eq!(
+            command.auth,
+            Auth::Basic { username: "alice".to_string(), password: "secret".to_string() }
+        );
+        assert!(command.headers.is_empty());
+    }
+
+    #[test]
+    fn test_parse_authorization_bearer_folds_into_auth() {
+        let command = parse("curl -H \"Authorization: Bearer abc123\" https://example.com");
+        assert_eq!(command.auth, Auth::Bearer("abc123".to_string()));
+        assert!(command.headers.is_empty());
+    }
+
+    #[test]
+    fn test_parse_authorization_basic_folds_into_auth() {
+        // base64 of "alice:secret"
+        let command = parse("curl -H \"Authorization: Basic YWxpY2U6c2VjcmV0\" https://example.com");
+        assert_eq!(
+            command.auth,
+            Auth::Basic { username: "alice".to_string(), password: "secret".to_string() }
+        );
+        assert!(command.headers.is_empty());
+    }
+
+    #[test]
+    fn test_parse_unrecognized_authorization_scheme_kept_as_header() {
+        let command = parse("curl -H \"Authorization: Digest abc\" https://example.com");
+        assert_eq!(command.auth, Auth::None);
+        assert_eq!(command.headers.len(), 1);
+        assert_eq!(command.headers[0].key, "Authorization");
+        assert_eq!(command.headers[0].value, "Digest abc");
+    }
+
+    #[test]
+    fn test_parse_data_sets_post() {
+        let command = parse("curl -d '{\"a\":1}' https://example.com");
+        assert_eq!(command.method, Some(HttpMethod::POST));
+        assert!(matches!(command.body, Some(RequestBody::Raw(_))));
+    }
+
+    #[test]
+    fn test_parse_preserves_unknown_flags() {
+        // "foo" doesn't look like a URL, so the real URL later in the
+        // command still wins and the unrecognized flag is kept as an option
+        let command = parse("curl --unknown-flag foo https://example.com");
+        assert_eq!(command.options.len(), 1);
+        assert_eq!(command.options[0].flag, "--unknown-flag");
+        assert_eq!(command.url, "https://example.com");
+    }
+
+    #[test]
+    fn test_parse_user_agent_flag_consumes_its_value() {
+        let command = parse("curl -A \"my-agent/1.0\" https://example.com");
+        assert_eq!(command.options.len(), 1);
+        assert_eq!(command.options[0].flag, "-A");
+        assert_eq!(command.options[0].value.as_deref(), Some("my-agent/1.0"));
+        assert_eq!(command.url, "https://example.com");
+    }
+
+    #[test]
+    fn test_parse_compressed_and_insecure_flags() {
+        let command = parse("curl --compressed -k https://example.com");
+        let flags: Vec<&str> = command.options.iter().map(|o| o.flag.as_str()).collect();
+        assert_eq!(flags, vec!["--compressed", "-k"]);
+    }
+
+    #[test]
+    fn test_parse_data_binary_file_reference_sets_binary_body() {
+        let command = parse("curl --data-binary @/tmp/payload.bin https://example.com");
+        assert_eq!(command.method, Some(HttpMethod::POST));
+        match command.body {
+            Some(RequestBody::Binary(path)) => assert_eq!(path.to_str(), Some("/tmp/payload.bin")),
+            _ => panic!("expected binary body"),
+        }
+    }
+
+    #[test]
+    fn test_parse_data_binary_inline_value_stays_raw() {
+        let command = parse("curl --data-binary 'raw bytes' https://example.com");
+        assert!(matches!(command.body, Some(RequestBody::Raw(ref value)) if value == "raw bytes"));
+    }
+
+    #[test]
+    fn test_parse_data_urlencode() {
+        let command = parse("curl --data-urlencode 'name=John Doe' https://example.com");
+        assert_eq!(command.method, Some(HttpMethod::POST));
+        assert!(matches!(command.body, Some(RequestBody::Raw(_))));
+    }
+
+    #[test]
+    fn test_parse_long_option_with_equals() {
+        let command = parse("curl --request=PUT https://example.com");
+        assert_eq!(command.method, Some(HttpMethod::PUT));
+    }
+
+    #[test]
+    fn test_parse_form_text_field() {
+        let command = parse("curl -F 'name=John Doe' https://example.com");
+        assert_eq!(command.method, Some(HttpMethod::POST));
+        match command.body {
+            Some(RequestBody::FormData(items)) => {
+                assert_eq!(items[0].key, "name");
+                assert!(matches!(&items[0].kind, FormFieldKind::Text(value) if value == "John Doe"));
+            }
+            _ => panic!("expected form data body"),
+        }
+    }
+
+    #[test]
+    fn test_parse_form_file_field_with_attributes() {
+        let command = parse("curl -F 'avatar=@/tmp/x.png;type=image/png;filename=x.png' https://example.com");
+        match command.body {
+            Some(RequestBody::FormData(items)) => {
+                assert_eq!(items[0].key, "avatar");
+                match &items[0].kind {
+                    FormFieldKind::File { path, content_type, filename } => {
+                        assert_eq!(path.to_str(), Some("/tmp/x.png"));
+                        assert_eq!(content_type.as_deref(), Some("image/png"));
+                        assert_eq!(filename.as_deref(), Some("x.png"));
+                    }
+                    _ => panic!("expected file field"),
+                }
+            }
+            _ => panic!("expected form data body"),
+        }
+    }
+}
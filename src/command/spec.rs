@@ -0,0 +1,174 @@
+use crate::command::options::{ArgumentKind, CurlOptions};
+use serde::{Deserialize, Serialize};
+
+/// Declaration of a single flag for an arbitrary CLI tool
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FlagSpec {
+    /// Flag as passed on the command line (e.g. "-v", "--location")
+    pub flag: String,
+    /// Long form of the flag, if the short form above is the primary name
+    pub long_flag: Option<String>,
+    /// Help text shown in the options form
+    pub help: String,
+    /// Whether this flag takes an argument
+    pub takes_value: bool,
+    /// Expected kind of the argument, reusing curl's argument-kind
+    /// vocabulary so validation/completion work the same for any tool
+    pub arg_kind: ArgumentKind,
+}
+
+/// A declarative description of an arbitrary CLI tool: its binary name plus
+/// the flags it supports. `OptionsPanel` renders a form from this,
+/// `CommandBuilder` assembles argv from the filled-in form, and
+/// `OutputPanel` runs the resulting command and displays its output —
+/// exactly as it already does for curl.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CliSpec {
+    /// Human-readable name of the tool (e.g. "curl", "httpie")
+    pub name: String,
+    /// Binary to invoke
+    pub binary: String,
+    /// Flags this tool supports
+    pub flags: Vec<FlagSpec>,
+}
+
+impl CliSpec {
+    /// Load a spec from a TOML file on disk, so users can point lazycurl at
+    /// any CLI tool by supplying their own spec (e.g. for `httpie` or
+    /// `grpcurl`) instead of curl's built-in default
+    pub fn load_from_file(path: &std::path::Path) -> Result<Self, String> {
+        let contents = std::fs::read_to_string(path)
+            .map_err(|err| format!("Failed to read spec file {}: {}", path.display(), err))?;
+        toml::from_str(&contents).map_err(|err| format!("Failed to parse spec file {}: {}", path.display(), err))
+    }
+
+    /// Find a flag's spec by its short or long form
+    pub fn get_flag(&self, flag: &str) -> Option<&FlagSpec> {
+        self.flags
+            .iter()
+            .find(|f| f.flag == flag || f.long_flag.as_deref() == Some(flag))
+    }
+
+    /// The built-in curl spec, derived from curl's own declarative option
+    /// table so the two stay in sync
+    pub fn curl() -> Self {
+        let curl_options = CurlOptions::new();
+        let mut flags: Vec<FlagSpec> = curl_options
+            .all_options()
+            .into_iter()
+            .map(|def| FlagSpec {
+                flag: def.flag.clone(),
+                long_flag: def.long_flag.clone(),
+                help: def.description.clone(),
+                takes_value: def.takes_value,
+                arg_kind: def.arg_kind.clone(),
+            })
+            .collect();
+        flags.sort_by(|a, b| a.flag.cmp(&b.flag));
+
+        Self {
+            name: "curl".to_string(),
+            binary: "curl".to_string(),
+            flags,
+        }
+    }
+}
+
+/// A single flag/value pair filled in on a generic command form
+#[derive(Debug, Clone)]
+pub struct GenericOption {
+    /// Flag being set, matching a `FlagSpec::flag` in the active `CliSpec`
+    pub flag: String,
+    /// Value supplied for the flag, if it takes one
+    pub value: Option<String>,
+    /// Whether the option is currently active
+    pub enabled: bool,
+}
+
+/// A command built against an arbitrary `CliSpec`, analogous to
+/// `CurlCommand` but generalized to any tool
+#[derive(Debug, Clone, Default)]
+pub struct GenericCommand {
+    /// Flags set on this invocation
+    pub options: Vec<GenericOption>,
+    /// Positional (non-flag) arguments, in order
+    pub positional_args: Vec<String>,
+}
+
+impl GenericCommand {
+    /// Assemble the argv for invoking `spec.binary` with this command's
+    /// options and positional arguments
+    pub fn build_argv(&self, spec: &CliSpec) -> Vec<String> {
+        let mut argv = vec![spec.binary.clone()];
+
+        for option in &self.options {
+            if !option.enabled {
+                continue;
+            }
+            argv.push(option.flag.clone());
+            if let Some(value) = &option.value {
+                argv.push(value.clone());
+            }
+        }
+
+        argv.extend(self.positional_args.iter().cloned());
+        argv
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_curl_spec_includes_known_flags() {
+        let spec = CliSpec::curl();
+        assert_eq!(spec.binary, "curl");
+        assert!(spec.get_flag("-X").is_some());
+        assert!(spec.get_flag("--location").is_some());
+    }
+
+    #[test]
+    fn test_build_argv_for_arbitrary_tool() {
+        let spec = CliSpec {
+            name: "httpie".to_string(),
+            binary: "http".to_string(),
+            flags: vec![FlagSpec {
+                flag: "--json".to_string(),
+                long_flag: None,
+                help: "Force JSON output".to_string(),
+                takes_value: false,
+                arg_kind: ArgumentKind::Flag,
+            }],
+        };
+
+        let command = GenericCommand {
+            options: vec![GenericOption {
+                flag: "--json".to_string(),
+                value: None,
+                enabled: true,
+            }],
+            positional_args: vec!["GET".to_string(), "https://example.com".to_string()],
+        };
+
+        assert_eq!(
+            command.build_argv(&spec),
+            vec!["http", "--json", "GET", "https://example.com"]
+        );
+    }
+
+    #[test]
+    fn test_build_argv_skips_disabled_options() {
+        let spec = CliSpec::curl();
+        let command = GenericCommand {
+            options: vec![GenericOption {
+                flag: "-v".to_string(),
+                value: None,
+                enabled: false,
+            }],
+            positional_args: vec!["https://example.com".to_string()],
+        };
+
+        assert_eq!(command.build_argv(&spec), vec!["curl", "https://example.com"]);
+    }
+}
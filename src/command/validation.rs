@@ -1,6 +1,101 @@
+use crate::command::options::CurlOptions;
 use crate::models::command::CurlCommand;
+use std::collections::{HashMap, HashSet};
 use url::Url;
 
+/// Conflict/dependency relations for one canonical curl option, keyed by
+/// its canonical flag (the `OptionDefinition::flag` form, e.g. `-s` rather
+/// than `--silent`)
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+struct OptionRule {
+    /// Canonical options that cannot be enabled at the same time as this one
+    #[serde(default)]
+    conflicts_with: Vec<String>,
+    /// Canonical options that must also be enabled for this one to be valid
+    #[serde(default)]
+    requires: Vec<String>,
+}
+
+/// Table of conflict/dependency rules between curl options, keyed by
+/// canonical flag. Seeded from a small built-in table sourced from the same
+/// option identities as `CurlOptions`, and extensible by the user via
+/// `~/.config/lazycurl/validation.toml` so custom wrapper flags can declare
+/// their own relations without touching this file.
+struct RuleTable {
+    rules: HashMap<String, OptionRule>,
+}
+
+impl RuleTable {
+    /// The built-in rule table covering curl's well-known option conflicts
+    fn built_in() -> Self {
+        let mut rules: HashMap<String, OptionRule> = HashMap::new();
+
+        let conflicts: &[(&str, &[&str])] = &[
+            ("-s", &["-v"]),
+            ("-v", &["-s"]),
+            ("--basic", &["--digest", "--ntlm"]),
+            ("--digest", &["--basic", "--ntlm"]),
+            ("--ntlm", &["--basic", "--digest"]),
+        ];
+        for (flag, others) in conflicts {
+            rules
+                .entry(flag.to_string())
+                .or_default()
+                .conflicts_with
+                .extend(others.iter().map(|s| s.to_string()));
+        }
+
+        let requires: &[(&str, &[&str])] = &[
+            ("--proxy-basic", &["-x"]),
+            ("--proxy-digest", &["-x"]),
+            ("--cert", &["--key"]),
+        ];
+        for (flag, others) in requires {
+            rules
+                .entry(flag.to_string())
+                .or_default()
+                .requires
+                .extend(others.iter().map(|s| s.to_string()));
+        }
+
+        Self { rules }
+    }
+
+    /// Layer a user-supplied TOML file of extra rules over the built-in
+    /// table, from the conventional config path. A missing or malformed
+    /// file leaves the built-in table untouched.
+    fn load_default() -> Self {
+        let mut table = Self::built_in();
+        if let Ok(home) = std::env::var("HOME") {
+            let path = std::path::Path::new(&home).join(".config/lazycurl/validation.toml");
+            if let Ok(contents) = std::fs::read_to_string(&path) {
+                table.merge_user_rules(&contents);
+            }
+        }
+        table
+    }
+
+    /// Merge a `{ flag = { conflicts_with = [...], requires = [...] } }`
+    /// TOML document into this table, adding to (rather than replacing)
+    /// any built-in rule already present for a flag
+    fn merge_user_rules(&mut self, contents: &str) {
+        let parsed: HashMap<String, OptionRule> = match toml::from_str(contents) {
+            Ok(parsed) => parsed,
+            Err(_) => return,
+        };
+        for (flag, rule) in parsed {
+            let entry = self.rules.entry(flag).or_default();
+            entry.conflicts_with.extend(rule.conflicts_with);
+            entry.requires.extend(rule.requires);
+        }
+    }
+
+    /// Rules declared for `canonical_flag`, if any
+    fn get(&self, canonical_flag: &str) -> Option<&OptionRule> {
+        self.rules.get(canonical_flag)
+    }
+}
+
 /// Validation result
 pub enum ValidationResult {
     /// Command is valid
@@ -93,60 +188,114 @@ impl CommandValidator {
 
     /// Validate options
     fn validate_options(command: &CurlCommand, errors: &mut Vec<String>, warnings: &mut Vec<String>) {
+        let curl_options = CurlOptions::new();
+        let rules = RuleTable::load_default();
+
         // Check for conflicting options
-        Self::check_conflicting_options(command, errors);
+        Self::check_conflicting_options(command, &curl_options, &rules, errors);
 
         // Check for missing required values
-        Self::check_missing_values(command, errors);
+        Self::check_missing_values(command, &curl_options, errors);
+
+        // Check for missing required-together dependencies
+        Self::check_missing_dependencies(command, &curl_options, &rules, errors);
 
         // Check for potentially problematic combinations
         Self::check_problematic_combinations(command, warnings);
     }
 
-    /// Check for conflicting options
-    fn check_conflicting_options(command: &CurlCommand, errors: &mut Vec<String>) {
-        let enabled_options: Vec<&String> = command
+    /// Resolve an enabled option's flag to its canonical form (the
+    /// `OptionDefinition::flag` it's known by), so aliases like
+    /// `--silent`/`-s` collapse to a single identity before rule evaluation
+    fn canonical_flag(flag: &str, curl_options: &CurlOptions) -> String {
+        curl_options
+            .get_option(flag)
+            .map(|def| def.flag.clone())
+            .unwrap_or_else(|| flag.to_string())
+    }
+
+    /// Check for conflicting options, both via the data-driven rule table
+    /// and the one relation (`-I` vs. a request body) that isn't itself a
+    /// curl option flag and so falls outside it
+    fn check_conflicting_options(
+        command: &CurlCommand,
+        curl_options: &CurlOptions,
+        rules: &RuleTable,
+        errors: &mut Vec<String>,
+    ) {
+        let enabled: Vec<String> = command
             .options
             .iter()
             .filter(|opt| opt.enabled)
-            .map(|opt| &opt.flag)
+            .map(|opt| Self::canonical_flag(&opt.flag, curl_options))
             .collect();
 
-        // Check for -s (silent) and -v (verbose)
-        if enabled_options.contains(&&"-s".to_string()) && enabled_options.contains(&&"-v".to_string()) {
-            errors.push("Conflicting options: -s (silent) and -v (verbose) cannot be used together".to_string());
+        let mut reported: HashSet<[String; 2]> = HashSet::new();
+        for flag in &enabled {
+            let Some(rule) = rules.get(flag) else { continue };
+            for other in &rule.conflicts_with {
+                if !enabled.contains(other) {
+                    continue;
+                }
+                let mut pair = [flag.clone(), other.clone()];
+                pair.sort();
+                if reported.insert(pair.clone()) {
+                    errors.push(format!(
+                        "Conflicting options: {} and {} cannot be used together",
+                        pair[0], pair[1]
+                    ));
+                }
+            }
         }
 
-        // Check for -I (head) and request body
-        if enabled_options.contains(&&"-I".to_string()) && command.body.is_some() {
+        if enabled.iter().any(|f| f == "-I") && command.body.is_some() {
             errors.push("Conflicting options: -I (head) cannot be used with a request body".to_string());
         }
     }
 
-    /// Check for missing required values
-    fn check_missing_values(command: &CurlCommand, errors: &mut Vec<String>) {
+    /// Check for missing required values, sourced from `OptionDefinition::takes_value`
+    /// so new options added to `options.rs` automatically gain this check
+    fn check_missing_values(command: &CurlCommand, curl_options: &CurlOptions, errors: &mut Vec<String>) {
         for option in &command.options {
             if !option.enabled {
                 continue;
             }
 
-            // Check if option requires a value
-            let requires_value = match option.flag.as_str() {
-                "-X" | "--request" | "-d" | "--data" | "--data-binary" | "--data-urlencode" |
-                "-F" | "--form" | "-u" | "--user" | "--oauth2-bearer" | "--connect-timeout" |
-                "--max-time" | "-H" | "--header" | "-A" | "--user-agent" | "-e" | "--referer" |
-                "-b" | "--cookie" | "-c" | "--cookie-jar" | "--cacert" | "--cert" | "--key" |
-                "--ciphers" | "--tls-max" | "-x" | "--proxy" | "--noproxy" | "-o" | "--output" |
-                "-w" | "--write-out" => true,
-                _ => false,
+            let Some(def) = curl_options.get_option(&option.flag) else {
+                continue;
             };
 
-            if requires_value && (option.value.is_none() || option.value.as_ref().unwrap().trim().is_empty()) {
+            if def.takes_value && option.value.as_deref().unwrap_or("").trim().is_empty() {
                 errors.push(format!("Option {} requires a value", option.flag));
             }
         }
     }
 
+    /// Check that every enabled option's required-together dependencies are
+    /// also enabled
+    fn check_missing_dependencies(
+        command: &CurlCommand,
+        curl_options: &CurlOptions,
+        rules: &RuleTable,
+        errors: &mut Vec<String>,
+    ) {
+        let enabled: Vec<String> = command
+            .options
+            .iter()
+            .filter(|opt| opt.enabled)
+            .map(|opt| Self::canonical_flag(&opt.flag, curl_options))
+            .collect();
+
+        for flag in &enabled {
+            let Some(rule) = rules.get(flag) else { continue };
+            for dep in &rule.requires {
+                if !enabled.contains(dep) {
+                    errors.push(format!("Option {} requires {} to also be enabled", flag, dep));
+                }
+            }
+        }
+    }
+
     /// Check for potentially problematic combinations
     fn check_problematic_combinations(command: &CurlCommand, warnings: &mut Vec<String>) {
         let enabled_options: Vec<&String> = command
@@ -251,4 +400,65 @@ mod tests {
         let result = CommandValidator::validate(&command);
         assert!(matches!(result, ValidationResult::Warning(_)));
     }
+
+    #[test]
+    fn test_validate_conflicting_options_via_alias() {
+        // --verbose and --silent resolve to the same canonical identities as -v/-s,
+        // so this must be caught even though neither option uses the other's
+        // exact flag spelling tested above
+        let mut command = CurlCommand::default();
+        command.url = "https://example.com".to_string();
+        command.options.push(CurlOption {
+            id: "1".to_string(),
+            flag: "--verbose".to_string(),
+            value: None,
+            enabled: true,
+        });
+        command.options.push(CurlOption {
+            id: "2".to_string(),
+            flag: "--silent".to_string(),
+            value: None,
+            enabled: true,
+        });
+
+        let result = CommandValidator::validate(&command);
+        assert!(matches!(result, ValidationResult::Error(_)));
+    }
+
+    #[test]
+    fn test_validate_missing_dependency() {
+        let mut command = CurlCommand::default();
+        command.url = "https://example.com".to_string();
+        command.options.push(CurlOption {
+            id: "1".to_string(),
+            flag: "--proxy-basic".to_string(),
+            value: None,
+            enabled: true,
+        });
+
+        let result = CommandValidator::validate(&command);
+        assert!(matches!(result, ValidationResult::Error(_)));
+        assert!(result.errors().iter().any(|e| e.contains("--proxy-basic") && e.contains("-x")));
+    }
+
+    #[test]
+    fn test_validate_dependency_satisfied() {
+        let mut command = CurlCommand::default();
+        command.url = "https://example.com".to_string();
+        command.options.push(CurlOption {
+            id: "1".to_string(),
+            flag: "--proxy-basic".to_string(),
+            value: None,
+            enabled: true,
+        });
+        command.options.push(CurlOption {
+            id: "2".to_string(),
+            flag: "-x".to_string(),
+            value: Some("http://proxy.example.com".to_string()),
+            enabled: true,
+        });
+
+        let result = CommandValidator::validate(&command);
+        assert!(result.is_valid());
+    }
 }
\ No newline at end of file
@@ -0,0 +1,72 @@
+use serde::{Deserialize, Serialize};
+
+/// User-editable preferences, loaded from `~/.config/lazycurl/config.toml`.
+/// Distinct from `persistence::PersistedState` (app-managed data like
+/// templates/history): this file is meant to be hand-edited, so every
+/// field is optional and a missing file behaves like an empty one.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Config {
+    /// Force the light theme (`true`) or dark theme (`false`) instead of
+    /// auto-detecting the terminal's background color
+    #[serde(default)]
+    pub light_theme: Option<bool>,
+    /// Enable vim-style modal editing (normal/insert/visual) for field
+    /// buffers, with a shared yank/paste register across fields. Off by
+    /// default so existing users keep the current insert-only behavior.
+    #[serde(default)]
+    pub vim_mode: bool,
+}
+
+impl Config {
+    /// Load the user config file, falling back to `Config::default()` (no
+    /// overrides) when no file is present or it fails to parse
+    pub fn load() -> Self {
+        let Some(path) = config_path() else {
+            return Self::default();
+        };
+        let Ok(contents) = std::fs::read_to_string(&path) else {
+            return Self::default();
+        };
+        toml::from_str(&contents).unwrap_or_default()
+    }
+}
+
+/// Path to the user config file (`~/.config/lazycurl/config.toml`)
+fn config_path() -> Option<std::path::PathBuf> {
+    let home = std::env::var("HOME").ok()?;
+    Some(std::path::PathBuf::from(home).join(".config/lazycurl/config.toml"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_config_has_no_overrides() {
+        assert_eq!(Config::default().light_theme, None);
+    }
+
+    #[test]
+    fn test_parses_light_theme_field() {
+        let config: Config = toml::from_str("light_theme = true\n").unwrap();
+        assert_eq!(config.light_theme, Some(true));
+    }
+
+    #[test]
+    fn test_missing_fields_default_to_none() {
+        let config: Config = toml::from_str("").unwrap();
+        assert_eq!(config.light_theme, None);
+    }
+
+    #[test]
+    fn test_vim_mode_defaults_to_false() {
+        let config: Config = toml::from_str("").unwrap();
+        assert_eq!(config.vim_mode, false);
+    }
+
+    #[test]
+    fn test_parses_vim_mode_field() {
+        let config: Config = toml::from_str("vim_mode = true\n").unwrap();
+        assert_eq!(config.vim_mode, true);
+    }
+}
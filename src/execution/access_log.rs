@@ -0,0 +1,188 @@
+//! An opt-in, rotating access log of every completed `execute` call -
+//! timestamp, the full curl command, exit code, byte counts, and elapsed
+//! time - written as one JSON line per record so a history/replay view in
+//! the TUI can read it back without re-parsing curl's own output.
+
+use super::executor::ExecutionResult;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fs::OpenOptions;
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+/// Where the execution access log lives, and how large it may grow
+/// before `AccessLogger` rotates it out of the way
+#[derive(Debug, Clone)]
+pub struct FileLogOptions {
+    /// Path to the active log file
+    pub path: PathBuf,
+    /// Once the active file reaches this size, it's rotated to
+    /// `<path>.1` (overwriting any previous rotation) and a fresh file
+    /// started
+    pub max_size_bytes: u64,
+}
+
+impl FileLogOptions {
+    /// A `FileLogOptions` for `path` with a 10 MB rotation threshold
+    pub fn new(path: PathBuf) -> Self {
+        Self { path, max_size_bytes: 10 * 1024 * 1024 }
+    }
+}
+
+/// One completed `execute` call, as written by `AccessLogger::log` and
+/// read back by `AccessLogger::read_all`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccessLogRecord {
+    /// When the execution completed
+    pub timestamp: DateTime<Utc>,
+    /// The full curl command that was run
+    pub command: String,
+    /// Exit code, if the process exited normally
+    pub exit_code: Option<i32>,
+    /// Bytes of stdout captured
+    pub stdout_bytes: usize,
+    /// Bytes of stderr captured
+    pub stderr_bytes: usize,
+    /// Elapsed execution time, in milliseconds
+    pub execution_time_ms: u128,
+    /// Error message, if any
+    pub error: Option<String>,
+}
+
+impl AccessLogRecord {
+    fn from_result(result: &ExecutionResult) -> Self {
+        Self {
+            timestamp: Utc::now(),
+            command: result.command.clone(),
+            exit_code: result.exit_code,
+            stdout_bytes: result.stdout.len(),
+            stderr_bytes: result.stderr.len(),
+            execution_time_ms: result.execution_time.as_millis(),
+            error: result.error.clone(),
+        }
+    }
+}
+
+/// Appends one JSON-line `AccessLogRecord` per completed execution to a
+/// file, rotating it once it grows past `FileLogOptions::max_size_bytes`.
+/// The open file handle is wrapped in a `Mutex` so a single
+/// `CommandExecutor` can log concurrent executions without interleaving
+/// writes.
+pub struct AccessLogger {
+    options: FileLogOptions,
+    file: Mutex<std::fs::File>,
+}
+
+impl AccessLogger {
+    /// Open (or create) the log file at `options.path`
+    pub fn new(options: FileLogOptions) -> Result<Self, String> {
+        if let Some(dir) = options.path.parent() {
+            std::fs::create_dir_all(dir).map_err(|err| format!("Failed to create log directory: {}", err))?;
+        }
+        let file = Self::open(&options.path)?;
+        Ok(Self { options, file: Mutex::new(file) })
+    }
+
+    fn open(path: &Path) -> Result<std::fs::File, String> {
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .map_err(|err| format!("Failed to open access log {}: {}", path.display(), err))
+    }
+
+    /// Append a record for `result`, rotating the file first if it's
+    /// grown past `max_size_bytes`. Failures (a poisoned lock, a write
+    /// error) are swallowed rather than propagated, since a logging
+    /// failure shouldn't take down command execution.
+    pub fn log(&self, result: &ExecutionResult) {
+        let Ok(mut file) = self.file.lock() else { return };
+
+        if let Ok(metadata) = file.metadata() {
+            if metadata.len() >= self.options.max_size_bytes {
+                self.rotate(&mut file);
+            }
+        }
+
+        if let Ok(line) = serde_json::to_string(&AccessLogRecord::from_result(result)) {
+            let _ = writeln!(file, "{}", line);
+        }
+    }
+
+    /// Move the active log aside to `<path>.1` (overwriting any previous
+    /// rotation) and reopen a fresh file in its place
+    fn rotate(&self, file: &mut std::fs::File) {
+        let rotated_path = PathBuf::from(format!("{}.1", self.options.path.display()));
+        if std::fs::rename(&self.options.path, &rotated_path).is_ok() {
+            if let Ok(fresh) = Self::open(&self.options.path) {
+                *file = fresh;
+            }
+        }
+    }
+
+    /// Read every record currently in the log file at `path`, oldest
+    /// first. Malformed lines (e.g. a partially-written last line after a
+    /// crash) are skipped rather than aborting the whole read.
+    pub fn read_all(path: &Path) -> Result<Vec<AccessLogRecord>, String> {
+        let file = std::fs::File::open(path).map_err(|err| format!("Failed to open access log {}: {}", path.display(), err))?;
+        Ok(BufReader::new(file)
+            .lines()
+            .filter_map(|line| line.ok())
+            .filter_map(|line| serde_json::from_str(&line).ok())
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn sample_result() -> ExecutionResult {
+        ExecutionResult {
+            command: "curl https://example.com".to_string(),
+            exit_code: Some(0),
+            stdout: "hello".to_string(),
+            stderr: String::new(),
+            execution_time: Duration::from_millis(42),
+            error: None,
+            cancelled: false,
+        }
+    }
+
+    #[test]
+    fn test_log_then_read_all_round_trips_a_record() {
+        let dir = std::env::temp_dir().join(format!("lazycurl-access-log-test-{}", std::process::id()));
+        let path = dir.join("access.log");
+        let logger = AccessLogger::new(FileLogOptions::new(path.clone())).unwrap();
+
+        logger.log(&sample_result());
+
+        let records = AccessLogger::read_all(&path).unwrap();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].command, "curl https://example.com");
+        assert_eq!(records[0].exit_code, Some(0));
+        assert_eq!(records[0].stdout_bytes, 5);
+        assert_eq!(records[0].execution_time_ms, 42);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_log_rotates_once_max_size_is_exceeded() {
+        let dir = std::env::temp_dir().join(format!("lazycurl-access-log-rotate-test-{}", std::process::id()));
+        let path = dir.join("access.log");
+        let mut options = FileLogOptions::new(path.clone());
+        options.max_size_bytes = 1;
+        let logger = AccessLogger::new(options).unwrap();
+
+        logger.log(&sample_result());
+        logger.log(&sample_result());
+
+        let rotated_path = PathBuf::from(format!("{}.1", path.display()));
+        assert!(rotated_path.exists());
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -1,9 +1,31 @@
-use std::process::{Command, Stdio};
-use std::io::{BufRead, BufReader};
+use std::process::Stdio;
 use std::time::{Duration, Instant};
-use tokio::sync::mpsc;
+use tokio::io::{AsyncBufReadExt, BufReader};
+use tokio::process::Command;
+use tokio::sync::{mpsc, oneshot};
 use which::which;
 
+/// One incremental event from a streaming execution: output lines as soon
+/// as they're read, transfer progress if it can be determined, or the
+/// final outcome once the process exits
+#[derive(Debug, Clone)]
+pub enum ExecOutput {
+    /// A line of stdout, forwarded as soon as it's read rather than
+    /// buffered until the process exits
+    Stdout(String),
+    /// A line of stderr
+    Stderr(String),
+    /// Bytes transferred so far, and the total if curl reported one. Not
+    /// emitted yet (curl only exposes this via its own progress meter,
+    /// not plain stdout/stderr lines), but kept here so a future progress
+    /// parser can plug into the same event stream without another API
+    /// change.
+    Progress { bytes: u64, total: Option<u64> },
+    /// The process has exited; carries the same summary `execute` folds
+    /// the stream into for callers that don't need the incremental events
+    Done(ExecutionResult),
+}
+
 /// Execution result
 #[derive(Debug, Clone)]
 pub struct ExecutionResult {
@@ -19,12 +41,46 @@ pub struct ExecutionResult {
     pub execution_time: Duration,
     /// Error message (if any)
     pub error: Option<String>,
+    /// Set when the execution ended because the caller cancelled it via
+    /// `ExecutionHandle::cancel` rather than curl exiting on its own, so
+    /// the TUI can show "Cancelled" instead of treating `error` as a
+    /// curl failure
+    pub cancelled: bool,
+}
+
+/// A running curl execution: the stream of incremental `ExecOutput`
+/// events, plus a handle to cancel it mid-flight. Returned by
+/// `execute_cancellable`, modeled on distant's process handle (wait +
+/// kill) rather than exposing the child's pid directly.
+pub struct ExecutionHandle {
+    events: mpsc::Receiver<ExecOutput>,
+    cancel_tx: Option<oneshot::Sender<()>>,
+}
+
+impl ExecutionHandle {
+    /// Receive the next event, or `None` once the stream has closed
+    /// (always preceded by a `Done` event)
+    pub async fn recv(&mut self) -> Option<ExecOutput> {
+        self.events.recv().await
+    }
+
+    /// Ask the running curl process to terminate. Idempotent: calling it
+    /// again after the first time (or after the process has already
+    /// exited) is a no-op.
+    pub async fn cancel(&mut self) {
+        if let Some(cancel_tx) = self.cancel_tx.take() {
+            let _ = cancel_tx.send(());
+        }
+    }
 }
 
 /// Command executor
 pub struct CommandExecutor {
     /// Path to curl executable
     curl_path: String,
+    /// Optional rotating access log every completed `execute` call is
+    /// recorded to
+    logger: Option<crate::execution::access_log::AccessLogger>,
 }
 
 impl CommandExecutor {
@@ -35,137 +91,251 @@ impl CommandExecutor {
             Ok(path) => path.to_string_lossy().to_string(),
             Err(_) => return Err("curl executable not found in PATH".to_string()),
         };
-        
-        Ok(Self { curl_path })
+
+        Ok(Self { curl_path, logger: None })
     }
-    
-    /// Execute a curl command
-    pub async fn execute(&self, command: &str) -> ExecutionResult {
+
+    /// Attach a rotating access logger so every `execute` call appends one
+    /// `AccessLogRecord` to `options.path`. Builder-style so it composes
+    /// with `new()`: `CommandExecutor::new()?.with_logger(options)?`.
+    pub fn with_logger(mut self, options: crate::execution::access_log::FileLogOptions) -> Result<Self, String> {
+        self.logger = Some(crate::execution::access_log::AccessLogger::new(options)?);
+        Ok(self)
+    }
+
+    /// Execute a curl command, returning an `ExecutionHandle` that streams
+    /// `ExecOutput` events as they happen and can cancel the process
+    /// mid-flight. The spawned line readers forward each line the moment
+    /// it's read; a final `Done` event, carrying the usual
+    /// `ExecutionResult`, is sent only after both readers have hit EOF so
+    /// no output is lost behind it.
+    pub async fn execute_cancellable(&self, command: &str) -> ExecutionHandle {
+        let (tx, rx) = mpsc::channel(256);
+        let (cancel_tx, mut cancel_rx) = oneshot::channel();
         let start_time = Instant::now();
-        
+
         // Split command into arguments
         let args: Vec<&str> = command.split_whitespace().collect();
         if args.is_empty() || args[0] != "curl" {
-            return ExecutionResult {
-                command: command.to_string(),
-                exit_code: None,
-                stdout: String::new(),
-                stderr: String::new(),
-                execution_time: start_time.elapsed(),
-                error: Some("Invalid curl command".to_string()),
-            };
+            let _ = tx
+                .send(ExecOutput::Done(ExecutionResult {
+                    command: command.to_string(),
+                    exit_code: None,
+                    stdout: String::new(),
+                    stderr: String::new(),
+                    execution_time: start_time.elapsed(),
+                    error: Some("Invalid curl command".to_string()),
+                    cancelled: false,
+                }))
+                .await;
+            return ExecutionHandle { events: rx, cancel_tx: None };
         }
-        
+
         // Create command
         let mut cmd = Command::new(&self.curl_path);
         cmd.args(&args[1..]);
-        
+
         // Set up pipes for stdout and stderr
         cmd.stdout(Stdio::piped());
         cmd.stderr(Stdio::piped());
-        
+
         // Execute command
         let mut child = match cmd.spawn() {
             Ok(child) => child,
             Err(err) => {
-                return ExecutionResult {
-                    command: command.to_string(),
-                    exit_code: None,
-                    stdout: String::new(),
-                    stderr: String::new(),
-                    execution_time: start_time.elapsed(),
-                    error: Some(format!("Failed to execute command: {}", err)),
-                };
+                let _ = tx
+                    .send(ExecOutput::Done(ExecutionResult {
+                        command: command.to_string(),
+                        exit_code: None,
+                        stdout: String::new(),
+                        stderr: String::new(),
+                        execution_time: start_time.elapsed(),
+                        error: Some(format!("Failed to execute command: {}", err)),
+                        cancelled: false,
+                    }))
+                    .await;
+                return ExecutionHandle { events: rx, cancel_tx: None };
             }
         };
-        
-        // Set up channels for stdout and stderr
-        let (stdout_tx, mut stdout_rx) = mpsc::channel(100);
-        let (stderr_tx, mut stderr_rx) = mpsc::channel(100);
-        
-        // Read stdout
+
+        // Forward stdout lines to the caller as they're read
         let stdout = child.stdout.take().unwrap();
-        let stdout_reader = BufReader::new(stdout);
-        tokio::spawn(async move {
-            for line in stdout_reader.lines() {
-                if let Ok(line) = line {
-                    if stdout_tx.send(line).await.is_err() {
-                        break;
-                    }
+        let stdout_tx = tx.clone();
+        let stdout_handle = tokio::spawn(async move {
+            let mut lines = BufReader::new(stdout).lines();
+            while let Ok(Some(line)) = lines.next_line().await {
+                if stdout_tx.send(ExecOutput::Stdout(line)).await.is_err() {
+                    break;
                 }
             }
         });
-        
-        // Read stderr
+
+        // Forward stderr lines to the caller as they're read
         let stderr = child.stderr.take().unwrap();
-        let stderr_reader = BufReader::new(stderr);
-        tokio::spawn(async move {
-            for line in stderr_reader.lines() {
-                if let Ok(line) = line {
-                    if stderr_tx.send(line).await.is_err() {
-                        break;
-                    }
+        let stderr_tx = tx.clone();
+        let stderr_handle = tokio::spawn(async move {
+            let mut lines = BufReader::new(stderr).lines();
+            while let Ok(Some(line)) = lines.next_line().await {
+                if stderr_tx.send(ExecOutput::Stderr(line)).await.is_err() {
+                    break;
                 }
             }
         });
-        
-        // Wait for command to complete
-        let status = match tokio::task::spawn_blocking(move || child.wait()).await {
-            Ok(Ok(status)) => status,
-            Ok(Err(err)) => {
-                return ExecutionResult {
-                    command: command.to_string(),
+
+        let command_owned = command.to_string();
+        tokio::spawn(async move {
+            // Race the process exiting on its own against a cancel
+            // request; on cancel, kill the child and reap it rather than
+            // leaving a zombie
+            let was_cancelled = tokio::select! {
+                status = child.wait() => {
+                    let _ = stdout_handle.await;
+                    let _ = stderr_handle.await;
+
+                    let done = match status {
+                        Ok(status) => {
+                            let error_message = if let Some(code) = status.code() {
+                                if code != 0 {
+                                    Some(format!(
+                                        "Command failed with exit code {}: {}",
+                                        code,
+                                        CommandExecutor::get_curl_error_message(code)
+                                    ))
+                                } else {
+                                    None
+                                }
+                            } else {
+                                Some("Process terminated by signal".to_string())
+                            };
+
+                            ExecutionResult {
+                                command: command_owned,
+                                exit_code: status.code(),
+                                stdout: String::new(),
+                                stderr: String::new(),
+                                execution_time: start_time.elapsed(),
+                                error: error_message,
+                                cancelled: false,
+                            }
+                        }
+                        Err(err) => ExecutionResult {
+                            command: command_owned,
+                            exit_code: None,
+                            stdout: String::new(),
+                            stderr: String::new(),
+                            execution_time: start_time.elapsed(),
+                            error: Some(format!("Failed to wait for command: {}", err)),
+                            cancelled: false,
+                        },
+                    };
+
+                    let _ = tx.send(ExecOutput::Done(done)).await;
+                    return;
+                }
+                _ = &mut cancel_rx => true,
+            };
+
+            if was_cancelled {
+                let _ = child.start_kill();
+                let _ = child.wait().await;
+                let _ = stdout_handle.await;
+                let _ = stderr_handle.await;
+
+                let done = ExecutionResult {
+                    command: command_owned,
                     exit_code: None,
                     stdout: String::new(),
                     stderr: String::new(),
                     execution_time: start_time.elapsed(),
-                    error: Some(format!("Failed to wait for command: {}", err)),
+                    error: Some("Cancelled by user".to_string()),
+                    cancelled: true,
                 };
+                let _ = tx.send(ExecOutput::Done(done)).await;
             }
-            Err(err) => {
-                return ExecutionResult {
-                    command: command.to_string(),
-                    exit_code: None,
-                    stdout: String::new(),
-                    stderr: String::new(),
-                    execution_time: start_time.elapsed(),
-                    error: Some(format!("Failed to join task: {}", err)),
-                };
+        });
+
+        ExecutionHandle { events: rx, cancel_tx: Some(cancel_tx) }
+    }
+
+    /// Execute a curl command, streaming `ExecOutput` events to the
+    /// returned channel as they happen instead of buffering everything
+    /// until the process exits. A thin wrapper around
+    /// `execute_cancellable` for callers that just want the event stream
+    /// without needing to cancel it.
+    pub async fn execute_streaming(&self, command: &str) -> mpsc::Receiver<ExecOutput> {
+        let mut handle = self.execute_cancellable(command).await;
+        let (tx, rx) = mpsc::channel(256);
+
+        tokio::spawn(async move {
+            while let Some(event) = handle.recv().await {
+                let is_done = matches!(event, ExecOutput::Done(_));
+                if tx.send(event).await.is_err() {
+                    break;
+                }
+                if is_done {
+                    break;
+                }
             }
-        };
-        
-        // Collect stdout and stderr
+        });
+
+        rx
+    }
+
+    /// Execute a curl command and wait for it to finish, returning the
+    /// complete result. A thin wrapper around `execute_streaming` that
+    /// folds the incremental events into a single `ExecutionResult`, for
+    /// callers that only want the blocking behavior.
+    pub async fn execute(&self, command: &str) -> ExecutionResult {
+        let mut rx = self.execute_streaming(command).await;
         let mut stdout_output = String::new();
-        while let Ok(Some(line)) = tokio::time::timeout(Duration::from_millis(100), stdout_rx.recv()).await {
-            stdout_output.push_str(&line);
-            stdout_output.push('\n');
-        }
-        
         let mut stderr_output = String::new();
-        while let Ok(Some(line)) = tokio::time::timeout(Duration::from_millis(100), stderr_rx.recv()).await {
-            stderr_output.push_str(&line);
-            stderr_output.push('\n');
-        }
-        
-        // Return result with enhanced error information
-        let error_message = if let Some(code) = status.code() {
-            if code != 0 {
-                Some(format!("Command failed with exit code {}: {}", code, CommandExecutor::get_curl_error_message(code)))
-            } else {
-                None
+
+        let result = loop {
+            match rx.recv().await {
+                Some(ExecOutput::Stdout(line)) => {
+                    stdout_output.push_str(&line);
+                    stdout_output.push('\n');
+                }
+                Some(ExecOutput::Stderr(line)) => {
+                    stderr_output.push_str(&line);
+                    stderr_output.push('\n');
+                }
+                Some(ExecOutput::Progress { .. }) => {}
+                Some(ExecOutput::Done(mut result)) => {
+                    result.stdout = stdout_output;
+                    result.stderr = stderr_output;
+                    break result;
+                }
+                // The channel closed without a `Done` event; shouldn't
+                // happen, but keeps `execute` total rather than panicking
+                None => {
+                    break ExecutionResult {
+                        command: command.to_string(),
+                        exit_code: None,
+                        stdout: stdout_output,
+                        stderr: stderr_output,
+                        execution_time: Duration::default(),
+                        error: Some("Execution stream closed unexpectedly".to_string()),
+                        cancelled: false,
+                    };
+                }
             }
-        } else {
-            Some("Process terminated by signal".to_string())
         };
 
-        ExecutionResult {
-            command: command.to_string(),
-            exit_code: status.code(),
-            stdout: stdout_output,
-            stderr: stderr_output,
-            execution_time: start_time.elapsed(),
-            error: error_message,
+        if let Some(logger) = &self.logger {
+            logger.log(&result);
         }
+
+        result
+    }
+
+    /// Execute curl inside a pseudo-terminal instead of a piped
+    /// stdout/stderr, so curl detects a TTY and emits its live progress
+    /// meter and interactive credential/known-hosts prompts. See
+    /// `crate::execution::pty` for details.
+    #[cfg(feature = "pty")]
+    pub fn execute_pty(&self, command: &str) -> Result<crate::execution::pty::PtyHandle, String> {
+        crate::execution::pty::spawn_pty(&self.curl_path, command)
     }
 
     /// Get a human-readable error message for curl exit codes
@@ -293,6 +463,7 @@ impl MockCommandExecutor {
             stderr: String::new(),
             execution_time: Duration::from_millis(100),
             error: None,
+            cancelled: false,
         }
     }
 }
@@ -311,5 +482,35 @@ mod tests {
         assert_eq!(result.stdout, "Mock stdout output");
         assert!(result.stderr.is_empty());
         assert!(result.error.is_none());
+        assert!(!result.cancelled);
+    }
+
+    #[tokio::test]
+    async fn test_execution_handle_cancel_sends_signal_once() {
+        let (_tx, rx) = mpsc::channel(1);
+        let (cancel_tx, cancel_rx) = oneshot::channel();
+        let mut handle = ExecutionHandle { events: rx, cancel_tx: Some(cancel_tx) };
+
+        handle.cancel().await;
+        assert_eq!(cancel_rx.await, Ok(()));
+
+        // Cancelling again once the sender is already consumed is a no-op,
+        // not a panic
+        handle.cancel().await;
+    }
+
+    #[tokio::test]
+    async fn test_execute_streaming_emits_done_for_invalid_command() {
+        let executor = CommandExecutor { curl_path: "curl".to_string(), logger: None };
+        let mut rx = executor.execute_streaming("not-curl --version").await;
+
+        match rx.recv().await {
+            Some(ExecOutput::Done(result)) => {
+                assert!(result.error.is_some());
+                assert_eq!(result.exit_code, None);
+            }
+            other => panic!("expected a Done event, got {:?}", other),
+        }
+        assert!(rx.recv().await.is_none());
     }
 }
\ No newline at end of file
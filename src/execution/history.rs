@@ -0,0 +1,192 @@
+//! An in-memory record of what a command's previous executions returned,
+//! so the TUI can show past responses, diff two runs, and re-display
+//! timing/size without re-executing - the response-side counterpart to
+//! `App::history`'s record of past commands.
+
+use super::output::ResponseInfo;
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+
+/// How large a `ResponseRecord`'s body is; streamed/binary bodies are
+/// inspired by the streaming logs interfaces in docker clients, which
+/// show a bounded preview rather than buffering the whole stream
+#[derive(Debug, Clone, PartialEq)]
+pub enum ResponseBody {
+    /// Body of at most 64 KiB whose Content-Type does not declare JSON, kept
+    /// in full whatever its content type
+    Text(String),
+    /// Body whose Content-Type declares JSON, kept as received
+    Json(String),
+    /// A body over 64 KiB whose Content-Type does not declare JSON, kept
+    /// only as a truncated preview
+    Binary { preview: Vec<u8>, truncated: bool },
+}
+
+/// One completed execution of a `CurlCommand`, as appended to
+/// `ResponseHistory` by `ResponseHistory::record`
+#[derive(Debug, Clone)]
+pub struct ResponseRecord {
+    /// Unique identifier
+    pub id: String,
+    /// The `CurlCommand.id` this response belongs to
+    pub command_id: String,
+    /// HTTP status code, if one was parsed
+    pub status: Option<u16>,
+    /// Response headers
+    pub headers: Vec<(String, String)>,
+    /// Response body
+    pub body: ResponseBody,
+    /// Elapsed execution time, in milliseconds
+    pub elapsed_ms: u64,
+    /// Response body size in bytes
+    pub size_bytes: usize,
+    /// When the execution completed
+    pub executed_at: DateTime<Utc>,
+}
+
+impl ResponseRecord {
+    /// Build a record from a parsed `ResponseInfo`, classifying the body
+    /// as `Json` when the headers declare it, `Text` when it's reasonably
+    /// sized, and a truncated `Binary` preview otherwise
+    pub fn from_response_info(command_id: String, info: &ResponseInfo) -> Self {
+        const MAX_TEXT_BYTES: usize = 64 * 1024;
+
+        let is_json = info
+            .headers
+            .iter()
+            .any(|(key, value)| key.eq_ignore_ascii_case("content-type") && value.contains("json"));
+
+        let body = if is_json {
+            ResponseBody::Json(info.body.clone())
+        } else if info.size <= MAX_TEXT_BYTES {
+            ResponseBody::Text(info.body.clone())
+        } else {
+            let preview_len = info.body.len().min(MAX_TEXT_BYTES);
+            ResponseBody::Binary {
+                preview: info.body.as_bytes()[..preview_len].to_vec(),
+                truncated: info.body.len() > preview_len,
+            }
+        };
+
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            command_id,
+            status: info.status_code,
+            headers: info.headers.clone(),
+            body,
+            elapsed_ms: info.time.as_millis() as u64,
+            size_bytes: info.size,
+            executed_at: Utc::now(),
+        }
+    }
+}
+
+/// In-memory execution history, keyed by `CurlCommand.id`, bounded to
+/// `capacity` records per command so a long-running session doesn't grow
+/// without limit
+pub struct ResponseHistory {
+    records: HashMap<String, Vec<ResponseRecord>>,
+    capacity: usize,
+}
+
+impl ResponseHistory {
+    /// A history that keeps at most `capacity` most-recent records per
+    /// command
+    pub fn new(capacity: usize) -> Self {
+        Self { records: HashMap::new(), capacity: capacity.max(1) }
+    }
+
+    /// Append `record`, evicting the oldest entry for its command if the
+    /// per-command list is already at `capacity`
+    pub fn record(&mut self, record: ResponseRecord) {
+        let entries = self.records.entry(record.command_id.clone()).or_default();
+        entries.push(record);
+        if entries.len() > self.capacity {
+            entries.remove(0);
+        }
+    }
+
+    /// Every recorded response for `command_id`, oldest first
+    pub fn for_command(&self, command_id: &str) -> &[ResponseRecord] {
+        self.records.get(command_id).map(|v| v.as_slice()).unwrap_or(&[])
+    }
+
+    /// The most recent response recorded for `command_id`, if any
+    pub fn latest_for_command(&self, command_id: &str) -> Option<&ResponseRecord> {
+        self.for_command(command_id).last()
+    }
+}
+
+impl Default for ResponseHistory {
+    /// Keep the 20 most recent responses per command
+    fn default() -> Self {
+        Self::new(20)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn response_info(body: &str, content_type: Option<&str>) -> ResponseInfo {
+        ResponseInfo {
+            status_code: Some(200),
+            status_message: Some("OK".to_string()),
+            headers: content_type
+                .map(|ct| vec![("Content-Type".to_string(), ct.to_string())])
+                .unwrap_or_default(),
+            body: body.to_string(),
+            size: body.len(),
+            time: Duration::from_millis(42),
+            content_type: content_type.map(|ct| ct.to_string()),
+            redirect_chain: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_from_response_info_classifies_json_body() {
+        let info = response_info("{\"ok\":true}", Some("application/json"));
+        let record = ResponseRecord::from_response_info("cmd-1".to_string(), &info);
+        assert!(matches!(record.body, ResponseBody::Json(_)));
+        assert_eq!(record.status, Some(200));
+        assert_eq!(record.elapsed_ms, 42);
+    }
+
+    #[test]
+    fn test_from_response_info_classifies_text_body() {
+        let info = response_info("hello world", Some("text/plain"));
+        let record = ResponseRecord::from_response_info("cmd-1".to_string(), &info);
+        assert!(matches!(record.body, ResponseBody::Text(_)));
+    }
+
+    #[test]
+    fn test_history_evicts_oldest_past_capacity() {
+        let mut history = ResponseHistory::new(2);
+        for i in 0..3 {
+            let info = response_info(&format!("response {}", i), None);
+            history.record(ResponseRecord::from_response_info("cmd-1".to_string(), &info));
+        }
+
+        let records = history.for_command("cmd-1");
+        assert_eq!(records.len(), 2);
+        assert!(matches!(&records[0].body, ResponseBody::Text(b) if b == "response 1"));
+        assert!(matches!(&records[1].body, ResponseBody::Text(b) if b == "response 2"));
+    }
+
+    #[test]
+    fn test_latest_for_command_returns_most_recent() {
+        let mut history = ResponseHistory::default();
+        history.record(ResponseRecord::from_response_info("cmd-1".to_string(), &response_info("first", None)));
+        history.record(ResponseRecord::from_response_info("cmd-1".to_string(), &response_info("second", None)));
+
+        let latest = history.latest_for_command("cmd-1").unwrap();
+        assert!(matches!(&latest.body, ResponseBody::Text(b) if b == "second"));
+    }
+
+    #[test]
+    fn test_for_command_is_empty_for_unknown_command() {
+        let history = ResponseHistory::default();
+        assert!(history.for_command("missing").is_empty());
+    }
+}
@@ -1,5 +1,17 @@
+pub mod access_log;
 pub mod executor;
+pub mod history;
 pub mod output;
+#[cfg(feature = "pty")]
+pub mod pty;
+#[cfg(test)]
+pub mod test_server;
 
+pub use access_log::{AccessLogRecord, AccessLogger, FileLogOptions};
 pub use executor::{CommandExecutor, ExecutionResult};
-pub use output::{OutputFormat, OutputParser, ResponseInfo};
\ No newline at end of file
+pub use history::{ResponseBody, ResponseHistory, ResponseRecord};
+pub use output::{DirEntry, OutputFormat, OutputParser, ResponseInfo};
+#[cfg(feature = "pty")]
+pub use pty::PtyHandle;
+#[cfg(test)]
+pub use test_server::TestServer;
\ No newline at end of file
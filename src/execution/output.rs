@@ -1,13 +1,22 @@
 use super::executor::ExecutionResult;
+use serde::Serialize;
+use std::collections::BTreeMap;
 use std::time::Duration;
 
 /// Output format
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum OutputFormat {
-    /// Raw output
+    /// Raw output: status line, headers and body with no pretty-printing.
+    /// `format_response` rebuilds these from the parsed `ResponseInfo`
+    /// (an `HTTP/1.1` status line, the body trimmed and de-chunked)
     Raw,
-    /// Formatted output
+    /// Formatted output: status, headers, and a content-type-aware
+    /// pretty-printed body
     Formatted,
+    /// Machine-readable JSON (status line, headers map, timing, body)
+    Json,
+    /// Just the content-type-aware pretty-printed body, nothing else
+    Pretty,
 }
 
 /// Response information
@@ -25,6 +34,34 @@ pub struct ResponseInfo {
     pub size: usize,
     /// Response time
     pub time: Duration,
+    /// The `Content-Type` response header, if one was present, used to
+    /// drive content-type-aware pretty-printing
+    pub content_type: Option<String>,
+    /// Status code/message of each intermediate response curl followed
+    /// before the final one (e.g. via `-L`), oldest first. Empty if there
+    /// was only a single response.
+    pub redirect_chain: Vec<(u16, String)>,
+}
+
+/// A single entry from an FTP/SFTP directory listing, parsed out of
+/// curl's raw `LIST`/`MLSD` output by `OutputParser::parse_dir_listing`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DirEntry {
+    /// File or directory name
+    pub name: String,
+    /// Size in bytes
+    pub size: u64,
+    /// Last-modified timestamp, as reported by the server. Kept as the
+    /// server's own string (`MLSD`'s `YYYYMMDDHHMMSS`, or the unix
+    /// listing's `Mon DD HH:MM`/`Mon DD  YYYY`) rather than parsed into a
+    /// `DateTime`, since the classic unix format omits the year and its
+    /// meaning depends on the server's locale.
+    pub modified: Option<String>,
+    /// Whether this entry is a directory
+    pub is_dir: bool,
+    /// Unix permission string (e.g. `drwxr-xr-x`), when the listing is in
+    /// the classic long format rather than `MLSD`
+    pub permissions: Option<String>,
 }
 
 /// Output parser
@@ -35,16 +72,23 @@ impl OutputParser {
     pub fn parse(result: &ExecutionResult) -> ResponseInfo {
         // Combine stdout and stderr
         let output = format!("{}{}", result.stdout, result.stderr);
-        
-        // Parse status code and message
-        let (status_code, status_message) = Self::parse_status(&output);
-        
-        // Parse headers and body
-        let (headers, body) = Self::parse_headers_and_body(&output);
-        
+
+        let (redirect_chain, status_code, status_message, headers, raw_body) = Self::parse_blocks(&output);
+
+        let body = if Self::is_chunked(&headers) {
+            Self::decode_chunked_body(&raw_body)
+        } else {
+            raw_body.trim().to_string()
+        };
+
         // Calculate size
         let size = body.len();
-        
+
+        let content_type = headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case("content-type"))
+            .map(|(_, value)| value.clone());
+
         ResponseInfo {
             status_code,
             status_message,
@@ -52,61 +96,129 @@ impl OutputParser {
             body,
             size,
             time: result.execution_time,
+            content_type,
+            redirect_chain,
         }
     }
-    
-    /// Parse HTTP status code and message
-    fn parse_status(output: &str) -> (Option<u16>, Option<String>) {
-        // Look for HTTP status line
-        for line in output.lines() {
-            if line.starts_with("HTTP/") {
-                // Extract status code and message
-                let parts: Vec<&str> = line.split_whitespace().collect();
-                if parts.len() >= 3 {
-                    if let Ok(code) = parts[1].parse::<u16>() {
-                        let message = parts[2..].join(" ");
-                        return (Some(code), Some(message));
-                    }
-                }
+
+    /// Parse an `HTTP/<version> <code> <reason...>` status line, tolerating
+    /// a missing reason phrase (e.g. `HTTP/2 200`)
+    fn parse_status_line(line: &str) -> Option<(u16, String)> {
+        let rest = line.strip_prefix("HTTP/")?;
+        let (_version, rest) = rest.split_once(char::is_whitespace)?;
+        let rest = rest.trim_start();
+        let (code, reason) = rest.split_once(char::is_whitespace).unwrap_or((rest, ""));
+        let code = code.trim().parse::<u16>().ok()?;
+        Some((code, reason.trim().to_string()))
+    }
+
+    /// Walk `output`'s `HTTP/...` header blocks. When curl followed
+    /// redirects (`-L`), there's one block per hop; everything up to the
+    /// last one is the redirect chain, and only the LAST block's status
+    /// and headers describe the actual response. Returns the redirect
+    /// chain, the final status code/message, the final block's headers,
+    /// and the raw (possibly still chunked) body that followed it.
+    fn parse_blocks(output: &str) -> (Vec<(u16, String)>, Option<u16>, Option<String>, Vec<(String, String)>, String) {
+        let lines: Vec<&str> = output.lines().collect();
+        let http_line_indices: Vec<usize> = lines
+            .iter()
+            .enumerate()
+            .filter(|(_, line)| line.starts_with("HTTP/"))
+            .map(|(i, _)| i)
+            .collect();
+
+        let Some(&final_index) = http_line_indices.last() else {
+            // No status line at all; nothing to treat as headers
+            return (Vec::new(), None, None, Vec::new(), output.to_string());
+        };
+
+        let redirect_chain = http_line_indices[..http_line_indices.len() - 1]
+            .iter()
+            .filter_map(|&i| Self::parse_status_line(lines[i]))
+            .collect();
+
+        let (status_code, status_message) = match Self::parse_status_line(lines[final_index]) {
+            Some((code, message)) => (Some(code), Some(message)),
+            None => (None, None),
+        };
+
+        let mut headers = Vec::new();
+        let mut body_start = lines.len();
+        for (i, line) in lines.iter().enumerate().skip(final_index + 1) {
+            if line.is_empty() {
+                body_start = i + 1;
+                break;
+            }
+            if let Some((key, value)) = line.split_once(':') {
+                headers.push((key.trim().to_string(), value.trim().to_string()));
             }
         }
-        
-        (None, None)
+
+        let body = lines[body_start.min(lines.len())..].join("\n");
+        (redirect_chain, status_code, status_message, headers, body)
     }
-    
-    /// Parse headers and body
-    fn parse_headers_and_body(output: &str) -> (Vec<(String, String)>, String) {
-        let mut headers = Vec::new();
-        let mut body = String::new();
-        let mut in_body = false;
-        
-        // Split output into lines
-        let lines: Vec<&str> = output.lines().collect();
-        
-        // Find where headers end and body begins
-        for (i, line) in lines.iter().enumerate() {
-            if in_body {
-                // Already in body, append line
-                body.push_str(line);
-                body.push('\n');
-            } else if line.is_empty() {
-                // Empty line marks end of headers
-                in_body = true;
-            } else if line.starts_with("HTTP/") {
-                // Skip HTTP status line
-                continue;
-            } else {
-                // Parse header
-                let parts: Vec<&str> = line.splitn(2, ':').collect();
-                if parts.len() == 2 {
-                    let key = parts[0].trim().to_string();
-                    let value = parts[1].trim().to_string();
-                    headers.push((key, value));
-                }
+
+    /// Whether `headers` declares `Transfer-Encoding: chunked`
+    fn is_chunked(headers: &[(String, String)]) -> bool {
+        headers
+            .iter()
+            .any(|(key, value)| key.eq_ignore_ascii_case("transfer-encoding") && value.to_ascii_lowercase().contains("chunked"))
+    }
+
+    /// Decode an HTTP chunked-transfer-encoded body: each chunk is a
+    /// hex length line (optional `;extension` suffix ignored), that many
+    /// bytes of data, a trailing newline, repeating until a zero-length
+    /// chunk ends the stream. Stops (returning what it has so far) on any
+    /// malformed length line.
+    ///
+    /// Decodes over raw bytes rather than `char` boundaries: a chunk
+    /// length is a byte count, and a chunk boundary can legally land in
+    /// the middle of a multibyte UTF-8 sequence, so slicing a `&str` by
+    /// that count can panic on perfectly valid curl output. `String` is
+    /// only built once every chunk has been reassembled, via a lossy
+    /// UTF-8 conversion in case a malformed length leaves a sequence
+    /// truncated at the very end of the body.
+    fn decode_chunked_body(body: &str) -> String {
+        let mut decoded: Vec<u8> = Vec::new();
+        let mut rest = body.as_bytes();
+
+        loop {
+            let rest_trimmed = Self::trim_start_crlf(rest);
+            let Some(line_end) = rest_trimmed.iter().position(|&b| b == b'\n') else { break };
+            let size_line = Self::trim_end_cr(&rest_trimmed[..line_end]);
+            let size_str = size_line.split(|&b| b == b';').next().unwrap_or(&[]);
+            let Ok(size_str) = std::str::from_utf8(size_str) else { break };
+            let size_str = size_str.trim();
+            if size_str.is_empty() {
+                break;
+            }
+            let Ok(size) = usize::from_str_radix(size_str, 16) else { break };
+            if size == 0 {
+                break;
             }
+
+            let chunk_data_start = line_end + 1;
+            let chunk_data_end = (chunk_data_start + size).min(rest_trimmed.len());
+            decoded.extend_from_slice(&rest_trimmed[chunk_data_start..chunk_data_end]);
+
+            rest = &rest_trimmed[chunk_data_end..];
         }
-        
-        (headers, body.trim().to_string())
+
+        String::from_utf8_lossy(&decoded).into_owned()
+    }
+
+    /// Trim leading `\r`/`\n` bytes, the byte-slice equivalent of
+    /// `str::trim_start_matches(['\r', '\n'])`
+    fn trim_start_crlf(bytes: &[u8]) -> &[u8] {
+        let start = bytes.iter().position(|&b| b != b'\r' && b != b'\n').unwrap_or(bytes.len());
+        &bytes[start..]
+    }
+
+    /// Trim trailing `\r` bytes, the byte-slice equivalent of
+    /// `str::trim_end_matches('\r')`
+    fn trim_end_cr(bytes: &[u8]) -> &[u8] {
+        let end = bytes.iter().rposition(|&b| b != b'\r').map(|i| i + 1).unwrap_or(0);
+        &bytes[..end]
     }
     
     /// Format response for display
@@ -114,7 +226,110 @@ impl OutputParser {
         match format {
             OutputFormat::Raw => Self::format_raw(info),
             OutputFormat::Formatted => Self::format_formatted(info),
+            OutputFormat::Json => Self::format_json(info),
+            OutputFormat::Pretty => Self::format_body(&info.body, info.content_type.as_deref()),
+        }
+    }
+
+    /// Pretty-print `body` according to `content_type`: re-indent JSON
+    /// (2-space), reflow XML/HTML with nesting indentation, and leave
+    /// anything else (including `text/plain`) untouched
+    fn format_body(body: &str, content_type: Option<&str>) -> String {
+        let mime = content_type.unwrap_or("").split(';').next().unwrap_or("").trim().to_ascii_lowercase();
+
+        if mime.contains("json") {
+            if let Ok(value) = serde_json::from_str::<serde_json::Value>(body) {
+                if let Ok(pretty) = serde_json::to_string_pretty(&value) {
+                    return pretty;
+                }
+            }
+            return body.to_string();
+        }
+
+        if mime.contains("xml") || mime.contains("html") {
+            return Self::reflow_markup(body);
+        }
+
+        body.to_string()
+    }
+
+    /// Reflow an XML/HTML document with 2-space nesting indentation, one
+    /// tag (or run of text) per line. Not a real parser: it splits on `<`
+    /// and `>` and indents by closing-tag depth, which is good enough to
+    /// turn a minified response into something readable without pulling in
+    /// a full markup parser for display purposes only.
+    fn reflow_markup(body: &str) -> String {
+        let mut output = String::new();
+        let mut depth: usize = 0;
+
+        for raw_tag in body.split('<').filter(|s| !s.trim().is_empty()) {
+            let Some((tag, rest)) = raw_tag.split_once('>') else {
+                output.push_str(raw_tag.trim());
+                continue;
+            };
+
+            let is_closing = tag.starts_with('/');
+            let is_self_closing = tag.ends_with('/') || Self::is_void_element(tag);
+
+            if is_closing {
+                depth = depth.saturating_sub(1);
+            }
+
+            output.push_str(&"  ".repeat(depth));
+            output.push('<');
+            output.push_str(tag);
+            output.push_str(">\n");
+
+            if !is_closing && !is_self_closing {
+                depth += 1;
+            }
+
+            let text = rest.trim();
+            if !text.is_empty() {
+                output.push_str(&"  ".repeat(depth));
+                output.push_str(text);
+                output.push('\n');
+            }
+        }
+
+        output.trim_end().to_string()
+    }
+
+    /// Whether `tag` (without the leading `<`) is a void HTML element that
+    /// never has a closing tag, e.g. `br`, `img`
+    fn is_void_element(tag: &str) -> bool {
+        const VOID_ELEMENTS: &[&str] = &[
+            "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "param", "source", "track", "wbr",
+        ];
+        let name = tag.trim_start_matches('!').split_whitespace().next().unwrap_or("").to_ascii_lowercase();
+        VOID_ELEMENTS.contains(&name.as_str())
+    }
+
+    /// Format response as structured JSON, suitable for piping into `jq`
+    /// or saving as a fixture
+    fn format_json(info: &ResponseInfo) -> String {
+        #[derive(Serialize)]
+        struct JsonResponse<'a> {
+            status_code: Option<u16>,
+            status_message: Option<&'a str>,
+            headers: BTreeMap<&'a str, &'a str>,
+            time_ms: u128,
+            size: usize,
+            body: &'a str,
+            redirect_chain: &'a [(u16, String)],
         }
+
+        let response = JsonResponse {
+            status_code: info.status_code,
+            status_message: info.status_message.as_deref(),
+            headers: info.headers.iter().map(|(k, v)| (k.as_str(), v.as_str())).collect(),
+            time_ms: info.time.as_millis(),
+            size: info.size,
+            body: &info.body,
+            redirect_chain: &info.redirect_chain,
+        };
+
+        serde_json::to_string_pretty(&response).unwrap_or_else(|_| "{}".to_string())
     }
     
     /// Format response as raw output
@@ -163,11 +378,92 @@ impl OutputParser {
         
         // Format body
         output.push_str("Body:\n");
-        output.push_str(&info.body);
-        
+        output.push_str(&Self::format_body(&info.body, info.content_type.as_deref()));
+
         output
     }
     
+    /// Parse an FTP/SFTP directory listing out of `result.stdout`, if it
+    /// looks like one. Handles both the classic unix long format
+    /// (`drwxr-xr-x ... name`) and `MLSD`'s `type=...;size=...; name`
+    /// key/value format; returns `None` if no line matches either (e.g.
+    /// this was an HTTP response, not a directory listing).
+    pub fn parse_dir_listing(result: &ExecutionResult) -> Option<Vec<DirEntry>> {
+        let lines: Vec<&str> = result.stdout.lines().map(str::trim_end).filter(|l| !l.is_empty()).collect();
+        if lines.is_empty() {
+            return None;
+        }
+
+        if lines.iter().all(|line| Self::is_mlsd_line(line)) {
+            return Some(lines.iter().filter_map(|line| Self::parse_mlsd_line(line)).collect());
+        }
+
+        if lines.iter().all(|line| Self::is_unix_listing_line(line)) {
+            return Some(lines.iter().filter_map(|line| Self::parse_unix_listing_line(line)).collect());
+        }
+
+        None
+    }
+
+    /// Whether `line` looks like an `MLSD` fact line: semicolon-separated
+    /// `key=value` facts, a space, then the filename
+    fn is_mlsd_line(line: &str) -> bool {
+        line.contains("type=") && line.contains(';')
+    }
+
+    /// Parse one `MLSD` line, e.g. `type=file;size=1234;modify=20230115093000; report.txt`
+    fn parse_mlsd_line(line: &str) -> Option<DirEntry> {
+        let (facts, name) = line.split_once(' ')?;
+        let name = name.trim().to_string();
+        if name.is_empty() {
+            return None;
+        }
+
+        let mut entry_type = None;
+        let mut size = 0u64;
+        let mut modified = None;
+
+        for fact in facts.split(';') {
+            let Some((key, value)) = fact.split_once('=') else { continue };
+            match key.to_ascii_lowercase().as_str() {
+                "type" => entry_type = Some(value.to_ascii_lowercase()),
+                "size" => size = value.parse().unwrap_or(0),
+                "modify" => modified = Some(value.to_string()),
+                _ => {}
+            }
+        }
+
+        Some(DirEntry {
+            name,
+            size,
+            modified,
+            is_dir: matches!(entry_type.as_deref(), Some("dir") | Some("cdir") | Some("pdir")),
+            permissions: None,
+        })
+    }
+
+    /// Whether `line` looks like a classic unix `LIST` line, e.g.
+    /// `drwxr-xr-x 2 user group 4096 Jan 01 12:00 name`
+    fn is_unix_listing_line(line: &str) -> bool {
+        matches!(line.as_bytes().first(), Some(b'-') | Some(b'd') | Some(b'l')) && line.split_whitespace().count() >= 9
+    }
+
+    /// Parse one classic unix `LIST` line
+    fn parse_unix_listing_line(line: &str) -> Option<DirEntry> {
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        if fields.len() < 9 {
+            return None;
+        }
+
+        let permissions = fields[0].to_string();
+        let is_dir = permissions.starts_with('d');
+        let size: u64 = fields[4].parse().ok()?;
+        let modified = format!("{} {} {}", fields[5], fields[6], fields[7]);
+        let name = fields[8..].join(" ");
+
+        Some(DirEntry { name, size, modified: Some(modified), is_dir, permissions: Some(permissions) })
+    }
+
     /// Format size in human-readable format
     fn format_size(size: usize) -> String {
         if size < 1024 {
@@ -191,25 +487,91 @@ mod tests {
     #[test]
     fn test_parse_status() {
         let output = "HTTP/1.1 200 OK\r\nContent-Type: text/html\r\n\r\n<html></html>";
-        let (code, message) = OutputParser::parse_status(output);
+        let (_, code, message, _, _) = OutputParser::parse_blocks(output);
         assert_eq!(code, Some(200));
         assert_eq!(message, Some("OK".to_string()));
     }
-    
+
+    #[test]
+    fn test_parse_status_line_tolerates_missing_reason_phrase() {
+        assert_eq!(OutputParser::parse_status_line("HTTP/2 200"), Some((200, String::new())));
+        assert_eq!(OutputParser::parse_status_line("HTTP/1.1 404 Not Found"), Some((404, "Not Found".to_string())));
+        assert_eq!(OutputParser::parse_status_line("not a status line"), None);
+    }
+
     #[test]
     fn test_parse_headers_and_body() {
         let output = "HTTP/1.1 200 OK\r\nContent-Type: text/html\r\nContent-Length: 13\r\n\r\n<html></html>";
-        let (headers, body) = OutputParser::parse_headers_and_body(output);
-        
+        let (_, _, _, headers, body) = OutputParser::parse_blocks(output);
+
         assert_eq!(headers.len(), 2);
         assert_eq!(headers[0].0, "Content-Type");
         assert_eq!(headers[0].1, "text/html");
         assert_eq!(headers[1].0, "Content-Length");
         assert_eq!(headers[1].1, "13");
-        
+
         assert_eq!(body, "<html></html>");
     }
-    
+
+    #[test]
+    fn test_parse_keeps_only_final_response_of_a_redirect_chain() {
+        let result = ExecutionResult {
+            command: "curl -L https://example.com".to_string(),
+            exit_code: Some(0),
+            stdout: "HTTP/1.1 301 Moved Permanently\r\nLocation: https://example.com/new\r\n\r\nHTTP/1.1 200 OK\r\nContent-Type: text/plain\r\n\r\nhello".to_string(),
+            stderr: String::new(),
+            execution_time: Duration::from_millis(20),
+            error: None,
+            cancelled: false,
+        };
+
+        let info = OutputParser::parse(&result);
+
+        assert_eq!(info.redirect_chain, vec![(301, "Moved Permanently".to_string())]);
+        assert_eq!(info.status_code, Some(200));
+        assert_eq!(info.headers, vec![("Content-Type".to_string(), "text/plain".to_string())]);
+        assert_eq!(info.body, "hello");
+    }
+
+    #[test]
+    fn test_parse_decodes_chunked_transfer_encoding() {
+        let result = ExecutionResult {
+            command: "curl https://example.com".to_string(),
+            exit_code: Some(0),
+            stdout: "HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n4\r\nWiki\r\n5\r\npedia\r\n0\r\n\r\n".to_string(),
+            stderr: String::new(),
+            execution_time: Duration::from_millis(20),
+            error: None,
+            cancelled: false,
+        };
+
+        let info = OutputParser::parse(&result);
+
+        assert_eq!(info.body, "Wikipedia");
+    }
+
+    #[test]
+    fn test_parse_chunked_body_does_not_panic_on_utf8_boundary_split() {
+        // "café" is 5 bytes ('c','a','f' + the 2-byte encoding of 'é'). A
+        // declared chunk length of 4 lands the slice right between the two
+        // bytes of 'é' -- not a valid `char` boundary. Before the fix this
+        // panicked with "byte index 4 is not a char boundary"; now it must
+        // decode over raw bytes instead and degrade gracefully.
+        let result = ExecutionResult {
+            command: "curl https://example.com".to_string(),
+            exit_code: Some(0),
+            stdout: "HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n4\r\ncaf\u{e9}\r\n0\r\n\r\n".to_string(),
+            stderr: String::new(),
+            execution_time: Duration::from_millis(20),
+            error: None,
+            cancelled: false,
+        };
+
+        let info = OutputParser::parse(&result);
+
+        assert!(info.body.starts_with("caf"));
+    }
+
     #[test]
     fn test_parse_execution_result() {
         let result = ExecutionResult {
@@ -219,6 +581,7 @@ mod tests {
             stderr: String::new(),
             execution_time: Duration::from_millis(100),
             error: None,
+            cancelled: false,
         };
         
         let info = OutputParser::parse(&result);
@@ -231,6 +594,127 @@ mod tests {
         assert_eq!(info.time, Duration::from_millis(100));
     }
     
+    #[test]
+    fn test_format_response_json_includes_expected_fields() {
+        let result = ExecutionResult {
+            command: "curl https://example.com".to_string(),
+            exit_code: Some(0),
+            stdout: "HTTP/1.1 200 OK\r\nContent-Type: text/html\r\n\r\n<html></html>".to_string(),
+            stderr: String::new(),
+            execution_time: Duration::from_millis(50),
+            error: None,
+            cancelled: false,
+        };
+
+        let info = OutputParser::parse(&result);
+        let json = OutputParser::format_response(&info, OutputFormat::Json);
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(parsed["status_code"], 200);
+        assert_eq!(parsed["status_message"], "OK");
+        assert_eq!(parsed["headers"]["Content-Type"], "text/html");
+        assert_eq!(parsed["time_ms"], 50);
+        assert_eq!(parsed["body"], "<html></html>");
+    }
+
+    #[test]
+    fn test_format_body_pretty_prints_json_content_type() {
+        let body = OutputParser::format_body("{\"a\":1,\"b\":[2,3]}", Some("application/json"));
+        assert_eq!(body, "{\n  \"a\": 1,\n  \"b\": [\n    2,\n    3\n  ]\n}");
+    }
+
+    #[test]
+    fn test_format_body_leaves_invalid_json_untouched() {
+        let body = OutputParser::format_body("not json", Some("application/json; charset=utf-8"));
+        assert_eq!(body, "not json");
+    }
+
+    #[test]
+    fn test_format_body_reflows_xml() {
+        let body = OutputParser::format_body("<root><item>value</item></root>", Some("application/xml"));
+        assert_eq!(body, "<root>\n  <item>\n    value\n  </item>\n</root>");
+    }
+
+    #[test]
+    fn test_format_body_leaves_plain_text_untouched() {
+        let body = OutputParser::format_body("just some text", Some("text/plain"));
+        assert_eq!(body, "just some text");
+    }
+
+    #[test]
+    fn test_format_response_pretty_formats_json_body_only() {
+        let result = ExecutionResult {
+            command: "curl https://example.com".to_string(),
+            exit_code: Some(0),
+            stdout: "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\n\r\n{\"ok\":true}".to_string(),
+            stderr: String::new(),
+            execution_time: Duration::from_millis(10),
+            error: None,
+            cancelled: false,
+        };
+
+        let info = OutputParser::parse(&result);
+        let pretty = OutputParser::format_response(&info, OutputFormat::Pretty);
+
+        assert_eq!(pretty, "{\n  \"ok\": true\n}");
+    }
+
+    #[test]
+    fn test_parse_dir_listing_unix_long_format() {
+        let result = ExecutionResult {
+            command: "curl ftp://example.com/".to_string(),
+            exit_code: Some(0),
+            stdout: "drwxr-xr-x 2 user group 4096 Jan 01 12:00 uploads\n-rw-r--r-- 1 user group 1234 Feb 02 08:30 report.txt\n".to_string(),
+            stderr: String::new(),
+            execution_time: Duration::from_millis(10),
+            error: None,
+            cancelled: false,
+        };
+
+        let entries = OutputParser::parse_dir_listing(&result).expect("should parse as a directory listing");
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].name, "uploads");
+        assert!(entries[0].is_dir);
+        assert_eq!(entries[1].name, "report.txt");
+        assert!(!entries[1].is_dir);
+        assert_eq!(entries[1].size, 1234);
+    }
+
+    #[test]
+    fn test_parse_dir_listing_mlsd_format() {
+        let result = ExecutionResult {
+            command: "curl ftp://example.com/".to_string(),
+            exit_code: Some(0),
+            stdout: "type=dir;modify=20230101120000; uploads\ntype=file;size=1234;modify=20230202083000; report.txt\n".to_string(),
+            stderr: String::new(),
+            execution_time: Duration::from_millis(10),
+            error: None,
+            cancelled: false,
+        };
+
+        let entries = OutputParser::parse_dir_listing(&result).expect("should parse as a directory listing");
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].name, "uploads");
+        assert!(entries[0].is_dir);
+        assert_eq!(entries[1].name, "report.txt");
+        assert_eq!(entries[1].size, 1234);
+    }
+
+    #[test]
+    fn test_parse_dir_listing_returns_none_for_http_response() {
+        let result = ExecutionResult {
+            command: "curl https://example.com".to_string(),
+            exit_code: Some(0),
+            stdout: "HTTP/1.1 200 OK\r\nContent-Type: text/html\r\n\r\n<html></html>".to_string(),
+            stderr: String::new(),
+            execution_time: Duration::from_millis(10),
+            error: None,
+            cancelled: false,
+        };
+
+        assert!(OutputParser::parse_dir_listing(&result).is_none());
+    }
+
     #[test]
     fn test_format_size() {
         assert_eq!(OutputParser::format_size(100), "100 B");
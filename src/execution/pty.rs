@@ -0,0 +1,102 @@
+//! Optional PTY-backed execution. curl only emits its live `#`/percentage
+//! transfer progress meter and interactive credential/known-hosts prompts
+//! when it detects a TTY on stderr; `CommandExecutor`'s `Stdio::piped()`
+//! never looks like one. This module allocates a real pseudo-terminal,
+//! attaches curl's stdout/stderr to the slave side, and streams the
+//! master side back through the same `ExecOutput` events used by piped
+//! execution. Gated behind the `pty` feature since it pulls in
+//! `portable-pty`, which the default piped path doesn't need.
+
+use super::executor::ExecOutput;
+use portable_pty::{native_pty_system, Child, CommandBuilder, MasterPty, PtySize};
+use std::io::{BufRead, BufReader, Write};
+use tokio::sync::mpsc;
+
+/// A running curl execution attached to a pseudo-terminal. Unlike
+/// `ExecutionHandle`, stdout and stderr arrive merged into a single
+/// stream (that's how a PTY works, not a limitation of this type), and
+/// `write_input` can forward typed keystrokes - e.g. an SSH known-hosts
+/// "yes" or a `--user` password prompt - back to curl's stdin.
+pub struct PtyHandle {
+    events: mpsc::Receiver<ExecOutput>,
+    writer: Box<dyn Write + Send>,
+    child: Box<dyn Child + Send + Sync>,
+}
+
+impl PtyHandle {
+    /// Receive the next event, or `None` once curl has closed the PTY
+    pub async fn recv(&mut self) -> Option<ExecOutput> {
+        self.events.recv().await
+    }
+
+    /// Forward typed input to curl's stdin, e.g. answering a prompt it
+    /// printed to the PTY
+    pub fn write_input(&mut self, input: &str) -> std::io::Result<()> {
+        self.writer.write_all(input.as_bytes())?;
+        self.writer.flush()
+    }
+
+    /// Terminate the running process
+    pub fn kill(&mut self) -> std::io::Result<()> {
+        self.child.kill()
+    }
+}
+
+/// Execute curl with its stdout/stderr attached to a pseudo-terminal
+/// slave, so curl detects a TTY and behaves as it would in an
+/// interactive shell instead of piped to a file.
+pub fn spawn_pty(curl_path: &str, command: &str) -> Result<PtyHandle, String> {
+    let args: Vec<&str> = command.split_whitespace().collect();
+    if args.is_empty() || args[0] != "curl" {
+        return Err("Invalid curl command".to_string());
+    }
+
+    let pty_system = native_pty_system();
+    let pair = pty_system
+        .openpty(PtySize { rows: 24, cols: 80, pixel_width: 0, pixel_height: 0 })
+        .map_err(|err| format!("Failed to allocate PTY: {}", err))?;
+
+    let mut cmd = CommandBuilder::new(curl_path);
+    cmd.args(&args[1..]);
+
+    let child = pair
+        .slave
+        .spawn_command(cmd)
+        .map_err(|err| format!("Failed to spawn curl in PTY: {}", err))?;
+    // The slave fd now belongs to the child; drop our copy so the master
+    // reader sees EOF once curl exits instead of hanging open forever
+    drop(pair.slave);
+
+    let reader = pair
+        .master
+        .try_clone_reader()
+        .map_err(|err| format!("Failed to read PTY output: {}", err))?;
+    let writer = pair
+        .master
+        .take_writer()
+        .map_err(|err| format!("Failed to open PTY input: {}", err))?;
+
+    let (tx, rx) = mpsc::channel(256);
+    tokio::task::spawn_blocking(move || {
+        let lines = BufReader::new(reader).lines();
+        for line in lines {
+            let Ok(line) = line else { break };
+            if tx.blocking_send(ExecOutput::Stdout(line)).is_err() {
+                break;
+            }
+        }
+    });
+
+    Ok(PtyHandle { events: rx, writer, child })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spawn_pty_rejects_non_curl_command() {
+        let result = spawn_pty("curl", "not-curl --version");
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,181 @@
+//! An embedded local HTTP/HTTPS server with a handful of well-known
+//! routes, so integration tests can point the real `CommandExecutor` at
+//! `http://127.0.0.1:{port}` and assert on actual curl behavior
+//! (redirects, chunked transfer, TLS cert-verification exit codes)
+//! instead of relying on `MockCommandExecutor`'s hardcoded string.
+
+use hyper::service::{make_service_fn, service_fn};
+use hyper::{Body, Request, Response, Server, StatusCode};
+use std::convert::Infallible;
+use std::net::SocketAddr;
+use std::time::Duration;
+use tokio::sync::oneshot;
+
+/// A running instance of the test server. Dropping it stops the
+/// listener; `url()` gives the base address curl commands should target.
+pub struct TestServer {
+    addr: SocketAddr,
+    shutdown_tx: Option<oneshot::Sender<()>>,
+}
+
+impl TestServer {
+    /// Base URL for the plain-HTTP listener, e.g. `http://127.0.0.1:54321`
+    pub fn url(&self) -> String {
+        format!("http://{}", self.addr)
+    }
+
+    /// Start the plain-HTTP variant on an ephemeral port with routes:
+    /// - `/status/{code}` - responds with the given status code
+    /// - `/redirect/{n}` - redirects `n` times before a 200
+    /// - `/delay/{ms}` - sleeps before responding, to test timeouts
+    /// - `/echo` - reflects the request method, headers, and body
+    /// - `/chunked` - streams its body across several chunks
+    pub async fn start() -> Self {
+        let addr: SocketAddr = ([127, 0, 0, 1], 0).into();
+        let make_svc = make_service_fn(|_conn| async { Ok::<_, Infallible>(service_fn(handle)) });
+        let server = Server::bind(&addr).serve(make_svc);
+        let bound_addr = server.local_addr();
+
+        let (shutdown_tx, shutdown_rx) = oneshot::channel();
+        let graceful = server.with_graceful_shutdown(async {
+            let _ = shutdown_rx.await;
+        });
+        tokio::spawn(async move {
+            let _ = graceful.await;
+        });
+
+        Self { addr: bound_addr, shutdown_tx: Some(shutdown_tx) }
+    }
+
+    /// Start a self-signed HTTPS variant of the same routes, so tests can
+    /// exercise curl's cert-verification exit codes (51, 60) by pointing
+    /// at `https://127.0.0.1:{port}` without `-k`.
+    pub async fn start_https() -> Self {
+        let cert = rcgen::generate_simple_self_signed(vec!["127.0.0.1".to_string()])
+            .expect("generate self-signed certificate");
+        let cert_der = cert.serialize_der().expect("serialize certificate");
+        let key_der = cert.serialize_private_key_der();
+
+        let mut server_config = rustls::ServerConfig::builder()
+            .with_safe_defaults()
+            .with_no_client_auth()
+            .with_single_cert(vec![rustls::Certificate(cert_der)], rustls::PrivateKey(key_der))
+            .expect("build TLS server config");
+        server_config.alpn_protocols = vec![b"http/1.1".to_vec()];
+        let acceptor = tokio_rustls::TlsAcceptor::from(std::sync::Arc::new(server_config));
+
+        let listener = tokio::net::TcpListener::bind(("127.0.0.1", 0))
+            .await
+            .expect("bind ephemeral port");
+        let addr = listener.local_addr().expect("read bound address");
+
+        let (shutdown_tx, mut shutdown_rx) = oneshot::channel();
+        tokio::spawn(async move {
+            loop {
+                tokio::select! {
+                    _ = &mut shutdown_rx => break,
+                    accepted = listener.accept() => {
+                        let Ok((stream, _)) = accepted else { break };
+                        let acceptor = acceptor.clone();
+                        tokio::spawn(async move {
+                            if let Ok(tls_stream) = acceptor.accept(stream).await {
+                                let _ = hyper::server::conn::Http::new()
+                                    .serve_connection(tls_stream, service_fn(handle))
+                                    .await;
+                            }
+                        });
+                    }
+                }
+            }
+        });
+
+        Self { addr, shutdown_tx: Some(shutdown_tx) }
+    }
+
+    /// Stop the server. Idempotent; also runs automatically on drop.
+    pub fn shutdown(&mut self) {
+        if let Some(tx) = self.shutdown_tx.take() {
+            let _ = tx.send(());
+        }
+    }
+}
+
+impl Drop for TestServer {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
+
+async fn handle(req: Request<Body>) -> Result<Response<Body>, Infallible> {
+    let path = req.uri().path().to_string();
+    let segments: Vec<&str> = path.trim_start_matches('/').split('/').collect();
+
+    let response = match segments.as_slice() {
+        ["status", code] => {
+            let status = code.parse::<u16>().unwrap_or(200);
+            Response::builder()
+                .status(StatusCode::from_u16(status).unwrap_or(StatusCode::OK))
+                .body(Body::empty())
+                .unwrap()
+        }
+        ["redirect", count] => {
+            let remaining: u32 = count.parse().unwrap_or(0);
+            if remaining == 0 {
+                Response::builder().status(StatusCode::OK).body(Body::from("redirected")).unwrap()
+            } else {
+                Response::builder()
+                    .status(StatusCode::FOUND)
+                    .header("Location", format!("/redirect/{}", remaining - 1))
+                    .body(Body::empty())
+                    .unwrap()
+            }
+        }
+        ["delay", ms] => {
+            let millis: u64 = ms.parse().unwrap_or(0);
+            tokio::time::sleep(Duration::from_millis(millis)).await;
+            Response::builder().status(StatusCode::OK).body(Body::from("done")).unwrap()
+        }
+        ["echo"] => {
+            let method = req.method().to_string();
+            let headers: Vec<String> = req
+                .headers()
+                .iter()
+                .map(|(name, value)| format!("{}: {}", name, value.to_str().unwrap_or("")))
+                .collect();
+            let body_bytes = hyper::body::to_bytes(req.into_body()).await.unwrap_or_default();
+            let body_text = String::from_utf8_lossy(&body_bytes).to_string();
+            let reflected = format!("{} {}\n{}\n\n{}", method, path, headers.join("\n"), body_text);
+            Response::builder().status(StatusCode::OK).body(Body::from(reflected)).unwrap()
+        }
+        ["chunked"] => {
+            let (mut sender, body) = Body::channel();
+            tokio::spawn(async move {
+                for chunk in ["first ", "second ", "third"] {
+                    if sender.send_data(bytes::Bytes::from(chunk)).await.is_err() {
+                        break;
+                    }
+                    tokio::time::sleep(Duration::from_millis(10)).await;
+                }
+            });
+            Response::builder().status(StatusCode::OK).body(body).unwrap()
+        }
+        _ => Response::builder().status(StatusCode::NOT_FOUND).body(Body::empty()).unwrap(),
+    };
+
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_status_route_returns_requested_code() {
+        let server = TestServer::start().await;
+        let client = hyper::Client::new();
+        let uri: hyper::Uri = format!("{}/status/204", server.url()).parse().unwrap();
+
+        let response = client.get(uri).await.unwrap();
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+    }
+}
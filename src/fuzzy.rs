@@ -0,0 +1,152 @@
+/// A match of a fuzzy query against a candidate string
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FuzzyMatch {
+    /// Score of the best alignment; higher is a better match
+    pub score: i32,
+    /// Char indices into the candidate where each query character matched,
+    /// in query order
+    pub positions: Vec<usize>,
+}
+
+/// Bonus for a query character matching immediately after the previous
+/// query character's match
+const CONSECUTIVE_BONUS: i32 = 5;
+/// Bonus for a match landing on the first character, right after a
+/// separator (`/ _ - . space`), or on a camelCase uppercase boundary
+const WORD_BOUNDARY_BONUS: i32 = 8;
+/// Penalty per skipped candidate character between two matched positions
+const GAP_PENALTY: i32 = 1;
+
+/// fzf-style subsequence scorer: every character of `query` must appear, in
+/// order and case-insensitively, in `candidate`. Returns `None` if no such
+/// alignment exists. Uses a DP table over (query index, candidate index) to
+/// find the maximum-scoring alignment.
+pub fn score_subsequence(query: &str, candidate: &str) -> Option<FuzzyMatch> {
+    if query.is_empty() {
+        return Some(FuzzyMatch { score: 0, positions: Vec::new() });
+    }
+
+    let query_chars: Vec<char> = query.to_lowercase().chars().collect();
+    let candidate_chars: Vec<char> = candidate.chars().collect();
+    let candidate_lower: Vec<char> = candidate.to_lowercase().chars().collect();
+
+    let query_len = query_chars.len();
+    let candidate_len = candidate_chars.len();
+    if candidate_len < query_len {
+        return None;
+    }
+
+    const UNREACHABLE: i32 = i32::MIN / 2;
+    // score[i][j]: best score aligning query[..=i] with candidate[..=j],
+    // where query character i is matched at candidate position j
+    let mut score = vec![vec![UNREACHABLE; candidate_len]; query_len];
+    // backtrack[i][j]: candidate position that query character i - 1 matched
+    // in the best alignment ending with query character i at position j
+    let mut backtrack = vec![vec![usize::MAX; candidate_len]; query_len];
+
+    for j in 0..candidate_len {
+        if candidate_lower[j] == query_chars[0] {
+            score[0][j] = 1 + word_boundary_bonus(&candidate_chars, j);
+        }
+    }
+
+    for i in 1..query_len {
+        for j in i..candidate_len {
+            if candidate_lower[j] != query_chars[i] {
+                continue;
+            }
+
+            let mut best = UNREACHABLE;
+            let mut best_prev = usize::MAX;
+            for k in (i - 1)..j {
+                if score[i - 1][k] <= UNREACHABLE {
+                    continue;
+                }
+                let gap = (j - k - 1) as i32;
+                let consecutive = if k + 1 == j { CONSECUTIVE_BONUS } else { 0 };
+                let candidate_score =
+                    score[i - 1][k] + 1 + consecutive + word_boundary_bonus(&candidate_chars, j) - gap * GAP_PENALTY;
+                if candidate_score > best {
+                    best = candidate_score;
+                    best_prev = k;
+                }
+            }
+            score[i][j] = best;
+            backtrack[i][j] = best_prev;
+        }
+    }
+
+    let (best_j, best_score) = (0..candidate_len)
+        .filter(|&j| score[query_len - 1][j] > UNREACHABLE)
+        .map(|j| (j, score[query_len - 1][j]))
+        .max_by_key(|&(_, s)| s)?;
+
+    let mut positions = vec![0usize; query_len];
+    let mut j = best_j;
+    for i in (0..query_len).rev() {
+        positions[i] = j;
+        if i == 0 {
+            break;
+        }
+        j = backtrack[i][j];
+    }
+
+    Some(FuzzyMatch { score: best_score, positions })
+}
+
+/// Bonus for a match at candidate position `j`: the first character, the
+/// character right after a separator, or a camelCase uppercase boundary
+fn word_boundary_bonus(candidate_chars: &[char], j: usize) -> i32 {
+    if j == 0 {
+        return WORD_BOUNDARY_BONUS;
+    }
+
+    let previous = candidate_chars[j - 1];
+    if matches!(previous, '/' | '_' | '-' | '.' | ' ') {
+        return WORD_BOUNDARY_BONUS;
+    }
+
+    let current = candidate_chars[j];
+    if current.is_uppercase() && previous.is_lowercase() {
+        return WORD_BOUNDARY_BONUS;
+    }
+
+    0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_no_match_when_not_a_subsequence() {
+        assert!(score_subsequence("xyz", "location").is_none());
+    }
+
+    #[test]
+    fn test_exact_prefix_scores_higher_than_scattered_match() {
+        let prefix = score_subsequence("loc", "location").unwrap();
+        let scattered = score_subsequence("loc", "layer of cats").unwrap();
+        assert!(prefix.score > scattered.score);
+    }
+
+    #[test]
+    fn test_word_boundary_bonus_on_separator() {
+        let matched = score_subsequence("ct", "content-type").unwrap();
+        // 'c' at position 0 and 't' right after the '-' separator both land
+        // on word boundaries
+        assert_eq!(matched.positions, vec![0, 8]);
+    }
+
+    #[test]
+    fn test_case_insensitive_matching() {
+        assert!(score_subsequence("GET", "get").is_some());
+    }
+
+    #[test]
+    fn test_empty_query_matches_everything_with_zero_score() {
+        let matched = score_subsequence("", "anything").unwrap();
+        assert_eq!(matched.score, 0);
+        assert!(matched.positions.is_empty());
+    }
+}
@@ -0,0 +1,98 @@
+//! A curated list of standard HTTP request/response headers, used as
+//! fuzzy-pickable candidates in `ui::components::picker::Picker` so users
+//! can insert a well-known header without typing its exact name.
+
+/// One standard header: its canonical name and a one-line description of
+/// what it's for, shown in the picker's preview pane
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HeaderDefinition {
+    /// Canonical header name, e.g. "Content-Type"
+    pub name: &'static str,
+    /// One-line description of the header's purpose
+    pub description: &'static str,
+}
+
+/// Standard headers, in no particular order; not exhaustive, but broad
+/// enough to cover the headers users reach for most often
+pub const STANDARD_HEADERS: &[HeaderDefinition] = &[
+    HeaderDefinition { name: "Accept", description: "Media types the client is willing to receive" },
+    HeaderDefinition { name: "Accept-Charset", description: "Character sets the client is willing to receive" },
+    HeaderDefinition { name: "Accept-Encoding", description: "Content encodings (e.g. gzip) the client can decode" },
+    HeaderDefinition { name: "Accept-Language", description: "Preferred natural languages for the response" },
+    HeaderDefinition { name: "Access-Control-Allow-Origin", description: "Origins allowed to read a CORS response" },
+    HeaderDefinition { name: "Access-Control-Request-Method", description: "Method a CORS preflight request asks permission for" },
+    HeaderDefinition { name: "Authorization", description: "Credentials for authenticating the client, e.g. \"Bearer <token>\"" },
+    HeaderDefinition { name: "Cache-Control", description: "Caching directives for requests and responses" },
+    HeaderDefinition { name: "Connection", description: "Controls whether the network connection stays open" },
+    HeaderDefinition { name: "Content-Disposition", description: "Suggests a filename or inline/attachment rendering" },
+    HeaderDefinition { name: "Content-Encoding", description: "Encoding applied to the body, e.g. gzip" },
+    HeaderDefinition { name: "Content-Language", description: "Natural language(s) of the intended audience" },
+    HeaderDefinition { name: "Content-Length", description: "Size of the body in bytes" },
+    HeaderDefinition { name: "Content-Range", description: "Byte range of a partial body, for resumable downloads" },
+    HeaderDefinition { name: "Content-Type", description: "Media type of the body, e.g. application/json" },
+    HeaderDefinition { name: "Cookie", description: "Stored cookies sent back to the server" },
+    HeaderDefinition { name: "Date", description: "Date and time the message was originated" },
+    HeaderDefinition { name: "ETag", description: "Opaque identifier for a specific version of a resource" },
+    HeaderDefinition { name: "Expect", description: "Expectations the client requires the server to fulfil, e.g. 100-continue" },
+    HeaderDefinition { name: "Expires", description: "Date/time after which the response is considered stale" },
+    HeaderDefinition { name: "Forwarded", description: "Client and proxy information added by forwarding proxies" },
+    HeaderDefinition { name: "From", description: "Email address of the user making the request" },
+    HeaderDefinition { name: "Host", description: "Domain name and port of the server being requested" },
+    HeaderDefinition { name: "If-Match", description: "Makes the request conditional on matching an ETag" },
+    HeaderDefinition { name: "If-Modified-Since", description: "Makes the request conditional on the resource changing since a date" },
+    HeaderDefinition { name: "If-None-Match", description: "Makes the request conditional on not matching an ETag" },
+    HeaderDefinition { name: "If-Range", description: "Makes a range request conditional on a matching validator" },
+    HeaderDefinition { name: "If-Unmodified-Since", description: "Makes the request conditional on the resource being unchanged since a date" },
+    HeaderDefinition { name: "Last-Modified", description: "Date/time the resource was last changed" },
+    HeaderDefinition { name: "Location", description: "URL to redirect to, or the URL of a newly created resource" },
+    HeaderDefinition { name: "Origin", description: "Scheme, host, and port the request originated from" },
+    HeaderDefinition { name: "Pragma", description: "Legacy HTTP/1.0 cache directive, superseded by Cache-Control" },
+    HeaderDefinition { name: "Proxy-Authorization", description: "Credentials for authenticating with a proxy" },
+    HeaderDefinition { name: "Range", description: "Requests only part of a resource's body" },
+    HeaderDefinition { name: "Referer", description: "Address of the page that linked to the requested resource" },
+    HeaderDefinition { name: "Retry-After", description: "How long to wait before retrying a request" },
+    HeaderDefinition { name: "Server", description: "Software used by the origin server" },
+    HeaderDefinition { name: "Set-Cookie", description: "Sends a cookie from the server to the client" },
+    HeaderDefinition { name: "TE", description: "Transfer encodings the client is willing to accept, trailers included" },
+    HeaderDefinition { name: "Trailer", description: "Headers present in the trailer of a chunked transfer" },
+    HeaderDefinition { name: "Transfer-Encoding", description: "Encoding used to safely transfer the body, e.g. chunked" },
+    HeaderDefinition { name: "User-Agent", description: "Identifies the client application making the request" },
+    HeaderDefinition { name: "Vary", description: "Request headers the server varied its response by" },
+    HeaderDefinition { name: "Via", description: "Proxies and gateways the message has passed through" },
+    HeaderDefinition { name: "WWW-Authenticate", description: "Authentication scheme(s) the server accepts" },
+    HeaderDefinition { name: "X-Content-Type-Options", description: "Disables MIME type sniffing when set to nosniff" },
+    HeaderDefinition { name: "X-Forwarded-For", description: "Originating IP address of a client behind a proxy" },
+    HeaderDefinition { name: "X-Forwarded-Host", description: "Original Host header requested by the client" },
+    HeaderDefinition { name: "X-Forwarded-Proto", description: "Original protocol (http/https) requested by the client" },
+    HeaderDefinition { name: "X-Frame-Options", description: "Controls whether the page may be embedded in a frame" },
+    HeaderDefinition { name: "X-Request-Id", description: "Correlation id for tracing a request across services" },
+];
+
+/// Look up a standard header by exact, case-insensitive name
+pub fn find(name: &str) -> Option<&'static HeaderDefinition> {
+    STANDARD_HEADERS.iter().find(|header| header.name.eq_ignore_ascii_case(name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_is_case_insensitive() {
+        assert_eq!(find("content-type").map(|h| h.name), Some("Content-Type"));
+        assert_eq!(find("CONTENT-TYPE").map(|h| h.name), Some("Content-Type"));
+    }
+
+    #[test]
+    fn test_find_returns_none_for_unknown_header() {
+        assert!(find("X-Not-A-Real-Header").is_none());
+    }
+
+    #[test]
+    fn test_standard_headers_have_no_duplicate_names() {
+        let mut names: Vec<&str> = STANDARD_HEADERS.iter().map(|h| h.name).collect();
+        names.sort_unstable();
+        names.dedup();
+        assert_eq!(names.len(), STANDARD_HEADERS.len());
+    }
+}
@@ -0,0 +1,479 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// A user-triggerable action, decoupled from any specific key chord so the
+/// `handle_*_key` methods can dispatch through a single `apply_action` and
+/// be tested without simulating real key presses
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Action {
+    /// Quit the application
+    QuitApp,
+    /// Move to the next tab
+    NextTab,
+    /// Move to the previous tab
+    PrevTab,
+    /// Move selection up
+    NavigateUp,
+    /// Move selection down
+    NavigateDown,
+    /// Move selection left
+    NavigateLeft,
+    /// Move selection right
+    NavigateRight,
+    /// Execute the current command
+    ExecuteCommand,
+    /// Confirm the current selection (start editing, load a template, add an option)
+    ConfirmSelection,
+    /// Remove the selected option
+    DeleteOption,
+    /// Toggle the selected option's enabled state
+    ToggleOption,
+    /// Toggle the templates panel
+    ToggleTemplates,
+    /// Toggle the environments panel
+    ToggleEnvironments,
+    /// Toggle the history panel
+    ToggleHistory,
+    /// Show the help screen
+    ShowHelp,
+    /// Open the fuzzy command palette
+    OpenCommandPalette,
+    /// Open the fuzzy picker over standard headers, curl flags, and saved
+    /// requests, with a live preview of the highlighted candidate
+    OpenPicker,
+    /// Add a new header or curl flag, opening the inline completion popup
+    /// to fill in its key/flag
+    AddField,
+    /// Reformat the request body in place: pretty-print a minified JSON
+    /// payload, or minify a pretty-printed one
+    ToggleBodyFormat,
+    /// Move the selected template into a different collection folder
+    MoveTemplateFolder,
+    /// Import a pasted curl command into the current command
+    ImportCurlCommand,
+    /// Export the current command to a Hurl (.hurl) file
+    ExportHurl,
+    /// Cycle the output panel between pretty, raw, and JSON views
+    ToggleOutputFormat,
+    /// Collapse disabled headers/query params/options in the selected tab
+    /// into a single "Disabled (n)" row, or expand them back out
+    ToggleFoldDisabled,
+    /// Show secret variables' real values in the generated command preview
+    /// instead of `***`, or mask them again
+    ToggleRevealSecrets,
+    /// Start an incremental fuzzy filter over the templates tree
+    FilterTemplates,
+    /// Cycle the generated command preview between curl, wget, HTTPie,
+    /// PowerShell, and Python requests
+    CycleGenerator,
+    /// Reflow JSON response bodies into indented multi-line form in the
+    /// output panel, or show the server's exact raw bytes again
+    ToggleOutputPretty,
+    /// Scroll the output panel up one line
+    OutputScrollUp,
+    /// Scroll the output panel down one line
+    OutputScrollDown,
+    /// Scroll the output panel up one page
+    OutputPageUp,
+    /// Scroll the output panel down one page
+    OutputPageDown,
+    /// Jump the output panel's scroll to the top
+    OutputScrollTop,
+    /// Jump the output panel's scroll to the bottom
+    OutputScrollBottom,
+    /// Start an incremental search over the output panel's text
+    OpenOutputSearch,
+    /// Jump the output panel's scroll to the next search match
+    OutputSearchNext,
+    /// Jump the output panel's scroll to the previous search match
+    OutputSearchPrev,
+    /// Cycle the output panel between showing headers and body, just the
+    /// headers, or just the body
+    CycleOutputViewMode,
+    /// Show or hide the options grid's side preview pane describing the
+    /// highlighted option in full
+    ToggleOptionPreview,
+}
+
+impl Action {
+    /// Resolve an action by its serialized name, used when loading a user
+    /// keymap file; returns `None` for an unrecognized name so the caller
+    /// can warn and skip it rather than aborting startup
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "QuitApp" => Some(Action::QuitApp),
+            "NextTab" => Some(Action::NextTab),
+            "PrevTab" => Some(Action::PrevTab),
+            "NavigateUp" => Some(Action::NavigateUp),
+            "NavigateDown" => Some(Action::NavigateDown),
+            "NavigateLeft" => Some(Action::NavigateLeft),
+            "NavigateRight" => Some(Action::NavigateRight),
+            "ExecuteCommand" => Some(Action::ExecuteCommand),
+            "ConfirmSelection" => Some(Action::ConfirmSelection),
+            "DeleteOption" => Some(Action::DeleteOption),
+            "ToggleOption" => Some(Action::ToggleOption),
+            "ToggleTemplates" => Some(Action::ToggleTemplates),
+            "ToggleEnvironments" => Some(Action::ToggleEnvironments),
+            "ToggleHistory" => Some(Action::ToggleHistory),
+            "ShowHelp" => Some(Action::ShowHelp),
+            "OpenCommandPalette" => Some(Action::OpenCommandPalette),
+            "OpenPicker" => Some(Action::OpenPicker),
+            "AddField" => Some(Action::AddField),
+            "ToggleBodyFormat" => Some(Action::ToggleBodyFormat),
+            "MoveTemplateFolder" => Some(Action::MoveTemplateFolder),
+            "ImportCurlCommand" => Some(Action::ImportCurlCommand),
+            "ExportHurl" => Some(Action::ExportHurl),
+            "ToggleOutputFormat" => Some(Action::ToggleOutputFormat),
+            "ToggleFoldDisabled" => Some(Action::ToggleFoldDisabled),
+            "ToggleRevealSecrets" => Some(Action::ToggleRevealSecrets),
+            "FilterTemplates" => Some(Action::FilterTemplates),
+            "CycleGenerator" => Some(Action::CycleGenerator),
+            "ToggleOutputPretty" => Some(Action::ToggleOutputPretty),
+            "OutputScrollUp" => Some(Action::OutputScrollUp),
+            "OutputScrollDown" => Some(Action::OutputScrollDown),
+            "OutputPageUp" => Some(Action::OutputPageUp),
+            "OutputPageDown" => Some(Action::OutputPageDown),
+            "OutputScrollTop" => Some(Action::OutputScrollTop),
+            "OutputScrollBottom" => Some(Action::OutputScrollBottom),
+            "OpenOutputSearch" => Some(Action::OpenOutputSearch),
+            "OutputSearchNext" => Some(Action::OutputSearchNext),
+            "OutputSearchPrev" => Some(Action::OutputSearchPrev),
+            "CycleOutputViewMode" => Some(Action::CycleOutputViewMode),
+            "ToggleOptionPreview" => Some(Action::ToggleOptionPreview),
+            _ => None,
+        }
+    }
+}
+
+/// A single key chord: a key code plus the modifiers held while pressing it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Chord {
+    /// The key pressed
+    pub code: KeyCode,
+    /// Modifiers held at the same time
+    pub modifiers: KeyModifiers,
+}
+
+impl Chord {
+    /// Build a chord from a real key event
+    pub fn from_key_event(key_event: &KeyEvent) -> Self {
+        Self {
+            code: key_event.code,
+            modifiers: key_event.modifiers,
+        }
+    }
+
+    /// Parse a chord from strings like `"ctrl-q"`, `"shift-tab"`, `"f5"`,
+    /// `"space"`
+    pub fn parse(input: &str) -> Result<Self, String> {
+        let parts: Vec<&str> = input.split('-').collect();
+        let (modifier_parts, key_part) = parts.split_at(parts.len() - 1);
+        let key_part = key_part[0];
+
+        let mut modifiers = KeyModifiers::NONE;
+        for modifier in modifier_parts {
+            match modifier.to_lowercase().as_str() {
+                "ctrl" | "control" => modifiers.insert(KeyModifiers::CONTROL),
+                "shift" => modifiers.insert(KeyModifiers::SHIFT),
+                "alt" => modifiers.insert(KeyModifiers::ALT),
+                other => return Err(format!("unknown modifier '{}' in chord '{}'", other, input)),
+            }
+        }
+
+        let mut code = match key_part.to_lowercase().as_str() {
+            "tab" => KeyCode::Tab,
+            "backtab" => KeyCode::BackTab,
+            "enter" | "return" => KeyCode::Enter,
+            "esc" | "escape" => KeyCode::Esc,
+            "space" => KeyCode::Char(' '),
+            "up" => KeyCode::Up,
+            "down" => KeyCode::Down,
+            "left" => KeyCode::Left,
+            "right" => KeyCode::Right,
+            "delete" | "del" => KeyCode::Delete,
+            "backspace" => KeyCode::Backspace,
+            "pageup" => KeyCode::PageUp,
+            "pagedown" => KeyCode::PageDown,
+            "home" => KeyCode::Home,
+            "end" => KeyCode::End,
+            other if other.len() > 1 && other.starts_with('f') && other[1..].parse::<u8>().is_ok() => {
+                KeyCode::F(other[1..].parse().expect("validated above"))
+            }
+            other if other.chars().count() == 1 => KeyCode::Char(other.chars().next().expect("non-empty")),
+            other => return Err(format!("unknown key '{}' in chord '{}'", other, input)),
+        };
+
+        // Crossterm reports Shift+Tab as its own `BackTab` code rather than
+        // `Tab` with a shift modifier, so normalize the parsed chord to match
+        if code == KeyCode::Tab && modifiers.contains(KeyModifiers::SHIFT) {
+            code = KeyCode::BackTab;
+            modifiers.remove(KeyModifiers::SHIFT);
+        }
+
+        Ok(Self { code, modifiers })
+    }
+}
+
+/// Maps input chords to `Action`s, resolved from a user config file
+/// (TOML or JSON) layered over a built-in default so a partial user keymap
+/// still works
+pub struct Keymap {
+    bindings: HashMap<Chord, Action>,
+}
+
+impl Keymap {
+    /// The built-in default keymap, used when no user config file is
+    /// present and as the base that a user keymap file is layered over
+    pub fn default_map() -> Self {
+        let defaults: &[(&str, Action)] = &[
+            ("ctrl-q", Action::QuitApp),
+            ("tab", Action::NextTab),
+            ("ctrl-right", Action::NextTab),
+            ("shift-tab", Action::PrevTab),
+            ("ctrl-left", Action::PrevTab),
+            ("up", Action::NavigateUp),
+            ("down", Action::NavigateDown),
+            ("left", Action::NavigateLeft),
+            ("right", Action::NavigateRight),
+            ("f5", Action::ExecuteCommand),
+            ("ctrl-r", Action::ExecuteCommand),
+            ("enter", Action::ConfirmSelection),
+            ("delete", Action::DeleteOption),
+            ("backspace", Action::DeleteOption),
+            ("space", Action::ToggleOption),
+            ("ctrl-t", Action::ToggleTemplates),
+            ("ctrl-e", Action::ToggleEnvironments),
+            ("ctrl-h", Action::ToggleHistory),
+            ("f1", Action::ShowHelp),
+            ("ctrl-p", Action::OpenCommandPalette),
+            ("ctrl-f", Action::OpenPicker),
+            ("ctrl-n", Action::AddField),
+            ("f6", Action::ToggleBodyFormat),
+            ("f3", Action::MoveTemplateFolder),
+            ("ctrl-i", Action::ImportCurlCommand),
+            ("f4", Action::ExportHurl),
+            ("f2", Action::ToggleOutputFormat),
+            ("f7", Action::ToggleFoldDisabled),
+            ("f8", Action::ToggleRevealSecrets),
+            ("/", Action::FilterTemplates),
+            ("f9", Action::CycleGenerator),
+            ("f10", Action::ToggleOutputPretty),
+            ("ctrl-up", Action::OutputScrollUp),
+            ("ctrl-down", Action::OutputScrollDown),
+            ("pageup", Action::OutputPageUp),
+            ("pagedown", Action::OutputPageDown),
+            ("home", Action::OutputScrollTop),
+            ("end", Action::OutputScrollBottom),
+            ("ctrl-o", Action::OpenOutputSearch),
+            ("ctrl-g", Action::OutputSearchNext),
+            ("ctrl-b", Action::OutputSearchPrev),
+            ("f11", Action::CycleOutputViewMode),
+            ("f12", Action::ToggleOptionPreview),
+        ];
+
+        let mut bindings = HashMap::new();
+        for (chord, action) in defaults {
+            let chord = Chord::parse(chord).expect("built-in chord must parse");
+            bindings.insert(chord, *action);
+        }
+
+        Self { bindings }
+    }
+
+    /// Load the user keymap from the conventional config path
+    /// (`~/.config/lazycurl/keymap.toml`), falling back to the built-in
+    /// default when no file is present
+    pub fn load_default() -> Self {
+        match std::env::var("HOME") {
+            Ok(home) => {
+                let path = std::path::Path::new(&home).join(".config/lazycurl/keymap.toml");
+                if path.exists() {
+                    Self::load(&path)
+                } else {
+                    Self::default_map()
+                }
+            }
+            Err(_) => Self::default_map(),
+        }
+    }
+
+    /// Load a keymap file (TOML or JSON, by extension) layered over the
+    /// built-in default. A missing or malformed file falls back to the
+    /// default map; an unknown action name for a given chord is skipped
+    /// with a warning rather than aborting startup.
+    pub fn load(path: &std::path::Path) -> Self {
+        let mut keymap = Self::default_map();
+
+        let contents = match std::fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(_) => return keymap,
+        };
+
+        let is_json = path.extension().and_then(|ext| ext.to_str()) == Some("json");
+        let raw: HashMap<String, String> = if is_json {
+            match serde_json::from_str(&contents) {
+                Ok(raw) => raw,
+                Err(err) => {
+                    eprintln!("Failed to parse keymap file {}: {}", path.display(), err);
+                    return keymap;
+                }
+            }
+        } else {
+            match toml::from_str(&contents) {
+                Ok(raw) => raw,
+                Err(err) => {
+                    eprintln!("Failed to parse keymap file {}: {}", path.display(), err);
+                    return keymap;
+                }
+            }
+        };
+
+        for (chord_str, action_name) in raw {
+            let chord = match Chord::parse(&chord_str) {
+                Ok(chord) => chord,
+                Err(err) => {
+                    eprintln!("Skipping invalid keymap chord '{}': {}", chord_str, err);
+                    continue;
+                }
+            };
+            match Action::from_name(&action_name) {
+                Some(action) => {
+                    keymap.bindings.insert(chord, action);
+                }
+                None => eprintln!(
+                    "Skipping unknown keymap action '{}' for chord '{}'",
+                    action_name, chord_str
+                ),
+            }
+        }
+
+        keymap
+    }
+
+    /// Resolve a key event to an `Action`, if any binding matches
+    pub fn resolve(&self, key_event: &KeyEvent) -> Option<Action> {
+        self.bindings.get(&Chord::from_key_event(key_event)).copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_simple_chords() {
+        assert_eq!(Chord::parse("space").unwrap(), Chord { code: KeyCode::Char(' '), modifiers: KeyModifiers::NONE });
+        assert_eq!(Chord::parse("f5").unwrap(), Chord { code: KeyCode::F(5), modifiers: KeyModifiers::NONE });
+        assert_eq!(
+            Chord::parse("ctrl-q").unwrap(),
+            Chord { code: KeyCode::Char('q'), modifiers: KeyModifiers::CONTROL }
+        );
+    }
+
+    #[test]
+    fn test_parse_shift_tab_normalizes_to_backtab() {
+        assert_eq!(Chord::parse("shift-tab").unwrap(), Chord { code: KeyCode::BackTab, modifiers: KeyModifiers::NONE });
+    }
+
+    #[test]
+    fn test_parse_rejects_unknown_key() {
+        assert!(Chord::parse("ctrl-nonsense").is_err());
+    }
+
+    #[test]
+    fn test_default_map_resolves_quit() {
+        let keymap = Keymap::default_map();
+        let key_event = KeyEvent::new(KeyCode::Char('q'), KeyModifiers::CONTROL);
+        assert_eq!(keymap.resolve(&key_event), Some(Action::QuitApp));
+    }
+
+    #[test]
+    fn test_default_map_resolves_reveal_secrets() {
+        let keymap = Keymap::default_map();
+        let key_event = KeyEvent::new(KeyCode::F(8), KeyModifiers::NONE);
+        assert_eq!(keymap.resolve(&key_event), Some(Action::ToggleRevealSecrets));
+    }
+
+    #[test]
+    fn test_default_map_resolves_filter_templates() {
+        let keymap = Keymap::default_map();
+        let key_event = KeyEvent::new(KeyCode::Char('/'), KeyModifiers::NONE);
+        assert_eq!(keymap.resolve(&key_event), Some(Action::FilterTemplates));
+    }
+
+    #[test]
+    fn test_default_map_resolves_cycle_generator() {
+        let keymap = Keymap::default_map();
+        let key_event = KeyEvent::new(KeyCode::F(9), KeyModifiers::NONE);
+        assert_eq!(keymap.resolve(&key_event), Some(Action::CycleGenerator));
+    }
+
+    #[test]
+    fn test_default_map_resolves_toggle_output_pretty() {
+        let keymap = Keymap::default_map();
+        let key_event = KeyEvent::new(KeyCode::F(10), KeyModifiers::NONE);
+        assert_eq!(keymap.resolve(&key_event), Some(Action::ToggleOutputPretty));
+    }
+
+    #[test]
+    fn test_default_map_resolves_output_page_down() {
+        let keymap = Keymap::default_map();
+        let key_event = KeyEvent::new(KeyCode::PageDown, KeyModifiers::NONE);
+        assert_eq!(keymap.resolve(&key_event), Some(Action::OutputPageDown));
+    }
+
+    #[test]
+    fn test_default_map_resolves_output_scroll_top_and_bottom() {
+        let keymap = Keymap::default_map();
+        assert_eq!(keymap.resolve(&KeyEvent::new(KeyCode::Home, KeyModifiers::NONE)), Some(Action::OutputScrollTop));
+        assert_eq!(keymap.resolve(&KeyEvent::new(KeyCode::End, KeyModifiers::NONE)), Some(Action::OutputScrollBottom));
+    }
+
+    #[test]
+    fn test_default_map_resolves_open_output_search() {
+        let keymap = Keymap::default_map();
+        let key_event = KeyEvent::new(KeyCode::Char('o'), KeyModifiers::CONTROL);
+        assert_eq!(keymap.resolve(&key_event), Some(Action::OpenOutputSearch));
+    }
+
+    #[test]
+    fn test_default_map_resolves_cycle_output_view_mode() {
+        let keymap = Keymap::default_map();
+        let key_event = KeyEvent::new(KeyCode::F(11), KeyModifiers::NONE);
+        assert_eq!(keymap.resolve(&key_event), Some(Action::CycleOutputViewMode));
+    }
+
+    #[test]
+    fn test_default_map_resolves_toggle_option_preview() {
+        let keymap = Keymap::default_map();
+        let key_event = KeyEvent::new(KeyCode::F(12), KeyModifiers::NONE);
+        assert_eq!(keymap.resolve(&key_event), Some(Action::ToggleOptionPreview));
+    }
+
+    #[test]
+    fn test_partial_user_keymap_inherits_defaults() {
+        let dir = std::env::temp_dir().join(format!("lazycurl-keymap-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("keymap.toml");
+        std::fs::write(&path, "h = \"NavigateLeft\"\nl = \"NavigateRight\"\nx = \"NotARealAction\"\n").unwrap();
+
+        let keymap = Keymap::load(&path);
+
+        // Vim-style rebinding took effect
+        assert_eq!(
+            keymap.resolve(&KeyEvent::new(KeyCode::Char('h'), KeyModifiers::NONE)),
+            Some(Action::NavigateLeft)
+        );
+        assert_eq!(keymap.resolve(&KeyEvent::new(KeyCode::Char('l'), KeyModifiers::NONE)), Some(Action::NavigateRight));
+        // Unknown action name for 'x' was skipped rather than aborting the load
+        assert_eq!(keymap.resolve(&KeyEvent::new(KeyCode::Char('x'), KeyModifiers::NONE)), None);
+        // A binding absent from the user file still falls back to the default
+        assert_eq!(
+            keymap.resolve(&KeyEvent::new(KeyCode::Char('q'), KeyModifiers::CONTROL)),
+            Some(Action::QuitApp)
+        );
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
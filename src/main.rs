@@ -1,11 +1,21 @@
 mod app;
+mod cli;
 mod command;
+mod config;
 mod execution;
+mod fuzzy;
+mod headers;
+mod keymap;
+mod mime;
 mod models;
 mod persistence;
+mod syntax;
 mod ui;
 
-use app::App;
+use app::{App, ClickRegions};
+use clap::Parser;
+use cli::{Cli, Commands};
+use config::Config;
 use crossterm::{
     event::{DisableMouseCapture, EnableMouseCapture},
     execute,
@@ -17,8 +27,22 @@ use ui::{Event, EventHandler, Theme};
 use ui::components::{
     CommandBuilder, CommandDisplay, OptionsPanel, OutputPanel, TemplatesTree,
 };
+use ui::theme::{light_theme_env_override, resolve_theme};
 
 fn main() -> Result<(), Box<dyn Error>> {
+    let args = Cli::parse();
+
+    if let Some(Commands::Run { template_name, dry_run, template_file, format }) = args.command {
+        std::process::exit(cli::run_headless(&template_name, dry_run, template_file.as_deref(), &format));
+    }
+
+    // Pick the theme before raw mode changes how the terminal behaves: an
+    // explicit `--theme` flag or env var or config override wins, otherwise
+    // auto-detect the terminal's background via OSC 11
+    let config = Config::load();
+    let theme_override = args.theme.as_deref().and_then(parse_theme_flag).or(light_theme_env_override());
+    let theme = resolve_theme(theme_override, config.light_theme, Duration::from_millis(100));
+
     // Setup terminal
     enable_raw_mode()?;
     let mut stdout = io::stdout();
@@ -28,16 +52,13 @@ fn main() -> Result<(), Box<dyn Error>> {
 
     // Create app state
     let app = App::new();
-    
-    // Create UI theme
-    let theme = Theme::new();
-    
+
     // Create event handler
     let events = EventHandler::new(Duration::from_millis(100));
     
     // Run app
     let res = run_app(&mut terminal, app, theme, events);
-    
+
     // Restore terminal
     disable_raw_mode()?;
     execute!(
@@ -54,6 +75,16 @@ fn main() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+/// Interpret the `--theme` flag's value ("light"/"dark"), ignoring (and
+/// falling through to the next precedence level for) anything else
+fn parse_theme_flag(theme: &str) -> Option<bool> {
+    match theme.to_lowercase().as_str() {
+        "light" => Some(true),
+        "dark" => Some(false),
+        _ => None,
+    }
+}
+
 fn run_app<B: ratatui::backend::Backend>(
     terminal: &mut Terminal<B>,
     mut app: App,
@@ -61,7 +92,12 @@ fn run_app<B: ratatui::backend::Backend>(
     events: EventHandler,
 ) -> io::Result<()> {
     loop {
-        // Draw UI
+        // Draw UI. `pending_click_regions`/`pending_command_preview_cache`
+        // are filled in while drawing (they only need what the draw
+        // closure already computes) and applied to `app` once the closure
+        // -- which borrows `app` immutably for rendering -- has returned.
+        let mut pending_click_regions = ClickRegions::default();
+        let mut pending_command_preview_cache = None;
         terminal.draw(|f| {
             let size = f.size();
             
@@ -91,24 +127,33 @@ fn run_app<B: ratatui::backend::Backend>(
             // Render command builder
             let command_builder = CommandBuilder::new(&app, &theme);
             command_builder.render(f, top_chunks[1]);
-            
+            pending_click_regions = command_builder.compute_click_regions(top_chunks[1]);
+
             // Render command display
             let command_display = CommandDisplay::new(&app, &theme);
-            command_display.render(f, chunks[1]);
+            pending_command_preview_cache = Some(command_display.render(f, chunks[1]));
             
             // Render output
             let output_panel = OutputPanel::new(&app, &theme);
             output_panel.render(f, chunks[2]);
         })?;
-        
+        app.ui_state.click_regions = pending_click_regions;
+        app.ui_state.command_preview_cache = pending_command_preview_cache;
+
         // Handle events
         if let Ok(event) = events.next() {
             match event {
                 Event::Key(key) => {
                     if app.handle_event(&crossterm::event::Event::Key(key)) {
+                        if let Err(err) = app.save() {
+                            eprintln!("Failed to save state: {}", err);
+                        }
                         return Ok(());
                     }
                 }
+                Event::Mouse(mouse) => {
+                    app.handle_event(&crossterm::event::Event::Mouse(mouse));
+                }
                 Event::Tick => {
                     app.update_command();
                 }
@@ -116,6 +161,9 @@ fn run_app<B: ratatui::backend::Backend>(
             }
         } else {
             // Handle RecvError (channel closed)
+            if let Err(err) = app.save() {
+                eprintln!("Failed to save state: {}", err);
+            }
             return Ok(());
         }
     }
@@ -0,0 +1,63 @@
+//! A curated list of common MIME types, used as fuzzy-pickable candidates
+//! when completing a `Content-Type` header's value (see
+//! `App::update_completion_results`), the same way `crate::headers` backs
+//! header-key completion.
+
+/// One MIME type: its canonical name and a one-line description of when
+/// it's used, shown in the completion popup's hint column
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MimeTypeDefinition {
+    /// Canonical media type, e.g. "application/json"
+    pub name: &'static str,
+    /// One-line description of what the type is for
+    pub description: &'static str,
+}
+
+/// Common request/response body MIME types, in no particular order; not
+/// exhaustive, but broad enough to cover the types users reach for most
+/// often when setting a request body's `Content-Type`
+pub const STANDARD_MIME_TYPES: &[MimeTypeDefinition] = &[
+    MimeTypeDefinition { name: "application/json", description: "JSON-encoded body" },
+    MimeTypeDefinition { name: "application/x-www-form-urlencoded", description: "URL-encoded form fields" },
+    MimeTypeDefinition { name: "multipart/form-data", description: "Multipart form fields and file uploads" },
+    MimeTypeDefinition { name: "application/xml", description: "XML-encoded body" },
+    MimeTypeDefinition { name: "text/xml", description: "XML-encoded body, served as text" },
+    MimeTypeDefinition { name: "text/plain", description: "Unstructured plain text" },
+    MimeTypeDefinition { name: "text/html", description: "HTML document" },
+    MimeTypeDefinition { name: "text/css", description: "CSS stylesheet" },
+    MimeTypeDefinition { name: "text/csv", description: "Comma-separated values" },
+    MimeTypeDefinition { name: "application/javascript", description: "JavaScript source" },
+    MimeTypeDefinition { name: "application/octet-stream", description: "Arbitrary binary data" },
+    MimeTypeDefinition { name: "application/pdf", description: "PDF document" },
+    MimeTypeDefinition { name: "application/graphql", description: "GraphQL query or mutation body" },
+    MimeTypeDefinition { name: "application/ld+json", description: "JSON-LD linked data" },
+    MimeTypeDefinition { name: "application/vnd.api+json", description: "JSON:API formatted body" },
+];
+
+/// Look up a standard MIME type by exact, case-insensitive name
+pub fn find(name: &str) -> Option<&'static MimeTypeDefinition> {
+    STANDARD_MIME_TYPES.iter().find(|mime| mime.name.eq_ignore_ascii_case(name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_is_case_insensitive() {
+        assert_eq!(find("APPLICATION/JSON").map(|m| m.name), Some("application/json"));
+    }
+
+    #[test]
+    fn test_find_returns_none_for_unknown_mime_type() {
+        assert!(find("application/not-a-real-type").is_none());
+    }
+
+    #[test]
+    fn test_standard_mime_types_have_no_duplicate_names() {
+        let mut names: Vec<&str> = STANDARD_MIME_TYPES.iter().map(|m| m.name).collect();
+        names.sort_unstable();
+        names.dedup();
+        assert_eq!(names.len(), STANDARD_MIME_TYPES.len());
+    }
+}
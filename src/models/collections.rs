@@ -0,0 +1,277 @@
+use crate::models::template::CommandTemplate;
+use std::collections::HashSet;
+
+/// A folder in the collections tree, built from templates' `/`-separated
+/// `category` paths (e.g. `"APIs/Billing"` nests a `Billing` folder inside
+/// an `APIs` folder). A template with no category lands in the implicit
+/// `Uncategorized` folder.
+#[derive(Debug, Default, Clone)]
+pub struct FolderNode {
+    /// This folder's own name, e.g. `"Billing"`
+    pub name: String,
+    /// Full path from the root, e.g. `"APIs/Billing"`, used as the stable
+    /// key for tracking expansion state
+    pub full_path: String,
+    /// Child folders, sorted by name
+    pub children: Vec<FolderNode>,
+    /// Indices into the template list that live directly in this folder,
+    /// sorted by template name
+    pub template_indices: Vec<usize>,
+}
+
+impl FolderNode {
+    fn child_mut(&mut self, name: &str, full_path: String) -> &mut FolderNode {
+        if let Some(pos) = self.children.iter().position(|child| child.name == name) {
+            &mut self.children[pos]
+        } else {
+            self.children.push(FolderNode {
+                name: name.to_string(),
+                full_path,
+                children: Vec::new(),
+                template_indices: Vec::new(),
+            });
+            self.children.last_mut().expect("just pushed")
+        }
+    }
+}
+
+/// The collections tree: a root folder whose descendants are built from
+/// every template's category path
+#[derive(Debug, Default, Clone)]
+pub struct CollectionTree {
+    pub root: FolderNode,
+}
+
+impl CollectionTree {
+    /// Build the tree from a template list, grouping by each template's
+    /// `/`-separated `category` (defaulting to `"Uncategorized"`)
+    pub fn build(templates: &[CommandTemplate]) -> Self {
+        let mut root = FolderNode::default();
+
+        for (index, template) in templates.iter().enumerate() {
+            let category = template.category.clone().unwrap_or_else(|| "Uncategorized".to_string());
+            let mut node = &mut root;
+            let mut accumulated = String::new();
+            for segment in category.split('/').filter(|s| !s.is_empty()) {
+                accumulated = if accumulated.is_empty() {
+                    segment.to_string()
+                } else {
+                    format!("{}/{}", accumulated, segment)
+                };
+                node = node.child_mut(segment, accumulated.clone());
+            }
+            node.template_indices.push(index);
+        }
+
+        Self::sort(&mut root, templates);
+        Self { root }
+    }
+
+    fn sort(node: &mut FolderNode, templates: &[CommandTemplate]) {
+        node.children.sort_by(|a, b| a.name.cmp(&b.name));
+        node.template_indices.sort_by(|&a, &b| templates[a].name.cmp(&templates[b].name));
+        for child in &mut node.children {
+            Self::sort(child, templates);
+        }
+    }
+
+    /// Flatten the tree into the rows visible given which folder paths are
+    /// currently expanded: collapsed folders hide their contents entirely
+    pub fn visible_rows(&self, expanded: &HashSet<String>) -> Vec<TreeRow> {
+        let mut rows = Vec::new();
+        Self::flatten(&self.root, 0, expanded, &mut rows);
+        rows
+    }
+
+    fn flatten(node: &FolderNode, depth: usize, expanded: &HashSet<String>, rows: &mut Vec<TreeRow>) {
+        for child in &node.children {
+            let is_expanded = expanded.contains(&child.full_path);
+            rows.push(TreeRow::Folder {
+                path: child.full_path.clone(),
+                depth,
+                expanded: is_expanded,
+            });
+            if is_expanded {
+                Self::flatten(child, depth + 1, expanded, rows);
+                for &index in &child.template_indices {
+                    rows.push(TreeRow::Template { index, depth: depth + 1 });
+                }
+            }
+        }
+    }
+
+    /// Flatten the tree into the rows matching `query`, an fzf-style
+    /// subsequence scored against each template's name and each folder's
+    /// name (see [`crate::fuzzy::score_subsequence`]). Unlike `visible_rows`,
+    /// a folder containing a match is always shown expanded regardless of
+    /// `expanded`, so a hit is never hidden behind a collapsed ancestor. An
+    /// empty query falls back to `visible_rows` unfiltered.
+    pub fn filtered_rows(&self, templates: &[CommandTemplate], query: &str, expanded: &HashSet<String>) -> Vec<TreeRow> {
+        if query.is_empty() {
+            return self.visible_rows(expanded);
+        }
+
+        let mut rows = Vec::new();
+        Self::flatten_filtered(&self.root, 0, templates, query, &mut rows);
+        rows
+    }
+
+    /// Returns whether `node` or any of its descendants matched `query`,
+    /// so the caller knows whether to keep `node`'s own folder row
+    fn flatten_filtered(node: &FolderNode, depth: usize, templates: &[CommandTemplate], query: &str, rows: &mut Vec<TreeRow>) -> bool {
+        let mut any_match = false;
+
+        for child in &node.children {
+            let folder_name_matches = crate::fuzzy::score_subsequence(query, &child.name).is_some();
+
+            let mut child_rows = Vec::new();
+            let descendant_matches = Self::flatten_filtered(child, depth + 1, templates, query, &mut child_rows);
+
+            let mut template_rows = Vec::new();
+            let mut template_matches = false;
+            for &index in &child.template_indices {
+                if folder_name_matches || crate::fuzzy::score_subsequence(query, &templates[index].name).is_some() {
+                    template_matches = true;
+                    template_rows.push(TreeRow::Template { index, depth: depth + 1 });
+                }
+            }
+
+            if folder_name_matches || descendant_matches || template_matches {
+                any_match = true;
+                rows.push(TreeRow::Folder { path: child.full_path.clone(), depth, expanded: true });
+                rows.extend(child_rows);
+                rows.extend(template_rows);
+            }
+        }
+
+        any_match
+    }
+}
+
+/// A single row in the flattened, visible collections tree
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TreeRow {
+    /// A folder row; `expanded` reflects whether its contents are shown
+    Folder { path: String, depth: usize, expanded: bool },
+    /// A template row, with its index into the template list
+    Template { index: usize, depth: usize },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::command::CurlCommand;
+
+    fn template(name: &str, category: Option<&str>) -> CommandTemplate {
+        let mut template = CommandTemplate::new(name.to_string(), CurlCommand::default());
+        if let Some(category) = category {
+            template.set_category(category.to_string());
+        }
+        template
+    }
+
+    #[test]
+    fn test_build_nests_slash_separated_categories() {
+        let templates = vec![template("Create Invoice", Some("APIs/Billing"))];
+        let tree = CollectionTree::build(&templates);
+
+        let apis = &tree.root.children[0];
+        assert_eq!(apis.name, "APIs");
+        assert_eq!(apis.full_path, "APIs");
+
+        let billing = &apis.children[0];
+        assert_eq!(billing.name, "Billing");
+        assert_eq!(billing.full_path, "APIs/Billing");
+        assert_eq!(billing.template_indices, vec![0]);
+    }
+
+    #[test]
+    fn test_uncategorized_template_gets_default_folder() {
+        let templates = vec![template("GET Example", None)];
+        let tree = CollectionTree::build(&templates);
+
+        assert_eq!(tree.root.children.len(), 1);
+        assert_eq!(tree.root.children[0].full_path, "Uncategorized");
+    }
+
+    #[test]
+    fn test_visible_rows_hides_collapsed_folder_contents() {
+        let templates = vec![template("Create Invoice", Some("APIs/Billing"))];
+        let tree = CollectionTree::build(&templates);
+
+        let collapsed = HashSet::new();
+        assert_eq!(tree.visible_rows(&collapsed), vec![TreeRow::Folder { path: "APIs".to_string(), depth: 0, expanded: false }]);
+
+        let mut expanded = HashSet::new();
+        expanded.insert("APIs".to_string());
+        expanded.insert("APIs/Billing".to_string());
+        let rows = tree.visible_rows(&expanded);
+        assert_eq!(
+            rows,
+            vec![
+                TreeRow::Folder { path: "APIs".to_string(), depth: 0, expanded: true },
+                TreeRow::Folder { path: "APIs/Billing".to_string(), depth: 1, expanded: true },
+                TreeRow::Template { index: 0, depth: 2 },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_filtered_rows_falls_back_to_visible_rows_for_empty_query() {
+        let templates = vec![template("Create Invoice", Some("APIs/Billing"))];
+        let tree = CollectionTree::build(&templates);
+        let expanded = HashSet::new();
+
+        assert_eq!(tree.filtered_rows(&templates, "", &expanded), tree.visible_rows(&expanded));
+    }
+
+    #[test]
+    fn test_filtered_rows_force_expands_collapsed_ancestor_of_a_match() {
+        let templates = vec![
+            template("Create Invoice", Some("APIs/Billing")),
+            template("List Widgets", Some("APIs/Widgets")),
+        ];
+        let tree = CollectionTree::build(&templates);
+        let collapsed = HashSet::new();
+
+        let rows = tree.filtered_rows(&templates, "invoice", &collapsed);
+        assert_eq!(
+            rows,
+            vec![
+                TreeRow::Folder { path: "APIs".to_string(), depth: 0, expanded: true },
+                TreeRow::Folder { path: "APIs/Billing".to_string(), depth: 1, expanded: true },
+                TreeRow::Template { index: 0, depth: 2 },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_filtered_rows_matching_folder_name_keeps_its_direct_templates() {
+        let templates = vec![template("GET Example", Some("Billing"))];
+        let tree = CollectionTree::build(&templates);
+        let collapsed = HashSet::new();
+
+        let rows = tree.filtered_rows(&templates, "billing", &collapsed);
+        assert_eq!(
+            rows,
+            vec![
+                TreeRow::Folder { path: "Billing".to_string(), depth: 0, expanded: true },
+                TreeRow::Template { index: 0, depth: 1 },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_filtered_rows_excludes_non_matching_branches() {
+        let templates = vec![
+            template("Create Invoice", Some("APIs/Billing")),
+            template("List Widgets", Some("APIs/Widgets")),
+        ];
+        let tree = CollectionTree::build(&templates);
+        let collapsed = HashSet::new();
+
+        let rows = tree.filtered_rows(&templates, "widgets", &collapsed);
+        assert!(rows.iter().all(|row| !matches!(row, TreeRow::Folder { path, .. } if path == "APIs/Billing")));
+        assert!(rows.contains(&TreeRow::Template { index: 1, depth: 2 }));
+    }
+}
@@ -1,3 +1,4 @@
+use crate::models::environment::Environment;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
@@ -64,6 +65,22 @@ pub struct QueryParam {
     pub enabled: bool,
 }
 
+/// The kind of multipart field a `FormDataItem` represents
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum FormFieldKind {
+    /// A plain text field, sent as `-F key=value`
+    Text(String),
+    /// A file field, sent as `-F "key=@path;type=content_type;filename=filename"`
+    File {
+        /// Path to the file on disk
+        path: PathBuf,
+        /// Explicit `Content-Type` override, if any
+        content_type: Option<String>,
+        /// Filename to report to the server, if different from `path`'s own
+        filename: Option<String>,
+    },
+}
+
 /// Form data item struct
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FormDataItem {
@@ -71,8 +88,8 @@ pub struct FormDataItem {
     pub id: String,
     /// Item key
     pub key: String,
-    /// Item value
-    pub value: String,
+    /// Item kind: a text value or a file part
+    pub kind: FormFieldKind,
     /// Whether the item is enabled
     pub enabled: bool,
 }
@@ -109,6 +126,47 @@ pub struct CurlOption {
     pub enabled: bool,
 }
 
+/// Authentication scheme for a `CurlCommand`, modeled after the
+/// registry-auth pattern used in docker clients: one structured value
+/// instead of a hand-written `Authorization` header or raw `-u` option,
+/// so the TUI can offer dedicated fields per scheme and command
+/// generation can lower it to the right curl form.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Auth {
+    /// No authentication
+    None,
+    /// HTTP Basic auth, lowered to `-u username:password`
+    Basic { username: String, password: String },
+    /// Bearer token, lowered to `-H "Authorization: Bearer <token>"`
+    Bearer(String),
+    /// An API key sent as a header or query parameter
+    ApiKey {
+        /// Whether `name`/`value` go in a header or a query parameter
+        location: ApiKeyLocation,
+        /// Header name or query parameter name
+        name: String,
+        /// Key value
+        value: String,
+    },
+    /// An OAuth2 access token, lowered the same way as `Bearer`; the
+    /// refresh URL is metadata for a future token-refresh flow and isn't
+    /// emitted on the command line
+    OAuth2 { token: String, refresh_url: Option<String> },
+}
+
+impl Default for Auth {
+    fn default() -> Self {
+        Self::None
+    }
+}
+
+/// Where an `Auth::ApiKey` is placed on the outgoing request
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ApiKeyLocation {
+    Header,
+    QueryParam,
+}
+
 /// Curl command struct
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CurlCommand {
@@ -130,6 +188,13 @@ pub struct CurlCommand {
     pub body: Option<RequestBody>,
     /// Curl options
     pub options: Vec<CurlOption>,
+    /// Authentication scheme, lowered to the right curl form on generation
+    #[serde(default)]
+    pub auth: Auth,
+    /// Tokens of a pasted command that have no structured representation
+    /// (an unrecognized `--flag=value`, a malformed `-H`/`-F` value, a
+    /// surplus bare argument), kept verbatim and emitted again by `CommandBuilder`
+    pub extra_args: Vec<String>,
     /// Creation timestamp
     pub created_at: DateTime<Utc>,
     /// Last update timestamp
@@ -148,6 +213,8 @@ impl Default for CurlCommand {
             query_params: Vec::new(),
             body: None,
             options: Vec::new(),
+            auth: Auth::None,
+            extra_args: Vec::new(),
             created_at: Utc::now(),
             updated_at: Utc::now(),
         }
@@ -163,6 +230,31 @@ impl CurlCommand {
         }
     }
 
+    /// Import a pasted `curl ...` invocation (e.g. copied from browser
+    /// DevTools) into a structured command, populating method, URL,
+    /// headers, and body rather than just tokenizing it
+    pub fn from_curl(input: &str) -> Self {
+        crate::command::parser::parse(input)
+    }
+
+    /// Inverse of [`crate::command::builder::CommandBuilder::build`]:
+    /// parse a raw `curl ...` invocation into a structured command.
+    /// Unlike [`Self::from_curl`], this rejects blank input instead of
+    /// silently returning a default command.
+    pub fn parse(input: &str) -> Result<Self, String> {
+        if input.trim().is_empty() {
+            return Err("cannot parse an empty curl command".to_string());
+        }
+        Ok(crate::command::parser::parse(input))
+    }
+
+    /// Serialize this command into a single-line, shell-quoted curl
+    /// invocation (no environment substitution) that round-trips safely
+    /// back through `App::parse_command_args`
+    pub fn to_shell_command(&self) -> String {
+        crate::command::builder::CommandBuilder::to_shell_command(self)
+    }
+
     /// Add a header
     pub fn add_header(&mut self, key: String, value: String) -> &mut Self {
         self.headers.push(Header {
@@ -207,4 +299,144 @@ impl CurlCommand {
         self.body = Some(body);
         self
     }
+
+    /// Set the authentication scheme
+    pub fn set_auth(&mut self, auth: Auth) -> &mut Self {
+        self.auth = auth;
+        self
+    }
+
+    /// Build this command's URL with all *enabled* `query_params` appended
+    /// and percent-encoded. This is the read side of keeping `url` and
+    /// `query_params` in sync; see `sync_query_from_url` for the inverse.
+    /// Both the key and the value are encoded through
+    /// `url::form_urlencoded::Serializer`, so a key containing a space,
+    /// `&`, `=`, or `#` round-trips cleanly instead of corrupting the
+    /// query string, and spaces come out as `+` to match the `+`-aware
+    /// decoding `sync_query_from_url` does on the way back in.
+    pub fn effective_url(&self) -> String {
+        let enabled: Vec<(&str, &str)> = self
+            .query_params
+            .iter()
+            .filter(|p| p.enabled)
+            .map(|p| (p.key.as_str(), p.value.as_str()))
+            .collect();
+
+        if enabled.is_empty() {
+            return self.url.clone();
+        }
+
+        let mut serializer = url::form_urlencoded::Serializer::new(String::new());
+        for (key, value) in &enabled {
+            serializer.append_pair(key, value);
+        }
+        let query_string = serializer.finish();
+
+        if self.url.contains('?') {
+            format!("{}&{}", self.url, query_string)
+        } else {
+            format!("{}?{}", self.url, query_string)
+        }
+    }
+
+    /// Parse a `?key=value&...` query string embedded in `url` into
+    /// `query_params`, replacing the existing list, and strip it from
+    /// `url`. Prevents the double-source-of-truth problem where a user
+    /// edits the raw URL directly and the structured params silently
+    /// diverge from it.
+    pub fn sync_query_from_url(&mut self) {
+        let Some((base, query)) = self.url.split_once('?') else {
+            return;
+        };
+        let base = base.to_string();
+
+        let mut params = Vec::new();
+        for pair in query.split('&') {
+            if pair.is_empty() {
+                continue;
+            }
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            params.push(QueryParam {
+                id: uuid::Uuid::new_v4().to_string(),
+                key: decode_query_component(key),
+                value: decode_query_component(value),
+                enabled: true,
+            });
+        }
+
+        self.url = base;
+        self.query_params = params;
+    }
+
+    /// Scan every field this command interpolates variables into (URL,
+    /// query params, headers, body, option values, auth) and collect the `{{KEY}}` keys
+    /// `environment` has no value for, deduplicated in the order
+    /// encountered. The raw fields themselves are left untouched; this is
+    /// purely diagnostic, so the UI can flag a request that references a
+    /// variable the active environment doesn't define.
+    pub fn unresolved_variables(&self, environment: &Environment) -> Vec<String> {
+        let mut unresolved = Vec::new();
+
+        collect_unresolved(&self.url, environment, &mut unresolved);
+        for header in &self.headers {
+            collect_unresolved(&header.key, environment, &mut unresolved);
+            collect_unresolved(&header.value, environment, &mut unresolved);
+        }
+        for param in &self.query_params {
+            collect_unresolved(&param.key, environment, &mut unresolved);
+            collect_unresolved(&param.value, environment, &mut unresolved);
+        }
+        if let Some(body) = &self.body {
+            match body {
+                RequestBody::Raw(content) => collect_unresolved(content, environment, &mut unresolved),
+                RequestBody::FormData(items) => {
+                    for item in items {
+                        collect_unresolved(&item.key, environment, &mut unresolved);
+                        if let FormFieldKind::Text(value) = &item.kind {
+                            collect_unresolved(value, environment, &mut unresolved);
+                        }
+                    }
+                }
+                RequestBody::Binary(_) | RequestBody::None => {}
+            }
+        }
+        for option in &self.options {
+            if let Some(value) = &option.value {
+                collect_unresolved(value, environment, &mut unresolved);
+            }
+        }
+        match &self.auth {
+            Auth::Basic { username, password } => {
+                collect_unresolved(username, environment, &mut unresolved);
+                collect_unresolved(password, environment, &mut unresolved);
+            }
+            Auth::Bearer(token) => collect_unresolved(token, environment, &mut unresolved),
+            Auth::ApiKey { name, value, .. } => {
+                collect_unresolved(name, environment, &mut unresolved);
+                collect_unresolved(value, environment, &mut unresolved);
+            }
+            Auth::OAuth2 { token, .. } => collect_unresolved(token, environment, &mut unresolved),
+            Auth::None => {}
+        }
+
+        unresolved
+    }
+}
+
+/// Percent- and `+`-decode a single query-string key or value
+fn decode_query_component(raw: &str) -> String {
+    let with_spaces = raw.replace('+', " ");
+    urlencoding::decode(&with_spaces).map(|s| s.into_owned()).unwrap_or(with_spaces)
+}
+
+/// Interpolate `input` against `environment`, appending any unresolved
+/// keys to `unresolved` that aren't already present
+fn collect_unresolved(input: &str, environment: &Environment, unresolved: &mut Vec<String>) {
+    if let Err(keys) = environment.interpolate(input) {
+        for key in keys {
+            if !unresolved.contains(&key) {
+                unresolved.push(key);
+            }
+        }
+    }
 }
\ No newline at end of file
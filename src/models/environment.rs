@@ -1,5 +1,66 @@
+use chacha20poly1305::aead::{Aead, KeyInit};
+use chacha20poly1305::{Key, XChaCha20Poly1305, XNonce};
 use chrono::{DateTime, Utc};
+use rand::rngs::OsRng;
+use rand::RngCore;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Name reserved for the shared environment whose variables are inherited
+/// by every other environment, beneath any explicit `parent`
+pub const GLOBAL_ENVIRONMENT_NAME: &str = "global";
+
+/// Which layer of the active → parent → global chain supplied a variable
+/// resolved via `Environment::resolve_variable`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VariableSource {
+    /// Defined directly on the environment that was asked about
+    Active,
+    /// Inherited from this environment's explicit `parent`
+    Parent(String),
+    /// Inherited from the shared [`GLOBAL_ENVIRONMENT_NAME`] environment
+    Global,
+}
+
+/// How an `EnvironmentVariable`'s value is held, both in memory and on
+/// disk. Ordinary variables are kept as `Plain` text; `is_secret`
+/// variables are kept as XChaCha20-Poly1305 ciphertext (`Sealed`) and are
+/// only ever decrypted transiently, so the plaintext never lives in a
+/// long-lived field or in the serialized JSON. When no key is available,
+/// `seal` stores a secret as `Plain` instead.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "format", rename_all = "lowercase")]
+enum SecretValue {
+    Plain { value: String },
+    Sealed { nonce: String, ciphertext: String },
+}
+
+impl SecretValue {
+    fn plain(value: String) -> Self {
+        SecretValue::Plain { value }
+    }
+
+    /// Encrypt `value` under this machine's key, falling back to storing
+    /// it as plaintext if no key is available (e.g. `HOME` isn't set) so a
+    /// missing key can never silently drop data
+    fn seal(value: &str) -> Self {
+        encryption_key()
+            .and_then(|key| encrypt_value(value, &key))
+            .map(|(nonce, ciphertext)| SecretValue::Sealed { nonce, ciphertext })
+            .unwrap_or_else(|| SecretValue::Plain { value: value.to_string() })
+    }
+
+    /// Decrypt (or simply return) the plaintext value
+    fn reveal(&self) -> Option<String> {
+        match self {
+            SecretValue::Plain { value } => Some(value.clone()),
+            SecretValue::Sealed { nonce, ciphertext } => {
+                let key = encryption_key()?;
+                decrypt_value(nonce, ciphertext, &key)
+            }
+        }
+    }
+}
 
 /// Environment variable struct
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -8,8 +69,11 @@ pub struct EnvironmentVariable {
     pub id: String,
     /// Variable key
     pub key: String,
-    /// Variable value
-    pub value: String,
+    /// The value, plaintext for ordinary variables or ciphertext for
+    /// `is_secret` ones. Not `pub`: go through `Environment::get_variable`,
+    /// `Environment::reveal`, or `Environment::masked_variable` instead of
+    /// reading it directly, so secret handling can't be bypassed.
+    value: SecretValue,
     /// Whether the variable is a secret
     pub is_secret: bool,
 }
@@ -21,6 +85,10 @@ pub struct Environment {
     pub id: String,
     /// Environment name
     pub name: String,
+    /// Name of another environment to inherit undefined variables from,
+    /// beneath the shared [`GLOBAL_ENVIRONMENT_NAME`] environment
+    #[serde(default)]
+    pub parent: Option<String>,
     /// Environment variables
     pub variables: Vec<EnvironmentVariable>,
     /// Creation timestamp
@@ -35,6 +103,7 @@ impl Environment {
         Self {
             id: uuid::Uuid::new_v4().to_string(),
             name,
+            parent: None,
             variables: Vec::new(),
             created_at: Utc::now(),
             updated_at: Utc::now(),
@@ -43,27 +112,55 @@ impl Environment {
 
     /// Add a variable
     pub fn add_variable(&mut self, key: String, value: String, is_secret: bool) -> &mut Self {
+        let stored = if is_secret { SecretValue::seal(&value) } else { SecretValue::plain(value) };
         self.variables.push(EnvironmentVariable {
             id: uuid::Uuid::new_v4().to_string(),
             key,
-            value,
+            value: stored,
             is_secret,
         });
         self
     }
 
-    /// Get a variable value by key
-    pub fn get_variable(&self, key: &str) -> Option<&str> {
-        self.variables
-            .iter()
-            .find(|v| v.key == key)
-            .map(|v| v.value.as_str())
+    /// Get a variable's real value by key, decrypting it if it's a secret.
+    /// Intended for internal use (building and interpolating commands);
+    /// prefer `masked_variable` for anything rendered to the user.
+    pub fn get_variable(&self, key: &str) -> Option<String> {
+        self.variables.iter().find(|v| v.key == key).and_then(|v| v.value.reveal())
+    }
+
+    /// Explicitly decrypt and return `key`'s real value, regardless of
+    /// `is_secret`. This is the entry point for a user-initiated "reveal"
+    /// action; everywhere else should default to `masked_variable`.
+    pub fn reveal(&self, key: &str) -> Option<String> {
+        self.get_variable(key)
+    }
+
+    /// Whether `key` is a known variable flagged `is_secret`. Used by
+    /// `CommandBuilder::build_masked` to decide which substituted values to
+    /// mask in the generated command preview.
+    pub fn is_secret(&self, key: &str) -> bool {
+        self.variables.iter().any(|v| v.key == key && v.is_secret)
+    }
+
+    /// This variable's value for display: the real value for ordinary
+    /// variables, or `"••••"` for `is_secret` ones. The status bar and any
+    /// other variable listing should use this instead of `get_variable`
+    /// unless the user has explicitly asked to reveal the value.
+    pub fn masked_variable(&self, key: &str) -> Option<String> {
+        self.variables.iter().find(|v| v.key == key).map(|v| {
+            if v.is_secret {
+                "••••".to_string()
+            } else {
+                v.value.reveal().unwrap_or_default()
+            }
+        })
     }
 
     /// Update a variable value
     pub fn update_variable(&mut self, key: &str, value: String) -> bool {
         if let Some(var) = self.variables.iter_mut().find(|v| v.key == key) {
-            var.value = value;
+            var.value = if var.is_secret { SecretValue::seal(&value) } else { SecretValue::plain(value) };
             self.updated_at = Utc::now();
             true
         } else {
@@ -78,4 +175,313 @@ impl Environment {
         self.updated_at = Utc::now();
         self.variables.len() < initial_len
     }
+
+    /// Resolve `key` by walking this environment's own variables, then its
+    /// explicit `parent` (if any), then the shared
+    /// [`GLOBAL_ENVIRONMENT_NAME`] environment, reporting which layer
+    /// supplied the value. `registry` is every known environment, keyed by
+    /// name, as persisted in `PersistedState::environments`.
+    pub fn resolve_variable(
+        &self,
+        key: &str,
+        registry: &HashMap<String, Environment>,
+    ) -> Option<(String, VariableSource)> {
+        if let Some(value) = self.get_variable(key) {
+            return Some((value, VariableSource::Active));
+        }
+
+        if let Some(parent_name) = &self.parent {
+            if let Some(value) = registry.get(parent_name).and_then(|parent| parent.get_variable(key)) {
+                return Some((value, VariableSource::Parent(parent_name.clone())));
+            }
+        }
+
+        if self.name != GLOBAL_ENVIRONMENT_NAME {
+            if let Some(value) = registry.get(GLOBAL_ENVIRONMENT_NAME).and_then(|global| global.get_variable(key)) {
+                return Some((value, VariableSource::Global));
+            }
+        }
+
+        None
+    }
+
+    /// Resolve every `{{KEY}}` placeholder in `input` against this
+    /// environment's variables. A literal `{{` can be produced by escaping
+    /// it as `\{{`. Substitution is single-pass: a value's own text is
+    /// never re-scanned for further placeholders, so a variable whose
+    /// value happens to contain `{{...}}` is inserted as-is rather than
+    /// triggering another round of expansion. If any placeholder's key has
+    /// no matching variable, interpolation fails with the list of
+    /// unresolved keys (in the order encountered) so the caller can
+    /// surface them to the user instead of silently running a broken
+    /// request.
+    pub fn interpolate(&self, input: &str) -> Result<String, Vec<String>> {
+        let chars: Vec<char> = input.chars().collect();
+        let mut result = String::with_capacity(input.len());
+        let mut unresolved = Vec::new();
+        let mut i = 0;
+
+        while i < chars.len() {
+            if chars[i] == '\\' && is_brace_pair(&chars, i + 1) {
+                result.push_str("{{");
+                i += 3;
+            } else if is_brace_pair(&chars, i) {
+                if let Some(end) = find_closing_braces(&chars, i + 2) {
+                    let key: String = chars[i + 2..end].iter().collect();
+                    let key = key.trim();
+                    match self.get_variable(key) {
+                        Some(value) => result.push_str(&value),
+                        None => {
+                            if !unresolved.iter().any(|k| k == key) {
+                                unresolved.push(key.to_string());
+                            }
+                        }
+                    }
+                    i = end + 2;
+                } else {
+                    result.push(chars[i]);
+                    i += 1;
+                }
+            } else {
+                result.push(chars[i]);
+                i += 1;
+            }
+        }
+
+        if unresolved.is_empty() {
+            Ok(result)
+        } else {
+            Err(unresolved)
+        }
+    }
+}
+
+/// Path to this machine's secret-encryption key, kept alongside (but
+/// separate from) the persisted state it protects
+fn key_path() -> Option<std::path::PathBuf> {
+    let home = std::env::var("HOME").ok()?;
+    Some(std::path::PathBuf::from(home).join(".config").join("lazycurl").join("secret.key"))
+}
+
+/// Load this machine's secret-encryption key, generating and persisting a
+/// fresh random one on first use. A platform keyring entry would be a
+/// drop-in replacement for this file-backed key, should one become
+/// available; for now it's a plain random key living outside `state.json`
+/// so the ciphertext it protects never travels with its own key.
+fn encryption_key() -> Option<[u8; 32]> {
+    let path = key_path()?;
+
+    if let Ok(contents) = std::fs::read_to_string(&path) {
+        if let Some(bytes) = decode_hex(contents.trim()) {
+            if bytes.len() == 32 {
+                let mut key = [0u8; 32];
+                key.copy_from_slice(&bytes);
+                return Some(key);
+            }
+        }
+    }
+
+    let mut key = [0u8; 32];
+    OsRng.fill_bytes(&mut key);
+    if let Some(dir) = path.parent() {
+        let _ = std::fs::create_dir_all(dir);
+    }
+    let _ = std::fs::write(&path, encode_hex(&key));
+    Some(key)
+}
+
+/// Encrypt `plain` under `key` with a fresh random nonce, returning
+/// `(nonce, ciphertext)` hex-encoded for storage in JSON
+fn encrypt_value(plain: &str, key: &[u8; 32]) -> Option<(String, String)> {
+    let cipher = XChaCha20Poly1305::new(Key::from_slice(key));
+    let mut nonce_bytes = [0u8; 24];
+    OsRng.fill_bytes(&mut nonce_bytes);
+    let nonce = XNonce::from_slice(&nonce_bytes);
+    let ciphertext = cipher.encrypt(nonce, plain.as_bytes()).ok()?;
+    Some((encode_hex(&nonce_bytes), encode_hex(&ciphertext)))
+}
+
+/// Decrypt a `(nonce, ciphertext)` pair produced by `encrypt_value`
+fn decrypt_value(nonce_hex: &str, ciphertext_hex: &str, key: &[u8; 32]) -> Option<String> {
+    let nonce_bytes = decode_hex(nonce_hex)?;
+    let ciphertext = decode_hex(ciphertext_hex)?;
+    let cipher = XChaCha20Poly1305::new(Key::from_slice(key));
+    let nonce = XNonce::from_slice(&nonce_bytes);
+    let plaintext = cipher.decrypt(nonce, ciphertext.as_slice()).ok()?;
+    String::from_utf8(plaintext).ok()
+}
+
+/// Encode bytes as lowercase hex
+fn encode_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Decode a lowercase hex string back into bytes
+fn decode_hex(s: &str) -> Option<Vec<u8>> {
+    if s.len() % 2 != 0 {
+        return None;
+    }
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).ok())
+        .collect()
+}
+
+/// Whether `chars[i]` and `chars[i + 1]` are both `{`
+fn is_brace_pair(chars: &[char], i: usize) -> bool {
+    chars.get(i) == Some(&'{') && chars.get(i + 1) == Some(&'{')
+}
+
+/// Find the index of the next `}}` at or after `from`
+fn find_closing_braces(chars: &[char], from: usize) -> Option<usize> {
+    let mut i = from;
+    while i + 1 < chars.len() {
+        if chars[i] == '}' && chars[i + 1] == '}' {
+            return Some(i);
+        }
+        i += 1;
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn env_with(vars: &[(&str, &str)]) -> Environment {
+        let mut env = Environment::new("test".to_string());
+        for (key, value) in vars {
+            env.add_variable(key.to_string(), value.to_string(), false);
+        }
+        env
+    }
+
+    #[test]
+    fn test_interpolate_substitutes_known_variable() {
+        let env = env_with(&[("host", "example.com")]);
+        assert_eq!(env.interpolate("https://{{host}}/api"), Ok("https://example.com/api".to_string()));
+    }
+
+    #[test]
+    fn test_interpolate_reports_unresolved_keys() {
+        let env = env_with(&[("host", "example.com")]);
+        let err = env.interpolate("{{host}}/{{missing}}").unwrap_err();
+        assert_eq!(err, vec!["missing".to_string()]);
+    }
+
+    #[test]
+    fn test_interpolate_supports_escaped_braces() {
+        let env = Environment::new("test".to_string());
+        assert_eq!(env.interpolate(r"literal \{{not a var}}"), Ok("literal {{not a var}}".to_string()));
+    }
+
+    #[test]
+    fn test_interpolate_does_not_rescan_substituted_values() {
+        let env = env_with(&[("outer", "{{inner}}"), ("inner", "resolved")]);
+        // `outer` expands to the literal text "{{inner}}", which is not
+        // scanned again for further placeholders
+        assert_eq!(env.interpolate("{{outer}}"), Ok("{{inner}}".to_string()));
+    }
+
+    #[test]
+    fn test_interpolate_dedups_repeated_unresolved_keys() {
+        let env = Environment::new("test".to_string());
+        let err = env.interpolate("{{missing}}-{{missing}}").unwrap_err();
+        assert_eq!(err, vec!["missing".to_string()]);
+    }
+
+    #[test]
+    fn test_secret_variable_is_not_stored_as_plaintext() {
+        let mut env = Environment::new("test".to_string());
+        env.add_variable("api_key".to_string(), "sk-super-secret".to_string(), true);
+
+        let serialized = serde_json::to_string(&env).unwrap();
+        assert!(!serialized.contains("sk-super-secret"));
+    }
+
+    #[test]
+    fn test_secret_variable_round_trips_through_reveal() {
+        let mut env = Environment::new("test".to_string());
+        env.add_variable("api_key".to_string(), "sk-super-secret".to_string(), true);
+
+        assert_eq!(env.reveal("api_key"), Some("sk-super-secret".to_string()));
+        assert_eq!(env.get_variable("api_key"), Some("sk-super-secret".to_string()));
+    }
+
+    #[test]
+    fn test_secret_variable_is_masked_by_default() {
+        let mut env = Environment::new("test".to_string());
+        env.add_variable("api_key".to_string(), "sk-super-secret".to_string(), true);
+
+        assert_eq!(env.masked_variable("api_key"), Some("••••".to_string()));
+    }
+
+    #[test]
+    fn test_is_secret_true_for_secret_variable_false_otherwise() {
+        let mut env = Environment::new("test".to_string());
+        env.add_variable("api_key".to_string(), "sk-super-secret".to_string(), true);
+        env.add_variable("host".to_string(), "example.com".to_string(), false);
+
+        assert!(env.is_secret("api_key"));
+        assert!(!env.is_secret("host"));
+        assert!(!env.is_secret("missing"));
+    }
+
+    #[test]
+    fn test_non_secret_variable_is_not_masked() {
+        let mut env = Environment::new("test".to_string());
+        env.add_variable("host".to_string(), "example.com".to_string(), false);
+
+        assert_eq!(env.masked_variable("host"), Some("example.com".to_string()));
+    }
+
+    #[test]
+    fn test_resolve_variable_prefers_active_environment() {
+        let mut registry = HashMap::new();
+        let mut global = Environment::new(GLOBAL_ENVIRONMENT_NAME.to_string());
+        global.add_variable("host".to_string(), "global.example.com".to_string(), false);
+        registry.insert(GLOBAL_ENVIRONMENT_NAME.to_string(), global);
+
+        let mut active = Environment::new("prod".to_string());
+        active.add_variable("host".to_string(), "prod.example.com".to_string(), false);
+
+        assert_eq!(
+            active.resolve_variable("host", &registry),
+            Some(("prod.example.com".to_string(), VariableSource::Active))
+        );
+    }
+
+    #[test]
+    fn test_resolve_variable_falls_back_to_parent_then_global() {
+        let mut registry = HashMap::new();
+        let mut global = Environment::new(GLOBAL_ENVIRONMENT_NAME.to_string());
+        global.add_variable("region".to_string(), "us-east-1".to_string(), false);
+        registry.insert(GLOBAL_ENVIRONMENT_NAME.to_string(), global);
+
+        let mut base = Environment::new("base".to_string());
+        base.add_variable("host".to_string(), "base.example.com".to_string(), false);
+        registry.insert("base".to_string(), base);
+
+        let mut active = Environment::new("prod".to_string());
+        active.parent = Some("base".to_string());
+        registry.insert("prod".to_string(), active.clone());
+
+        assert_eq!(
+            active.resolve_variable("host", &registry),
+            Some(("base.example.com".to_string(), VariableSource::Parent("base".to_string())))
+        );
+        assert_eq!(active.resolve_variable("region", &registry), Some(("us-east-1".to_string(), VariableSource::Global)));
+        assert_eq!(active.resolve_variable("missing", &registry), None);
+    }
+
+    #[test]
+    fn test_update_variable_preserves_secret_sealing() {
+        let mut env = Environment::new("test".to_string());
+        env.add_variable("api_key".to_string(), "old-secret".to_string(), true);
+        env.update_variable("api_key", "new-secret".to_string());
+
+        let serialized = serde_json::to_string(&env).unwrap();
+        assert!(!serialized.contains("new-secret"));
+        assert_eq!(env.reveal("api_key"), Some("new-secret".to_string()));
+    }
 }
\ No newline at end of file
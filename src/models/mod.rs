@@ -0,0 +1,4 @@
+pub mod collections;
+pub mod command;
+pub mod environment;
+pub mod template;
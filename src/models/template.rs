@@ -1,6 +1,32 @@
-use crate::models::command::CurlCommand;
+use crate::models::command::{Auth, CurlCommand, FormFieldKind, RequestBody};
+use crate::models::environment::Environment;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// The declared type of a template variable, used to hint how it should be
+/// prompted for and displayed (e.g. secrets get masked input)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TemplateVariableType {
+    /// Plain text
+    String,
+    /// A value that should be masked when displayed
+    Secret,
+    /// A URL
+    Url,
+}
+
+/// A named placeholder used in a template's URL, headers, or body, e.g.
+/// `{{base_url}}`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TemplateVariable {
+    /// Variable key, as it appears inside `{{...}}` in the template
+    pub key: String,
+    /// Value to use when the environment doesn't define this variable
+    pub default_value: Option<String>,
+    /// Declared type of the variable
+    pub var_type: TemplateVariableType,
+}
 
 /// Command template struct
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -11,8 +37,10 @@ pub struct CommandTemplate {
     pub name: String,
     /// Template description
     pub description: Option<String>,
-    /// The curl command
+    /// The curl command, with `{{variable}}` placeholders in its fields
     pub command: CurlCommand,
+    /// Named placeholders used by this template's command
+    pub variables: Vec<TemplateVariable>,
     /// Template category
     pub category: Option<String>,
     /// Creation timestamp
@@ -29,6 +57,7 @@ impl CommandTemplate {
             name,
             description: None,
             command,
+            variables: Vec::new(),
             category: None,
             created_at: Utc::now(),
             updated_at: Utc::now(),
@@ -46,4 +75,171 @@ impl CommandTemplate {
         self.category = Some(category);
         self
     }
+
+    /// Add a named placeholder to this template
+    pub fn add_variable(&mut self, key: String, default_value: Option<String>, var_type: TemplateVariableType) -> &mut Self {
+        self.variables.push(TemplateVariable { key, default_value, var_type });
+        self
+    }
+
+    /// Variables that have neither a value in `environment` nor a declared
+    /// default, and so must be prompted for before the template can be used
+    pub fn unbound_variables(&self, environment: &Environment) -> Vec<&TemplateVariable> {
+        self.variables
+            .iter()
+            .filter(|var| environment.get_variable(&var.key).is_none() && var.default_value.is_none())
+            .collect()
+    }
+
+    /// Resolve this template's `{{variable}}` placeholders into a concrete
+    /// `CurlCommand`, ready to populate `CommandBuilder`. Values are looked
+    /// up in `overrides` first (explicit user input), then `environment`,
+    /// then the variable's declared default. Substitution reaches every
+    /// field a command can interpolate into: URL, headers, query params,
+    /// form text fields, the raw body, option values, and auth.
+    pub fn resolve(&self, environment: &Environment, overrides: &HashMap<String, String>) -> CurlCommand {
+        let mut values: HashMap<String, String> = HashMap::new();
+        for var in &self.variables {
+            let resolved = overrides
+                .get(&var.key)
+                .cloned()
+                .or_else(|| environment.get_variable(&var.key))
+                .or_else(|| var.default_value.clone());
+            if let Some(resolved) = resolved {
+                values.insert(var.key.clone(), resolved);
+            }
+        }
+
+        let mut command = self.command.clone();
+        command.url = Self::substitute(&command.url, &values);
+        for header in &mut command.headers {
+            header.key = Self::substitute(&header.key, &values);
+            header.value = Self::substitute(&header.value, &values);
+        }
+        for param in &mut command.query_params {
+            param.key = Self::substitute(&param.key, &values);
+            param.value = Self::substitute(&param.value, &values);
+        }
+        if let Some(body) = &mut command.body {
+            match body {
+                RequestBody::Raw(content) => *content = Self::substitute(content, &values),
+                RequestBody::FormData(items) => {
+                    for item in items {
+                        item.key = Self::substitute(&item.key, &values);
+                        if let FormFieldKind::Text(value) = &mut item.kind {
+                            *value = Self::substitute(value, &values);
+                        }
+                    }
+                }
+                RequestBody::Binary(_) | RequestBody::None => {}
+            }
+        }
+        for option in &mut command.options {
+            if let Some(value) = &mut option.value {
+                *value = Self::substitute(value, &values);
+            }
+        }
+        command.auth = match command.auth {
+            Auth::Basic { username, password } => Auth::Basic {
+                username: Self::substitute(&username, &values),
+                password: Self::substitute(&password, &values),
+            },
+            Auth::Bearer(token) => Auth::Bearer(Self::substitute(&token, &values)),
+            Auth::ApiKey { location, name, value } => Auth::ApiKey {
+                location,
+                name: Self::substitute(&name, &values),
+                value: Self::substitute(&value, &values),
+            },
+            Auth::OAuth2 { token, refresh_url } => {
+                Auth::OAuth2 { token: Self::substitute(&token, &values), refresh_url }
+            }
+            Auth::None => Auth::None,
+        };
+
+        command
+    }
+
+    /// The set of `{{variable}}` names referenced anywhere in this
+    /// template's command (URL, headers, query params, form fields, body,
+    /// options, and auth), in the order encountered, so the UI can prompt
+    /// for them before the template is resolved
+    pub fn required_variables(&self) -> Vec<String> {
+        self.command.unresolved_variables(&Environment::new(String::new()))
+    }
+
+    /// Replace every `{{key}}` occurrence in `input` with its resolved value
+    fn substitute(input: &str, values: &HashMap<String, String>) -> String {
+        let mut result = input.to_string();
+        for (key, value) in values {
+            result = result.replace(&format!("{{{{{}}}}}", key), value);
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unbound_variables_without_default_or_environment() {
+        let mut template = CommandTemplate::new("GET".to_string(), CurlCommand::default());
+        template.add_variable("base_url".to_string(), None, TemplateVariableType::Url);
+
+        let environment = Environment::new("dev".to_string());
+        assert_eq!(template.unbound_variables(&environment).len(), 1);
+    }
+
+    #[test]
+    fn test_resolve_substitutes_from_environment_and_defaults() {
+        let mut command = CurlCommand::default();
+        command.url = "{{base_url}}/users".to_string();
+
+        let mut template = CommandTemplate::new("GET Users".to_string(), command);
+        template.add_variable("base_url".to_string(), Some("https://default.example.com".to_string()), TemplateVariableType::Url);
+
+        let mut environment = Environment::new("dev".to_string());
+        environment.add_variable("base_url".to_string(), "https://dev.example.com".to_string(), false);
+
+        let resolved = template.resolve(&environment, &HashMap::new());
+        assert_eq!(resolved.url, "https://dev.example.com/users");
+    }
+
+    #[test]
+    fn test_required_variables_collects_names_across_fields() {
+        let mut command = CurlCommand::default();
+        command.url = "{{base_url}}/users".to_string();
+        command.add_header("X-Api-Key".to_string(), "{{api_key}}".to_string());
+
+        let template = CommandTemplate::new("GET Users".to_string(), command);
+        assert_eq!(template.required_variables(), vec!["base_url".to_string(), "api_key".to_string()]);
+    }
+
+    #[test]
+    fn test_resolve_substitutes_query_params() {
+        let mut command = CurlCommand::default();
+        command.url = "{{base_url}}/users".to_string();
+        command.add_query_param("token".to_string(), "{{api_key}}".to_string());
+
+        let mut template = CommandTemplate::new("GET Users".to_string(), command);
+        template.add_variable("base_url".to_string(), Some("https://default.example.com".to_string()), TemplateVariableType::Url);
+        template.add_variable("api_key".to_string(), Some("secret".to_string()), TemplateVariableType::Secret);
+
+        let environment = Environment::new("dev".to_string());
+        let resolved = template.resolve(&environment, &HashMap::new());
+        assert_eq!(resolved.query_params[0].value, "secret");
+    }
+
+    #[test]
+    fn test_resolve_falls_back_to_default() {
+        let mut command = CurlCommand::default();
+        command.url = "{{base_url}}/users".to_string();
+
+        let mut template = CommandTemplate::new("GET Users".to_string(), command);
+        template.add_variable("base_url".to_string(), Some("https://default.example.com".to_string()), TemplateVariableType::Url);
+
+        let environment = Environment::new("dev".to_string());
+        let resolved = template.resolve(&environment, &HashMap::new());
+        assert_eq!(resolved.url, "https://default.example.com/users");
+    }
 }
\ No newline at end of file
@@ -0,0 +1,162 @@
+use crate::models::environment::Environment;
+use crate::models::template::CommandTemplate;
+use crate::models::command::CurlCommand;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+
+/// Number of past commands kept in the persisted history ring; older
+/// entries are dropped as new ones are pushed
+const HISTORY_CAPACITY: usize = 100;
+
+/// The subset of `App` state that survives between runs: templates,
+/// environments, the active environment, and a bounded command history.
+/// Serialized to disk under the platform config dir so the in-memory
+/// sample data in `App::new` only ever seeds a brand-new install.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PersistedState {
+    /// Schema version, bumped whenever a breaking change is made to this
+    /// struct's shape so `load` can detect and discard old files
+    pub schema_version: u32,
+    /// Command templates
+    pub templates: Vec<CommandTemplate>,
+    /// Environments, keyed by name
+    pub environments: HashMap<String, Environment>,
+    /// Currently selected environment
+    pub current_environment: String,
+    /// Past executed commands, most recent last, capped at
+    /// `HISTORY_CAPACITY`
+    pub history: Vec<CurlCommand>,
+    /// Full paths of collections-tree folders expanded by the user,
+    /// remembered across sessions
+    #[serde(default)]
+    pub expanded_folders: HashSet<String>,
+}
+
+/// Current schema version written by this build; bump when the shape of
+/// `PersistedState` changes in a way older files can't be read as
+///
+/// v2: `EnvironmentVariable::value` went from a plain `String` to the
+/// tagged `SecretValue` enum (plaintext or sealed ciphertext), so state
+/// files written by v1 no longer deserialize and are reset rather than
+/// misread.
+const CURRENT_SCHEMA_VERSION: u32 = 2;
+
+impl PersistedState {
+    /// Push a command onto the history ring, dropping the oldest entry
+    /// once `HISTORY_CAPACITY` is exceeded
+    pub fn push_history(&mut self, command: CurlCommand) {
+        self.history.push(command);
+        if self.history.len() > HISTORY_CAPACITY {
+            let overflow = self.history.len() - HISTORY_CAPACITY;
+            self.history.drain(0..overflow);
+        }
+    }
+}
+
+impl Default for PersistedState {
+    fn default() -> Self {
+        Self {
+            schema_version: CURRENT_SCHEMA_VERSION,
+            templates: Vec::new(),
+            environments: HashMap::new(),
+            current_environment: "default".to_string(),
+            history: Vec::new(),
+            expanded_folders: HashSet::new(),
+        }
+    }
+}
+
+/// The platform config directory for lazycurl (`~/.config/lazycurl`),
+/// created on demand
+fn config_dir() -> Option<PathBuf> {
+    let home = std::env::var("HOME").ok()?;
+    Some(PathBuf::from(home).join(".config").join("lazycurl"))
+}
+
+/// Path to the persisted state file
+fn state_path() -> Option<PathBuf> {
+    config_dir().map(|dir| dir.join("state.json"))
+}
+
+/// Whether a state file already exists on disk. `App::new` uses this to
+/// decide whether to seed the built-in sample templates and environment —
+/// only a brand-new install (no state file yet) gets them.
+pub fn has_persisted_state() -> bool {
+    state_path().map(|path| path.exists()).unwrap_or(false)
+}
+
+/// Load persisted state from disk, falling back to `PersistedState::default`
+/// when no file exists, the file is unreadable, or it fails to parse or is
+/// from an incompatible schema version. A corrupt or old-schema file is
+/// backed up alongside itself (`state.json.bak`) rather than overwritten
+/// silently, so the user can recover it if needed.
+pub fn load() -> PersistedState {
+    let Some(path) = state_path() else {
+        return PersistedState::default();
+    };
+
+    let Ok(contents) = std::fs::read_to_string(&path) else {
+        return PersistedState::default();
+    };
+
+    match serde_json::from_str::<PersistedState>(&contents) {
+        Ok(state) if state.schema_version == CURRENT_SCHEMA_VERSION => state,
+        Ok(_) | Err(_) => {
+            back_up_corrupt_file(&path);
+            PersistedState::default()
+        }
+    }
+}
+
+/// Save persisted state to disk, creating the config directory if needed
+pub fn save(state: &PersistedState) -> Result<(), String> {
+    let path = state_path().ok_or_else(|| "Could not determine config directory (HOME not set)".to_string())?;
+
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).map_err(|err| format!("Failed to create config directory: {}", err))?;
+    }
+
+    let serialized =
+        serde_json::to_string_pretty(state).map_err(|err| format!("Failed to serialize state: {}", err))?;
+    std::fs::write(&path, serialized).map_err(|err| format!("Failed to write state file {}: {}", path.display(), err))
+}
+
+/// Move an unreadable or incompatible state file aside so it isn't lost,
+/// rather than silently overwriting it on the next save
+fn back_up_corrupt_file(path: &std::path::Path) {
+    let backup_path = path.with_extension("json.bak");
+    let _ = std::fs::rename(path, backup_path);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_push_history_caps_at_capacity() {
+        let mut state = PersistedState::default();
+        for _ in 0..(HISTORY_CAPACITY + 10) {
+            state.push_history(CurlCommand::default());
+        }
+        assert_eq!(state.history.len(), HISTORY_CAPACITY);
+    }
+
+    #[test]
+    fn test_default_state_is_empty_with_current_schema() {
+        let state = PersistedState::default();
+        assert_eq!(state.schema_version, CURRENT_SCHEMA_VERSION);
+        assert!(state.templates.is_empty());
+        assert!(state.history.is_empty());
+        assert_eq!(state.current_environment, "default");
+    }
+
+    #[test]
+    fn test_old_schema_version_is_rejected() {
+        let mut state = PersistedState::default();
+        state.schema_version = 0;
+        let serialized = serde_json::to_string(&state).unwrap();
+        let reparsed: PersistedState = serde_json::from_str(&serialized).unwrap();
+        assert_ne!(reparsed.schema_version, CURRENT_SCHEMA_VERSION);
+    }
+}
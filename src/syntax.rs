@@ -0,0 +1,470 @@
+//! Lightweight, per-line tokenizer used to syntax-highlight request bodies
+//! in the Body tab, plus JSON pretty-print/minify helpers for the
+//! "reformat buffer" action. Deliberately line-oriented (not a full
+//! streaming parser) since the renderer only ever needs styled spans for
+//! the lines currently visible in the viewport.
+
+use crate::models::command::Header;
+
+/// Content type detected from the current command's `Content-Type` header,
+/// used to pick a tokenizer for body syntax highlighting
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContentType {
+    Json,
+    Xml,
+    FormUrlEncoded,
+    PlainText,
+}
+
+/// Detect the body's content type from a `Content-Type` header, falling
+/// back to plain text when absent or unrecognized
+pub fn detect_content_type(headers: &[Header]) -> ContentType {
+    let value = headers
+        .iter()
+        .find(|header| header.key.eq_ignore_ascii_case("content-type"))
+        .map(|header| header.value.clone());
+
+    match value {
+        Some(value) => content_type_from_header_value(&value),
+        None => ContentType::PlainText,
+    }
+}
+
+/// Map a raw `Content-Type` header value (e.g. `"application/json;
+/// charset=utf-8"`) to the content type it selects, falling back to plain
+/// text when unrecognized. Split out of [`detect_content_type`] so callers
+/// that only have the raw header string (e.g. a parsed HTTP response,
+/// rather than this app's own `Header` model) can still reuse the mapping.
+pub fn content_type_from_header_value(value: &str) -> ContentType {
+    let value = value.to_lowercase();
+    if value.contains("json") {
+        ContentType::Json
+    } else if value.contains("xml") || value.contains("html") {
+        ContentType::Xml
+    } else if value.contains("x-www-form-urlencoded") {
+        ContentType::FormUrlEncoded
+    } else {
+        ContentType::PlainText
+    }
+}
+
+/// Guess a body's content type from its first non-whitespace character,
+/// used when no `Content-Type` header is available to consult
+pub fn content_type_from_body(body: &str) -> ContentType {
+    match body.trim_start().chars().next() {
+        Some('{') | Some('[') => ContentType::Json,
+        Some('<') => ContentType::Xml,
+        _ => ContentType::PlainText,
+    }
+}
+
+/// Highlight category for a single token, mapped to a `Theme` style by the
+/// renderer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenKind {
+    /// An object key, e.g. the `"name"` in `"name": "value"`
+    Key,
+    /// A quoted string value
+    String,
+    /// A numeric literal
+    Number,
+    /// `true`/`false`/`null`
+    Literal,
+    /// Structural punctuation: braces, brackets, colons, commas, `<`/`>`/`=`
+    Punctuation,
+    /// Whitespace or anything else not recognized by the tokenizer
+    Text,
+}
+
+/// A single highlighted run of text within a line
+#[derive(Debug, Clone, PartialEq)]
+pub struct Token {
+    pub text: String,
+    pub kind: TokenKind,
+}
+
+/// Tokenize a single line of body content for syntax highlighting,
+/// dispatching on the detected content type
+pub fn tokenize_line(content_type: ContentType, line: &str) -> Vec<Token> {
+    match content_type {
+        ContentType::Json => tokenize_json_line(line),
+        ContentType::Xml => tokenize_xml_line(line),
+        ContentType::FormUrlEncoded => tokenize_form_urlencoded_line(line),
+        ContentType::PlainText => vec![Token { text: line.to_string(), kind: TokenKind::Text }],
+    }
+}
+
+/// Tokenize one line of JSON: strings (reclassified as `Key` when followed
+/// by a colon), numbers, `true`/`false`/`null` literals, and punctuation
+fn tokenize_json_line(line: &str) -> Vec<Token> {
+    let chars: Vec<char> = line.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+
+        if c == '"' {
+            let start = i;
+            i += 1;
+            while i < chars.len() {
+                if chars[i] == '\\' && i + 1 < chars.len() {
+                    i += 2;
+                    continue;
+                }
+                if chars[i] == '"' {
+                    i += 1;
+                    break;
+                }
+                i += 1;
+            }
+            let text: String = chars[start..i].iter().collect();
+
+            // A string immediately followed by whitespace then ':' is a key
+            let mut lookahead = i;
+            while lookahead < chars.len() && chars[lookahead].is_whitespace() {
+                lookahead += 1;
+            }
+            let kind = if lookahead < chars.len() && chars[lookahead] == ':' {
+                TokenKind::Key
+            } else {
+                TokenKind::String
+            };
+            tokens.push(Token { text, kind });
+        } else if c.is_ascii_digit() || (c == '-' && chars.get(i + 1).is_some_and(|n| n.is_ascii_digit())) {
+            let start = i;
+            i += 1;
+            while i < chars.len() && (chars[i].is_ascii_digit() || matches!(chars[i], '.' | 'e' | 'E' | '+' | '-')) {
+                i += 1;
+            }
+            tokens.push(Token { text: chars[start..i].iter().collect(), kind: TokenKind::Number });
+        } else if c.is_alphabetic() {
+            let start = i;
+            while i < chars.len() && chars[i].is_alphanumeric() {
+                i += 1;
+            }
+            let text: String = chars[start..i].iter().collect();
+            let kind = if matches!(text.as_str(), "true" | "false" | "null") { TokenKind::Literal } else { TokenKind::Text };
+            tokens.push(Token { text, kind });
+        } else if matches!(c, '{' | '}' | '[' | ']' | ':' | ',') {
+            tokens.push(Token { text: c.to_string(), kind: TokenKind::Punctuation });
+            i += 1;
+        } else {
+            let start = i;
+            i += 1;
+            tokens.push(Token { text: chars[start..i].iter().collect(), kind: TokenKind::Text });
+        }
+    }
+
+    tokens
+}
+
+/// Tokenize one line of XML: tag delimiters and `=` as punctuation, tag
+/// and attribute names as keys, quoted attribute values as strings
+fn tokenize_xml_line(line: &str) -> Vec<Token> {
+    let chars: Vec<char> = line.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+
+        if matches!(c, '<' | '>' | '/' | '=') {
+            tokens.push(Token { text: c.to_string(), kind: TokenKind::Punctuation });
+            i += 1;
+        } else if c == '"' || c == '\'' {
+            let quote = c;
+            let start = i;
+            i += 1;
+            while i < chars.len() && chars[i] != quote {
+                i += 1;
+            }
+            if i < chars.len() {
+                i += 1;
+            }
+            tokens.push(Token { text: chars[start..i].iter().collect(), kind: TokenKind::String });
+        } else if c.is_alphabetic() || c == '_' {
+            let start = i;
+            while i < chars.len() && (chars[i].is_alphanumeric() || matches!(chars[i], '_' | '-' | ':')) {
+                i += 1;
+            }
+            tokens.push(Token { text: chars[start..i].iter().collect(), kind: TokenKind::Key });
+        } else {
+            let start = i;
+            i += 1;
+            tokens.push(Token { text: chars[start..i].iter().collect(), kind: TokenKind::Text });
+        }
+    }
+
+    tokens
+}
+
+/// Tokenize one line of `key=value&key=value` form data: keys, `=`/`&`
+/// punctuation, and values as strings
+fn tokenize_form_urlencoded_line(line: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+
+    for (pair_idx, pair) in line.split('&').enumerate() {
+        if pair_idx > 0 {
+            tokens.push(Token { text: "&".to_string(), kind: TokenKind::Punctuation });
+        }
+
+        match pair.split_once('=') {
+            Some((key, value)) => {
+                tokens.push(Token { text: key.to_string(), kind: TokenKind::Key });
+                tokens.push(Token { text: "=".to_string(), kind: TokenKind::Punctuation });
+                tokens.push(Token { text: value.to_string(), kind: TokenKind::String });
+            }
+            None => tokens.push(Token { text: pair.to_string(), kind: TokenKind::Key }),
+        }
+    }
+
+    tokens
+}
+
+/// Toggle a body's formatting in place: minify a pretty-printed JSON
+/// payload, or pretty-print a minified one, detected by whether the
+/// content already contains a newline. Non-JSON content types and
+/// unparseable JSON are returned unchanged.
+pub fn toggle_format(content_type: ContentType, content: &str) -> String {
+    if content_type != ContentType::Json {
+        return content.to_string();
+    }
+
+    let Ok(value) = serde_json::from_str::<serde_json::Value>(content) else {
+        return content.to_string();
+    };
+
+    if content.contains('\n') {
+        serde_json::to_string(&value).unwrap_or_else(|_| content.to_string())
+    } else {
+        serde_json::to_string_pretty(&value).unwrap_or_else(|_| content.to_string())
+    }
+}
+
+/// Reformat a JSON body into indented multi-line form, regardless of how it
+/// currently looks, for views that always want the reflowed shape rather
+/// than a minify/pretty toggle (e.g. the response output panel's "pretty"
+/// mode). Non-JSON content types and unparseable JSON are returned
+/// unchanged.
+pub fn pretty_print(content_type: ContentType, content: &str) -> String {
+    if content_type != ContentType::Json {
+        return content.to_string();
+    }
+
+    match serde_json::from_str::<serde_json::Value>(content) {
+        Ok(value) => serde_json::to_string_pretty(&value).unwrap_or_else(|_| content.to_string()),
+        Err(_) => content.to_string(),
+    }
+}
+
+/// A response-like blob of text (headers/preamble, a blank-line separator,
+/// then a body) split into the lines it should actually be displayed as,
+/// with its content type resolved and the body pretty-printed when
+/// requested. The single source of truth for both the output panel's line
+/// styling and its incremental search, so highlighted matches and search
+/// results can't drift out of sync with what's actually on screen.
+pub struct DisplayedOutput {
+    /// Index into `lines` of the blank separator line, if the text had one
+    pub header_end: Option<usize>,
+    /// Content type resolved for the body
+    pub content_type: ContentType,
+    /// Every line to display: the header/preamble lines, the blank
+    /// separator (if present), then the (possibly pretty-printed) body
+    pub lines: Vec<String>,
+}
+
+/// Split `output` into its displayable lines at the first blank line,
+/// resolve the body's content type, and pretty-print a JSON body when
+/// `pretty_print_json` is set
+pub fn display_output(output: &str, pretty_print_json: bool) -> DisplayedOutput {
+    let all_lines: Vec<&str> = output.lines().collect();
+    let header_end = all_lines.iter().position(|line| line.is_empty());
+
+    let content_type_header = header_end.map(|end| &all_lines[..end]).and_then(|header_lines| {
+        header_lines.iter().find_map(|line| {
+            let (key, value) = line.split_once(':')?;
+            key.eq_ignore_ascii_case("content-type").then(|| value.trim())
+        })
+    });
+
+    let raw_body = header_end.map(|end| &all_lines[end + 1..]).unwrap_or(&[]);
+    let content_type = match content_type_header {
+        Some(value) => content_type_from_header_value(value),
+        None => raw_body.first().map(|line| content_type_from_body(line)).unwrap_or(ContentType::PlainText),
+    };
+
+    let body: Vec<String> = if pretty_print_json && content_type == ContentType::Json {
+        pretty_print(content_type, &raw_body.join("\n")).lines().map(|line| line.to_string()).collect()
+    } else {
+        raw_body.iter().map(|line| line.to_string()).collect()
+    };
+
+    let header_lines = header_end.map(|end| &all_lines[..end]).unwrap_or(&all_lines);
+    let mut lines: Vec<String> = header_lines.iter().map(|line| line.to_string()).collect();
+    if header_end.is_some() {
+        lines.push(String::new());
+        lines.extend(body);
+    }
+
+    DisplayedOutput { header_end, content_type, lines }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn header(key: &str, value: &str) -> Header {
+        Header { id: "id".to_string(), key: key.to_string(), value: value.to_string(), enabled: true }
+    }
+
+    #[test]
+    fn test_detect_content_type_matches_known_types() {
+        assert_eq!(detect_content_type(&[header("Content-Type", "application/json")]), ContentType::Json);
+        assert_eq!(detect_content_type(&[header("content-type", "text/xml")]), ContentType::Xml);
+        assert_eq!(
+            detect_content_type(&[header("Content-Type", "application/x-www-form-urlencoded")]),
+            ContentType::FormUrlEncoded
+        );
+        assert_eq!(detect_content_type(&[]), ContentType::PlainText);
+    }
+
+    #[test]
+    fn test_tokenize_json_line_distinguishes_keys_from_string_values() {
+        let tokens = tokenize_json_line(r#"  "name": "value","#);
+        let key = tokens.iter().find(|t| t.text == "\"name\"").expect("key token");
+        assert_eq!(key.kind, TokenKind::Key);
+        let value = tokens.iter().find(|t| t.text == "\"value\"").expect("value token");
+        assert_eq!(value.kind, TokenKind::String);
+    }
+
+    #[test]
+    fn test_tokenize_json_line_recognizes_numbers_and_literals() {
+        let tokens = tokenize_json_line(r#""count": -12, "ok": true, "v": null"#);
+        assert!(tokens.iter().any(|t| t.text == "-12" && t.kind == TokenKind::Number));
+        assert!(tokens.iter().any(|t| t.text == "true" && t.kind == TokenKind::Literal));
+        assert!(tokens.iter().any(|t| t.text == "null" && t.kind == TokenKind::Literal));
+    }
+
+    #[test]
+    fn test_tokenize_form_urlencoded_line() {
+        let tokens = tokenize_form_urlencoded_line("a=1&b=2");
+        assert_eq!(tokens.iter().filter(|t| t.kind == TokenKind::Key).count(), 2);
+        assert!(tokens.iter().any(|t| t.text == "&" && t.kind == TokenKind::Punctuation));
+    }
+
+    #[test]
+    fn test_tokenize_xml_line_distinguishes_tags_from_attribute_values() {
+        let tokens = tokenize_xml_line(r#"<user id="1">"#);
+        let tag = tokens.iter().find(|t| t.text == "user").expect("tag name token");
+        assert_eq!(tag.kind, TokenKind::Key);
+        let value = tokens.iter().find(|t| t.text == "\"1\"").expect("attribute value token");
+        assert_eq!(value.kind, TokenKind::String);
+    }
+
+    #[test]
+    fn test_tokenize_xml_line_does_not_panic_on_an_unterminated_attribute() {
+        let tokens = tokenize_xml_line(r#"<user id="1"#);
+        let rejoined: String = tokens.iter().map(|t| t.text.as_str()).collect();
+        assert_eq!(rejoined, r#"<user id="1"#);
+    }
+
+    #[test]
+    fn test_tokenize_form_urlencoded_line_does_not_panic_on_a_key_with_no_value() {
+        let tokens = tokenize_form_urlencoded_line("a=1&just-a-key&b=2");
+        let bare_key = tokens.iter().find(|t| t.text == "just-a-key").expect("bare key token");
+        assert_eq!(bare_key.kind, TokenKind::Key);
+    }
+
+    #[test]
+    fn test_toggle_format_pretty_prints_minified_json() {
+        let minified = r#"{"a":1}"#;
+        let pretty = toggle_format(ContentType::Json, minified);
+        assert!(pretty.contains('\n'));
+    }
+
+    #[test]
+    fn test_toggle_format_round_trips_pretty_back_to_minified() {
+        let minified = r#"{"a":1}"#;
+        let pretty = toggle_format(ContentType::Json, minified);
+        let minified_again = toggle_format(ContentType::Json, &pretty);
+        let reparsed_original: serde_json::Value = serde_json::from_str(minified).unwrap();
+        let reparsed_again: serde_json::Value = serde_json::from_str(&minified_again).unwrap();
+        assert_eq!(reparsed_original, reparsed_again);
+        assert!(!minified_again.contains('\n'));
+    }
+
+    #[test]
+    fn test_toggle_format_leaves_non_json_untouched() {
+        let content = "a=1&b=2";
+        assert_eq!(toggle_format(ContentType::FormUrlEncoded, content), content);
+    }
+
+    #[test]
+    fn test_toggle_format_leaves_unparseable_json_untouched() {
+        let content = "not json";
+        assert_eq!(toggle_format(ContentType::Json, content), content);
+    }
+
+    #[test]
+    fn test_tokenize_json_line_does_not_panic_on_an_unterminated_string() {
+        let tokens = tokenize_json_line(r#"{"name": "unterminated"#);
+        let rejoined: String = tokens.iter().map(|t| t.text.as_str()).collect();
+        assert_eq!(rejoined, r#"{"name": "unterminated"#);
+    }
+
+    #[test]
+    fn test_content_type_from_header_value_recognizes_html_as_xml_like() {
+        assert_eq!(content_type_from_header_value("text/html; charset=utf-8"), ContentType::Xml);
+    }
+
+    #[test]
+    fn test_content_type_from_body_guesses_from_the_first_character() {
+        assert_eq!(content_type_from_body("  {\"a\": 1}"), ContentType::Json);
+        assert_eq!(content_type_from_body("[1, 2]"), ContentType::Json);
+        assert_eq!(content_type_from_body("<html></html>"), ContentType::Xml);
+        assert_eq!(content_type_from_body("plain text"), ContentType::PlainText);
+    }
+
+    #[test]
+    fn test_pretty_print_reflows_minified_json_regardless_of_current_shape() {
+        let minified = r#"{"a":1}"#;
+        assert!(pretty_print(ContentType::Json, minified).contains('\n'));
+        // Already-pretty input stays reflowed, rather than round-tripping
+        // back to minified the way `toggle_format` would
+        let already_pretty = pretty_print(ContentType::Json, minified);
+        assert!(pretty_print(ContentType::Json, &already_pretty).contains('\n'));
+    }
+
+    #[test]
+    fn test_pretty_print_leaves_non_json_and_unparseable_json_untouched() {
+        assert_eq!(pretty_print(ContentType::FormUrlEncoded, "a=1&b=2"), "a=1&b=2");
+        assert_eq!(pretty_print(ContentType::Json, "not json"), "not json");
+    }
+
+    #[test]
+    fn test_display_output_splits_at_the_first_blank_line_and_detects_the_content_type() {
+        let output = "Content-Type: application/json\n\n{\"a\":1}";
+        let displayed = display_output(output, false);
+        assert_eq!(displayed.header_end, Some(1));
+        assert_eq!(displayed.content_type, ContentType::Json);
+        assert_eq!(displayed.lines, vec!["Content-Type: application/json", "", "{\"a\":1}"]);
+    }
+
+    #[test]
+    fn test_display_output_pretty_prints_the_body_only_when_requested() {
+        let output = "Content-Type: application/json\n\n{\"a\":1}";
+        let raw = display_output(output, false);
+        assert_eq!(raw.lines.last().unwrap(), "{\"a\":1}");
+
+        let pretty = display_output(output, true);
+        assert!(pretty.lines.len() > raw.lines.len());
+    }
+
+    #[test]
+    fn test_display_output_with_no_blank_line_treats_everything_as_headers() {
+        let displayed = display_output("just one block of text", false);
+        assert_eq!(displayed.header_end, None);
+        assert_eq!(displayed.lines, vec!["just one block of text"]);
+    }
+}
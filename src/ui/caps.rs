@@ -0,0 +1,86 @@
+//! Terminal hyperlink capability probe, used to gate the OSC 8 escape
+//! sequences the output panel wraps `Location`/`Link`/`Content-Location`
+//! header values in. Dependency-free env-var checks, mirroring the
+//! `COLORTERM` probe `ui::theme::supports_truecolor` already uses for
+//! truecolor detection.
+
+/// The env-var-driven decision behind [`supports_hyperlinks`], kept
+/// separate from the actual env reads so it's testable without mutating
+/// process environment state
+fn hyperlinks_supported_for(no_color: bool, force_hyperlink: bool, term_program: &str, windows_terminal: bool, vte_based: bool) -> bool {
+    if no_color {
+        return false;
+    }
+    if force_hyperlink {
+        return true;
+    }
+
+    const KNOWN_TERM_PROGRAMS: &[&str] = &["iTerm.app", "WezTerm", "vscode", "Hyper"];
+    KNOWN_TERM_PROGRAMS.contains(&term_program) || windows_terminal || vte_based
+}
+
+/// Whether the terminal understands OSC 8 hyperlink escapes. `NO_COLOR`
+/// (a terminal opting out of all nonessential escape sequences) always
+/// disables it; `FORCE_HYPERLINK` always forces it on, overriding every
+/// other check. Otherwise a known-supporting terminal is detected via
+/// `TERM_PROGRAM`, `WT_SESSION` (Windows Terminal), or `VTE_VERSION`
+/// (GNOME Terminal, Konsole, and other VTE-based terminals, which have
+/// supported OSC 8 since VTE 0.50).
+pub fn supports_hyperlinks() -> bool {
+    hyperlinks_supported_for(
+        std::env::var("NO_COLOR").is_ok(),
+        std::env::var("FORCE_HYPERLINK").is_ok(),
+        &std::env::var("TERM_PROGRAM").unwrap_or_default(),
+        std::env::var("WT_SESSION").is_ok(),
+        std::env::var("VTE_VERSION").is_ok(),
+    )
+}
+
+/// Build an OSC 8 hyperlink escape wrapping `text`, pointing at `url`
+fn build_hyperlink(url: &str, text: &str) -> String {
+    format!("\x1b]8;;{}\x1b\\{}\x1b]8;;\x1b\\", url, text)
+}
+
+/// Wrap `text` in an OSC 8 hyperlink escape pointing at `url`, or return
+/// `text` unchanged when [`supports_hyperlinks`] says the terminal won't
+/// understand it -- the plain-text fallback for every other terminal
+pub fn hyperlink(url: &str, text: &str) -> String {
+    if supports_hyperlinks() {
+        build_hyperlink(url, text)
+    } else {
+        text.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_no_color_always_wins_over_force_hyperlink() {
+        assert!(!hyperlinks_supported_for(true, true, "iTerm.app", true, true));
+    }
+
+    #[test]
+    fn test_force_hyperlink_overrides_an_unknown_terminal() {
+        assert!(hyperlinks_supported_for(false, true, "unknown", false, false));
+    }
+
+    #[test]
+    fn test_known_term_program_is_detected() {
+        assert!(hyperlinks_supported_for(false, false, "WezTerm", false, false));
+        assert!(!hyperlinks_supported_for(false, false, "unknown-terminal", false, false));
+    }
+
+    #[test]
+    fn test_windows_terminal_and_vte_session_are_detected() {
+        assert!(hyperlinks_supported_for(false, false, "", true, false));
+        assert!(hyperlinks_supported_for(false, false, "", false, true));
+    }
+
+    #[test]
+    fn test_build_hyperlink_wraps_text_in_osc_8_escapes() {
+        let escaped = build_hyperlink("https://example.com", "example");
+        assert_eq!(escaped, "\x1b]8;;https://example.com\x1b\\example\x1b]8;;\x1b\\");
+    }
+}
@@ -1,11 +1,16 @@
-use crate::app::{App, Tab, AppState, EditField, SelectedField, UrlField, BodyField};
+use crate::app::{App, ClickRegions, Tab, AppState, EditField, SelectedField, UrlField, BodyField};
 use crate::models::command::{CurlCommand, HttpMethod};
+use crate::ui::components::completion::Completion;
+use crate::ui::components::picker::Picker;
+use crate::ui::components::scrollable_list;
 use crate::ui::theme::Theme;
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
 use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span, Text};
 use ratatui::widgets::{Block, Borders, Paragraph, Tabs};
 use ratatui::Frame;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
 
 /// Command builder component
 pub struct CommandBuilder<'a> {
@@ -39,7 +44,31 @@ impl<'a> CommandBuilder<'a> {
         // Render tabs
         self.render_tabs(frame, chunks[1]);
 
-        // Render tab content
+        // Render tab content, or the picker overlay if it's open over the
+        // Headers/Options tab
+        if matches!(self.app.state, AppState::Picker) {
+            Picker::new(self.app, self.theme).render(frame, chunks[2]);
+            return;
+        }
+
+        // When the inline completion popup is open, render the tab content
+        // in a smaller area and the popup anchored below it
+        if matches!(self.app.state, AppState::Completing(_)) {
+            let content_chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Min(0), Constraint::Length(8)])
+                .split(chunks[2]);
+
+            match self.app.ui_state.active_tab {
+                Tab::Headers => self.render_headers_tab(frame, content_chunks[0]),
+                Tab::Options => self.render_options_tab(frame, content_chunks[0]),
+                _ => {}
+            }
+
+            Completion::new(self.app, self.theme).render(frame, content_chunks[1]);
+            return;
+        }
+
         match self.app.ui_state.active_tab {
             Tab::Url => self.render_url_tab(frame, chunks[2]),
             Tab::Headers => self.render_headers_tab(frame, chunks[2]),
@@ -48,6 +77,74 @@ impl<'a> CommandBuilder<'a> {
         }
     }
 
+    /// Recompute the hit-test rectangles a mouse click is resolved
+    /// against: the URL box, each tab's (evenly divided) title region, and
+    /// -- for whichever tab is active, when rows aren't folded -- each
+    /// visible header/query-param/option row. Mirrors `render`'s own
+    /// layout math exactly so a click lands on what the user is actually
+    /// looking at; called separately from `render` since `render` only
+    /// gets `&App`; the caller is expected to store the result back into
+    /// `App::ui_state.click_regions` once it has `&mut App` again.
+    pub fn compute_click_regions(&self, area: Rect) -> ClickRegions {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3),
+                Constraint::Length(3),
+                Constraint::Min(0),
+            ])
+            .split(area);
+
+        let mut regions = ClickRegions { url: Some(chunks[0]), ..ClickRegions::default() };
+
+        let tabs = [Tab::Url, Tab::Headers, Tab::Body, Tab::Options];
+        let tab_width = chunks[1].width / tabs.len() as u16;
+        for (i, tab) in tabs.into_iter().enumerate() {
+            let rect = Rect {
+                x: chunks[1].x + tab_width * i as u16,
+                y: chunks[1].y,
+                width: tab_width,
+                height: chunks[1].height,
+            };
+            regions.tabs.push((tab, rect));
+        }
+
+        // Folding collapses disabled rows into a single summary line,
+        // which would desync the row-index-to-screen-row mapping below;
+        // skip row hit-testing in that mode rather than click on the
+        // wrong field
+        if self.app.ui_state.fold_disabled {
+            return regions;
+        }
+
+        match self.app.ui_state.active_tab {
+            Tab::Headers => {
+                let total = self.app.current_command.headers.len();
+                let scroll = self.app.ui_state.headers_scroll_offset.min(total.saturating_sub(1));
+                for (idx, rect) in (scroll..total).zip(visible_row_rects(chunks[2], total, scroll)) {
+                    regions.rows.push((SelectedField::Headers(idx), rect));
+                }
+            }
+            Tab::Options => {
+                let total = self.app.current_command.options.len();
+                let scroll = self.app.ui_state.options_scroll_offset.min(total.saturating_sub(1));
+                for (idx, rect) in (scroll..total).zip(visible_row_rects(chunks[2], total, scroll)) {
+                    regions.rows.push((SelectedField::Options(idx), rect));
+                }
+            }
+            Tab::Url => {
+                let total = self.app.current_command.query_params.len();
+                let scroll = self.app.ui_state.query_params_scroll_offset.min(total.saturating_sub(1));
+                for (idx, rect) in (scroll..total).zip(visible_row_rects(chunks[2], total, scroll)) {
+                    regions.rows.push((SelectedField::Url(UrlField::QueryParam(idx)), rect));
+                }
+            }
+            Tab::Body => {}
+        }
+
+        regions
+    }
+
     /// Render method component (standalone)
     pub fn render_method_component(&self, frame: &mut Frame, area: Rect) {
         // Check if method dropdown is open
@@ -60,6 +157,45 @@ impl<'a> CommandBuilder<'a> {
         }
     }
 
+    /// Render the active `edit_buffer` as styled spans. When vim mode is
+    /// off, this is just the buffer with a trailing cursor block, matching
+    /// the app's original editing style. When vim mode is on, the cursor
+    /// is drawn at `edit_cursor`'s column and any active visual selection
+    /// is rendered with a distinct highlight.
+    fn edit_buffer_spans(&self, base_style: Style) -> Vec<Span<'static>> {
+        let buffer = &self.app.ui_state.edit_buffer;
+
+        if !self.app.vim_mode {
+            return vec![Span::styled(format!("{} █", buffer), base_style)];
+        }
+
+        let chars: Vec<char> = buffer.chars().collect();
+        let cursor = self.app.ui_state.edit_cursor.min(chars.len());
+        let selection = self.app.ui_state.visual_selection_range();
+
+        let mut spans: Vec<Span<'static>> = chars
+            .iter()
+            .enumerate()
+            .map(|(idx, ch)| {
+                let selected = selection.is_some_and(|(start, end)| idx >= start && idx <= end);
+                let style = if idx == cursor {
+                    self.theme.editing_style()
+                } else if selected {
+                    self.theme.highlight_style()
+                } else {
+                    base_style
+                };
+                Span::styled(ch.to_string(), style)
+            })
+            .collect();
+
+        if cursor >= chars.len() {
+            spans.push(Span::styled("█", self.theme.editing_style()));
+        }
+
+        spans
+    }
+
     /// Render URL input (without method)
     fn render_url_input(&self, frame: &mut Frame, area: Rect) {
         let url = &self.app.current_command.url;
@@ -88,17 +224,13 @@ impl<'a> CommandBuilder<'a> {
         };
 
         // Add visual indicator for editing mode
-        let url_display = if is_editing_url {
-            format!("{} █", url_text) // Add cursor indicator
+        let url_spans = if is_editing_url {
+            self.edit_buffer_spans(url_style)
         } else {
-            url_text.to_string()
+            vec![Span::styled(url_text.to_string(), url_style)]
         };
 
-        let text = Text::from(vec![
-            Line::from(vec![
-                Span::styled(url_display, url_style),
-            ]),
-        ]);
+        let text = Text::from(vec![Line::from(url_spans)]);
 
         // Choose border style based on state
         let border_style = if is_editing_url {
@@ -152,14 +284,16 @@ impl<'a> CommandBuilder<'a> {
     /// Render URL tab
     fn render_url_tab(&self, frame: &mut Frame, area: Rect) {
         // Render query parameters (now takes the full area)
-        let query_block = Block::default()
-            .title("Query Parameters")
-            .borders(Borders::ALL)
-            .style(self.theme.border_style());
+        let active_idx = match self.app.ui_state.selected_field {
+            SelectedField::Url(UrlField::QueryParam(idx)) => Some(idx),
+            _ => None,
+        };
+        let inner_height = Block::default().borders(Borders::ALL).inner(area).height;
 
-        let query_text = if self.app.current_command.query_params.is_empty() {
-            Text::from(vec![Line::from(Span::raw("No query parameters"))])
+        let (query_title, query_text, scroll) = if self.app.current_command.query_params.is_empty() {
+            ("Query Parameters".to_string(), Text::from(vec![Line::from(Span::raw("No query parameters"))]), 0)
         } else {
+            let enabled_flags: Vec<bool> = self.app.current_command.query_params.iter().map(|p| p.enabled).collect();
             let mut lines = Vec::new();
             for (idx, param) in self.app.current_command.query_params.iter().enumerate() {
                 let enabled = if param.enabled { "✓" } else { "✗" };
@@ -188,10 +322,10 @@ impl<'a> CommandBuilder<'a> {
                 };
 
                 // Add visual indicator for editing mode
-                let value_display = if is_editing {
-                    format!("{} █", value_text) // Add cursor indicator
+                let value_spans = if is_editing {
+                    self.edit_buffer_spans(style)
                 } else {
-                    value_text.to_string()
+                    vec![Span::styled(value_text.to_string(), style)]
                 };
 
                 // Add status indicator
@@ -203,32 +337,54 @@ impl<'a> CommandBuilder<'a> {
                     ""
                 };
 
-                lines.push(Line::from(vec![
+                let mut param_spans = vec![
                     Span::styled(enabled, style),
                     Span::raw(" "),
-                    Span::styled(&param.key, style),
+                    Span::styled(param.key.clone(), style),
                     Span::raw(": "),
-                    Span::styled(value_display, style),
-                    Span::styled(status_indicator, if is_editing { self.theme.editing_style() } else { self.theme.selected_style() }),
-                ]));
+                ];
+                param_spans.extend(value_spans);
+                param_spans.push(Span::styled(status_indicator, if is_editing { self.theme.editing_style() } else { self.theme.selected_style() }));
+                lines.push(Line::from(param_spans));
             }
-            Text::from(lines)
+
+            let (lines, scroll_target) = if self.app.ui_state.fold_disabled {
+                scrollable_list::fold_disabled_rows(&enabled_flags, lines, active_idx, self.theme.help_style())
+            } else {
+                (lines, active_idx)
+            };
+
+            let total = lines.len();
+            let offset = match scroll_target {
+                Some(idx) => scrollable_list::clamp_offset(self.app.ui_state.query_params_scroll_offset, idx, total, inner_height),
+                None => self.app.ui_state.query_params_scroll_offset.min(total.saturating_sub(1)),
+            };
+            let indicator = scrollable_list::position_indicator(offset, total, inner_height).unwrap_or_default();
+
+            (format!("Query Parameters{}", indicator), Text::from(lines), offset)
         };
 
-        let query_paragraph = Paragraph::new(query_text).block(query_block);
+        let query_block = Block::default()
+            .title(query_title)
+            .borders(Borders::ALL)
+            .style(self.theme.border_style());
+
+        let query_paragraph = Paragraph::new(query_text).block(query_block).scroll((scroll as u16, 0));
         frame.render_widget(query_paragraph, area);
     }
 
     /// Render headers tab
     fn render_headers_tab(&self, frame: &mut Frame, area: Rect) {
-        let block = Block::default()
-            .title("Headers")
-            .borders(Borders::ALL)
-            .style(self.theme.border_style());
+        let active_idx = match self.app.ui_state.selected_field {
+            SelectedField::Headers(idx) => Some(idx),
+            _ => None,
+        };
+        let inner_height = Block::default().borders(Borders::ALL).inner(area).height;
 
-        let text = if self.app.current_command.headers.is_empty() {
-            Text::from(vec![Line::from(Span::raw("No headers"))])
+        let (title, text, scroll) = if self.app.current_command.headers.is_empty() {
+            ("Headers".to_string(), Text::from(vec![Line::from(Span::raw("No headers"))]), 0)
         } else {
+            let enabled_flags: Vec<bool> = self.app.current_command.headers.iter().map(|h| h.enabled).collect();
             let mut lines = Vec::new();
             for (idx, header) in self.app.current_command.headers.iter().enumerate() {
                 let enabled = if header.enabled { "✓" } else { "✗" };
@@ -239,8 +395,11 @@ impl<'a> CommandBuilder<'a> {
                     SelectedField::Headers(selected_idx) if selected_idx == idx
                 );
 
-                // Check if we're editing this header
+                // Check if we're editing this header's value, completing its
+                // key, or completing its value (a `Content-Type` MIME type)
                 let is_editing = matches!(&self.app.state, AppState::Editing(EditField::HeaderValue(edit_idx)) if *edit_idx == idx);
+                let is_completing_key = matches!(&self.app.state, AppState::Completing(EditField::HeaderKey(edit_idx)) if *edit_idx == idx);
+                let is_completing_value = matches!(&self.app.state, AppState::Completing(EditField::HeaderValue(edit_idx)) if *edit_idx == idx);
                 let value_text = if is_editing {
                     &self.app.ui_state.edit_buffer
                 } else {
@@ -248,7 +407,7 @@ impl<'a> CommandBuilder<'a> {
                 };
 
                 // Style based on selection and editing state
-                let style = if is_editing {
+                let style = if is_editing || is_completing_key || is_completing_value {
                     self.theme.editing_style()
                 } else if is_selected {
                     self.theme.selected_style()
@@ -257,14 +416,22 @@ impl<'a> CommandBuilder<'a> {
                 };
 
                 // Add visual indicator for editing mode
-                let value_display = if is_editing {
-                    format!("{} █", value_text) // Add cursor indicator
+                let value_spans = if is_editing {
+                    self.edit_buffer_spans(style)
+                } else if is_completing_value {
+                    vec![Span::styled(format!("{} █", self.app.ui_state.edit_buffer), style)]
                 } else {
-                    value_text.to_string()
+                    vec![Span::styled(value_text.to_string(), style)]
+                };
+
+                let key_display = if is_completing_key {
+                    format!("{} █", self.app.ui_state.edit_buffer)
+                } else {
+                    header.key.clone()
                 };
 
                 // Add status indicator
-                let status_indicator = if is_editing {
+                let status_indicator = if is_editing || is_completing_key || is_completing_value {
                     " [EDIT]"
                 } else if is_selected {
                     " [SELECTED]"
@@ -272,19 +439,39 @@ impl<'a> CommandBuilder<'a> {
                     ""
                 };
 
-                lines.push(Line::from(vec![
+                let mut header_spans = vec![
                     Span::styled(enabled, style),
                     Span::raw(" "),
-                    Span::styled(&header.key, style),
+                    Span::styled(key_display, style),
                     Span::raw(": "),
-                    Span::styled(value_display, style),
-                    Span::styled(status_indicator, if is_editing { self.theme.editing_style() } else { self.theme.selected_style() }),
-                ]));
+                ];
+                header_spans.extend(value_spans);
+                header_spans.push(Span::styled(status_indicator, if is_editing || is_completing_key || is_completing_value { self.theme.editing_style() } else { self.theme.selected_style() }));
+                lines.push(Line::from(header_spans));
             }
-            Text::from(lines)
+
+            let (lines, scroll_target) = if self.app.ui_state.fold_disabled {
+                scrollable_list::fold_disabled_rows(&enabled_flags, lines, active_idx, self.theme.help_style())
+            } else {
+                (lines, active_idx)
+            };
+
+            let total = lines.len();
+            let offset = match scroll_target {
+                Some(idx) => scrollable_list::clamp_offset(self.app.ui_state.headers_scroll_offset, idx, total, inner_height),
+                None => self.app.ui_state.headers_scroll_offset.min(total.saturating_sub(1)),
+            };
+            let indicator = scrollable_list::position_indicator(offset, total, inner_height).unwrap_or_default();
+
+            (format!("Headers{}", indicator), Text::from(lines), offset)
         };
 
-        let paragraph = Paragraph::new(text).block(block);
+        let block = Block::default()
+            .title(title)
+            .borders(Borders::ALL)
+            .style(self.theme.border_style());
+
+        let paragraph = Paragraph::new(text).block(block).scroll((scroll as u16, 0));
         frame.render_widget(paragraph, area);
     }
 
@@ -324,19 +511,16 @@ impl<'a> CommandBuilder<'a> {
 
         // Check if we're editing the body
         let text = if is_editing_body {
-            // Add cursor indicator for editing mode
-            let content_with_cursor = format!("{} █", self.app.ui_state.edit_buffer);
-            Text::from(vec![Line::from(Span::styled(content_with_cursor, self.theme.editing_style()))])
+            self.render_body_lines(
+                &self.app.ui_state.body_textarea.lines().to_vec(),
+                Some(self.app.ui_state.body_textarea.cursor()),
+            )
         } else {
             match &self.app.current_command.body {
                 Some(body) => match body {
                     crate::models::command::RequestBody::Raw(content) => {
-                        let style = if is_content_selected {
-                            self.theme.selected_style()
-                        } else {
-                            self.theme.text_style()
-                        };
-                        Text::from(vec![Line::from(Span::styled(content, style))])
+                        let lines: Vec<String> = content.lines().map(|line| line.to_string()).collect();
+                        self.render_body_lines(&lines, None)
                     }
                     crate::models::command::RequestBody::FormData(items) => {
                         let mut lines = Vec::new();
@@ -347,12 +531,18 @@ impl<'a> CommandBuilder<'a> {
                             } else {
                                 self.theme.text_style()
                             };
+                            let value_text = match &item.kind {
+                                crate::models::command::FormFieldKind::Text(value) => value.clone(),
+                                crate::models::command::FormFieldKind::File { path, filename, .. } => {
+                                    format!("@{} (file{})", path.display(), filename.as_deref().map(|f| format!(", as {}", f)).unwrap_or_default())
+                                }
+                            };
                             lines.push(Line::from(vec![
                                 Span::styled(enabled, style),
                                 Span::raw(" "),
                                 Span::styled(&item.key, style),
                                 Span::raw(": "),
-                                Span::styled(&item.value, style),
+                                Span::styled(value_text, style),
                             ]));
                         }
                         Text::from(lines)
@@ -388,20 +578,108 @@ impl<'a> CommandBuilder<'a> {
             }
         };
 
-        let paragraph = Paragraph::new(text).block(block);
+        let scroll_offset = if is_editing_body { self.app.ui_state.body_scroll_offset as u16 } else { 0 };
+        let paragraph = Paragraph::new(text).block(block).scroll((scroll_offset, 0));
         frame.render_widget(paragraph, area);
     }
 
+    /// Tokenize and style each line of body content for syntax
+    /// highlighting, driven by the command's detected `Content-Type`.
+    /// When `cursor` is `Some((row, col))`, splices a cursor indicator into
+    /// that line at the real edit position rather than just appending one.
+    fn render_body_lines(&self, lines: &[String], cursor: Option<(usize, usize)>) -> Text<'static> {
+        let content_type = crate::syntax::detect_content_type(&self.app.current_command.headers);
+
+        if lines.is_empty() {
+            return Text::from(vec![Line::from("")]);
+        }
+
+        let rendered: Vec<Line> = lines
+            .iter()
+            .enumerate()
+            .map(|(row, line)| match cursor {
+                Some((cursor_row, cursor_col)) if cursor_row == row => self.render_body_line_with_cursor(content_type, line, cursor_col),
+                _ => self.render_body_line(content_type, line),
+            })
+            .collect();
+
+        Text::from(rendered)
+    }
+
+    /// Style a single body line's tokens using the theme
+    fn render_body_line(&self, content_type: crate::syntax::ContentType, line: &str) -> Line<'static> {
+        Line::from(self.styled_tokens(content_type, line))
+    }
+
+    /// Style a body line with the edit cursor spliced in at `col`, a
+    /// grapheme-cluster offset (not a byte or `char` offset) into the line,
+    /// the same unit Helix's editor positions its cursor in. Walking by
+    /// grapheme rather than `char` keeps a multi-codepoint cluster (an
+    /// accented letter as base+combining mark, an emoji+modifier sequence)
+    /// under the cursor as one unit instead of splitting it mid-cluster,
+    /// which would misrender or panic once the body contains multibyte
+    /// UTF-8 (very common in JSON payloads).
+    fn render_body_line_with_cursor(&self, content_type: crate::syntax::ContentType, line: &str, col: usize) -> Line<'static> {
+        let (start, end) = match line.grapheme_indices(true).nth(col) {
+            Some((byte_idx, grapheme)) => (byte_idx, byte_idx + grapheme.len()),
+            None => (line.len(), line.len()),
+        };
+        let before = &line[..start];
+        let cursor_grapheme = &line[start..end];
+        let after = &line[end..];
+
+        let mut spans = self.styled_tokens(content_type, before);
+        if cursor_grapheme.is_empty() || cursor_grapheme.width() == 0 {
+            // Past the end of the line, or the grapheme under the cursor has
+            // no on-screen width of its own (a lone combining mark): always
+            // render a one-cell block so the cursor stays visible, keeping
+            // the (invisible) combining mark itself right after it
+            spans.push(Span::styled("█".to_string(), self.theme.editing_style()));
+            spans.extend(self.styled_tokens(content_type, cursor_grapheme));
+        } else {
+            // Highlight the real grapheme in place rather than overwriting
+            // it with a fixed-width block glyph, so a double-width cluster
+            // (CJK, most emoji) occupies its true two screen cells instead
+            // of collapsing the line's horizontal layout by one
+            spans.push(Span::styled(cursor_grapheme.to_string(), self.theme.editing_style()));
+        }
+        spans.extend(self.styled_tokens(content_type, after));
+
+        Line::from(spans)
+    }
+
+    /// Tokenize a line and map each token's `TokenKind` to a theme style
+    fn styled_tokens(&self, content_type: crate::syntax::ContentType, line: &str) -> Vec<Span<'static>> {
+        use crate::syntax::TokenKind;
+
+        crate::syntax::tokenize_line(content_type, line)
+            .into_iter()
+            .map(|token| {
+                let style = match token.kind {
+                    TokenKind::Key => self.theme.header_style(),
+                    TokenKind::String => self.theme.success_style(),
+                    TokenKind::Number => self.theme.warning_style(),
+                    TokenKind::Literal => self.theme.active_style(),
+                    TokenKind::Punctuation => self.theme.text_style(),
+                    TokenKind::Text => self.theme.text_style(),
+                };
+                Span::styled(token.text, style)
+            })
+            .collect()
+    }
+
     /// Render options tab
     fn render_options_tab(&self, frame: &mut Frame, area: Rect) {
-        let block = Block::default()
-            .title("Curl Options")
-            .borders(Borders::ALL)
-            .style(self.theme.border_style());
+        let active_idx = match self.app.ui_state.selected_field {
+            SelectedField::Options(idx) => Some(idx),
+            _ => None,
+        };
+        let inner_height = Block::default().borders(Borders::ALL).inner(area).height;
 
-        let text = if self.app.current_command.options.is_empty() {
-            Text::from(vec![Line::from(Span::raw("No options"))])
+        let (title, text, scroll) = if self.app.current_command.options.is_empty() {
+            ("Curl Options".to_string(), Text::from(vec![Line::from(Span::raw("No options"))]), 0)
         } else {
+            let enabled_flags: Vec<bool> = self.app.current_command.options.iter().map(|o| o.enabled).collect();
             let mut lines = Vec::new();
             for (idx, option) in self.app.current_command.options.iter().enumerate() {
                 let enabled = if option.enabled { "✓" } else { "✗" };
@@ -412,11 +690,15 @@ impl<'a> CommandBuilder<'a> {
                     SelectedField::Options(selected_idx) if selected_idx == idx
                 );
 
-                // Check if we're editing this option
+                // Check if we're editing this option's value, completing its
+                // flag, or completing its value against a known set (e.g.
+                // an HTTP method or enum option)
                 let is_editing = matches!(&self.app.state, AppState::Editing(EditField::OptionValue(edit_idx)) if *edit_idx == idx);
+                let is_completing_flag = matches!(&self.app.state, AppState::Completing(EditField::OptionFlag(edit_idx)) if *edit_idx == idx);
+                let is_completing_value = matches!(&self.app.state, AppState::Completing(EditField::OptionValue(edit_idx)) if *edit_idx == idx);
 
                 // Style based on selection and editing state
-                let style = if is_editing {
+                let style = if is_editing || is_completing_flag || is_completing_value {
                     self.theme.editing_style()
                 } else if is_selected {
                     self.theme.selected_style()
@@ -425,17 +707,26 @@ impl<'a> CommandBuilder<'a> {
                 };
 
                 // Check if we're editing this option
-                let value_display = if is_editing {
-                    format!(": {} █", self.app.ui_state.edit_buffer) // Add cursor indicator
+                let value_spans = if is_editing || is_completing_value {
+                    let mut spans = vec![Span::styled(": ", style)];
+                    spans.extend(self.edit_buffer_spans(style));
+                    spans
                 } else {
-                    match &option.value {
+                    let text = match &option.value {
                         Some(val) => format!(": {}", val),
                         None => String::new(),
-                    }
+                    };
+                    vec![Span::styled(text, style)]
+                };
+
+                let flag_display = if is_completing_flag {
+                    format!("{} █", self.app.ui_state.edit_buffer)
+                } else {
+                    option.flag.clone()
                 };
 
                 // Add status indicator
-                let status_indicator = if is_editing {
+                let status_indicator = if is_editing || is_completing_flag || is_completing_value {
                     " [EDIT]"
                 } else if is_selected {
                     " [SELECTED]"
@@ -443,18 +734,38 @@ impl<'a> CommandBuilder<'a> {
                     ""
                 };
 
-                lines.push(Line::from(vec![
+                let mut option_spans = vec![
                     Span::styled(enabled, style),
                     Span::raw(" "),
-                    Span::styled(&option.flag, style),
-                    Span::styled(value_display, style),
-                    Span::styled(status_indicator, if is_editing { self.theme.editing_style() } else { self.theme.selected_style() }),
-                ]));
+                    Span::styled(flag_display, style),
+                ];
+                option_spans.extend(value_spans);
+                option_spans.push(Span::styled(status_indicator, if is_editing || is_completing_flag || is_completing_value { self.theme.editing_style() } else { self.theme.selected_style() }));
+                lines.push(Line::from(option_spans));
             }
-            Text::from(lines)
+
+            let (lines, scroll_target) = if self.app.ui_state.fold_disabled {
+                scrollable_list::fold_disabled_rows(&enabled_flags, lines, active_idx, self.theme.help_style())
+            } else {
+                (lines, active_idx)
+            };
+
+            let total = lines.len();
+            let offset = match scroll_target {
+                Some(idx) => scrollable_list::clamp_offset(self.app.ui_state.options_scroll_offset, idx, total, inner_height),
+                None => self.app.ui_state.options_scroll_offset.min(total.saturating_sub(1)),
+            };
+            let indicator = scrollable_list::position_indicator(offset, total, inner_height).unwrap_or_default();
+
+            (format!("Curl Options{}", indicator), Text::from(lines), offset)
         };
 
-        let paragraph = Paragraph::new(text).block(block);
+        let block = Block::default()
+            .title(title)
+            .borders(Borders::ALL)
+            .style(self.theme.border_style());
+
+        let paragraph = Paragraph::new(text).block(block).scroll((scroll as u16, 0));
         frame.render_widget(paragraph, area);
     }
 
@@ -538,4 +849,21 @@ impl<'a> CommandBuilder<'a> {
         let dropdown_paragraph = Paragraph::new(dropdown_text).block(dropdown_block);
         frame.render_widget(dropdown_paragraph, area);
     }
+}
+
+/// Rects for each currently visible row of a bordered, single-column list
+/// block (one row per line, scrolled the same way `Paragraph::scroll` is
+/// used in `render_headers_tab`/`render_options_tab`/`render_url_tab`):
+/// row `scroll` lands on the block's first content line, one row per line
+/// down to the area's bottom or `total`, whichever comes first
+fn visible_row_rects(area: Rect, total: usize, scroll: usize) -> Vec<Rect> {
+    let visible_rows = area.height.saturating_sub(2) as usize;
+    (scroll..total.min(scroll + visible_rows))
+        .map(|row| Rect {
+            x: area.x + 1,
+            y: area.y + 1 + (row - scroll) as u16,
+            width: area.width.saturating_sub(2),
+            height: 1,
+        })
+        .collect()
 }
\ No newline at end of file
@@ -1,11 +1,13 @@
 use crate::app::App;
-use crate::command::builder::CommandBuilder;
+use crate::command::generator;
+use crate::ui::highlight;
 use crate::ui::theme::Theme;
 use ratatui::layout::Rect;
-use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span, Text};
 use ratatui::widgets::{Block, Borders, Paragraph, Wrap};
 use ratatui::Frame;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
 
 /// Command display component
 pub struct CommandDisplay<'a> {
@@ -21,150 +23,89 @@ impl<'a> CommandDisplay<'a> {
         Self { app, theme }
     }
 
-    /// Render the command display
-    pub fn render(&self, frame: &mut Frame, area: Rect) {
-        // Generate the curl command
+    /// Narrowest width/shortest height, in columns/rows, of the pane's
+    /// `area` (borders included) at which the preview is still worth
+    /// drawing; below either, the highlighted
+    /// command would wrap or clip into something unreadable, so the pane
+    /// is skipped entirely for this frame
+    const MIN_WIDTH: u16 = 20;
+    const MIN_HEIGHT: u16 = 3;
+
+    /// Render the command display, reusing the last frame's highlighted
+    /// text when nothing that feeds into it has changed, and returning the
+    /// (hash, text) pair so the caller can stash it as that cache for the
+    /// next frame (the same `&self`-only / pending-value pattern
+    /// `CommandBuilder::compute_click_regions` uses, since a `Paragraph`
+    /// render only ever borrows `App` immutably)
+    pub fn render(&self, frame: &mut Frame, area: Rect) -> (u64, Text<'static>) {
         let environment = self.app.environments.get(&self.app.current_environment).unwrap();
-        let command = CommandBuilder::build(&self.app.current_command, environment);
+        let kind = self.app.ui_state.current_generator;
+        let cache_key = self.cache_key(environment, kind);
+
+        let text = match &self.app.ui_state.command_preview_cache {
+            Some((key, cached)) if *key == cache_key => cached.clone(),
+            _ => {
+                // Generate the command in whichever target format is
+                // selected, masking secret variables with `***` unless the
+                // user has pressed the reveal-secrets toggle
+                let command = generator::generate(kind, &self.app.current_command, environment, self.app.ui_state.reveal_secrets);
+                self.highlight_command(&command)
+            }
+        };
 
-        // Create text with syntax highlighting
-        let text = self.highlight_command(&command);
+        if area.width < Self::MIN_WIDTH || area.height < Self::MIN_HEIGHT {
+            return (cache_key, text);
+        }
 
         // Create block
+        let title = if self.app.ui_state.reveal_secrets {
+            format!("Generated Command: {} (secrets revealed)", kind.label())
+        } else {
+            format!("Generated Command: {}", kind.label())
+        };
         let block = Block::default()
-            .title("Generated Command")
+            .title(title)
             .borders(Borders::ALL)
             .style(self.theme.border_style());
 
         // Create paragraph
-        let paragraph = Paragraph::new(text)
+        let paragraph = Paragraph::new(text.clone())
             .block(block)
             .wrap(Wrap { trim: true });
 
         // Render paragraph
         frame.render_widget(paragraph, area);
-    }
 
-    /// Highlight the curl command with syntax highlighting
-    fn highlight_command(&self, command: &str) -> Text<'static> {
-        let mut spans = Vec::new();
-        let mut in_quotes = false;
-        let mut in_option = false;
-        let mut current_word = String::new();
+        (cache_key, text)
+    }
 
-        // Process each character
-        for c in command.chars() {
-            match c {
-                ' ' => {
-                    // End of word
-                    if !current_word.is_empty() {
-                        let style = if in_quotes {
-                            self.theme.text_style()
-                        } else if in_option {
-                            Style::default().fg(self.theme.primary)
-                        } else if current_word == "curl" {
-                            Style::default().fg(self.theme.accent)
-                        } else {
-                            self.theme.text_style()
-                        };
-                        spans.push(Span::styled(current_word.clone(), style));
-                        current_word.clear();
-                    }
-                    spans.push(Span::raw(" "));
-                    in_option = false;
-                }
-                '"' | '\'' => {
-                    // Quote
-                    if !current_word.is_empty() {
-                        let style = if in_quotes {
-                            self.theme.text_style()
-                        } else if in_option {
-                            Style::default().fg(self.theme.primary)
-                        } else {
-                            self.theme.text_style()
-                        };
-                        spans.push(Span::styled(current_word.clone(), style));
-                        current_word.clear();
-                    }
-                    spans.push(Span::styled(
-                        c.to_string(),
-                        Style::default()
-                            .fg(self.theme.secondary)
-                            .add_modifier(Modifier::BOLD),
-                    ));
-                    in_quotes = !in_quotes;
-                }
-                '-' => {
-                    // Option
-                    if !current_word.is_empty() {
-                        let style = if in_quotes {
-                            self.theme.text_style()
-                        } else if in_option {
-                            Style::default().fg(self.theme.primary)
-                        } else {
-                            self.theme.text_style()
-                        };
-                        spans.push(Span::styled(current_word.clone(), style));
-                        current_word.clear();
-                    }
-                    current_word.push(c);
-                    in_option = true;
-                }
-                '\\' => {
-                    // Line continuation
-                    if !current_word.is_empty() {
-                        let style = if in_quotes {
-                            self.theme.text_style()
-                        } else if in_option {
-                            Style::default().fg(self.theme.primary)
-                        } else {
-                            self.theme.text_style()
-                        };
-                        spans.push(Span::styled(current_word.clone(), style));
-                        current_word.clear();
-                    }
-                    spans.push(Span::styled(
-                        c.to_string(),
-                        Style::default().fg(self.theme.secondary),
-                    ));
-                }
-                '\n' => {
-                    // Newline
-                    if !current_word.is_empty() {
-                        let style = if in_quotes {
-                            self.theme.text_style()
-                        } else if in_option {
-                            Style::default().fg(self.theme.primary)
-                        } else {
-                            self.theme.text_style()
-                        };
-                        spans.push(Span::styled(current_word.clone(), style));
-                        current_word.clear();
-                    }
-                    spans.push(Span::raw("\n"));
-                }
-                _ => {
-                    // Other character
-                    current_word.push(c);
-                }
-            }
-        }
+    /// Cheap version stamp for everything the generated command depends
+    /// on: hashing `current_command`'s `Debug` output is far cheaper than
+    /// re-running `generator::generate` plus the tokenizer on every frame,
+    /// and needs no `Hash` impl threaded through `CurlCommand` and its
+    /// nested field types just to support a render-time cache
+    fn cache_key(&self, environment: &crate::models::environment::Environment, kind: generator::GeneratorKind) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        format!("{:?}", self.app.current_command).hash(&mut hasher);
+        format!("{:?}", environment).hash(&mut hasher);
+        format!("{:?}", kind).hash(&mut hasher);
+        self.app.ui_state.reveal_secrets.hash(&mut hasher);
+        hasher.finish()
+    }
 
-        // Add the last word
-        if !current_word.is_empty() {
-            let style = if in_quotes {
-                self.theme.text_style()
-            } else if in_option {
-                Style::default().fg(self.theme.primary)
-            } else if current_word == "curl" {
-                Style::default().fg(self.theme.accent)
-            } else {
-                self.theme.text_style()
-            };
-            spans.push(Span::styled(current_word, style));
-        }
+    /// Highlight the generated command with real syntax highlighting:
+    /// `command` is run through [`highlight::highlight_bash_line`], a
+    /// `syntect`-driven pipeline over a bundled bash grammar and theme, and
+    /// each returned `(text, Style)` run becomes one `Span`. Every
+    /// [`generator::GeneratorKind`] target (curl, wget, HTTPie, PowerShell,
+    /// Python) goes through this same bash grammar, so PowerShell and
+    /// Python output is colored as if it were shell.
+    fn highlight_command(&self, command: &str) -> Text<'static> {
+        let spans: Vec<Span<'static>> = highlight::highlight_bash_line(command)
+            .into_iter()
+            .map(|(text, style)| Span::styled(text, style))
+            .collect();
 
-        // Create text
         Text::from(Line::from(spans))
     }
 }
\ No newline at end of file
@@ -0,0 +1,61 @@
+use crate::app::{App, CompletionCandidate};
+use crate::ui::theme::Theme;
+use ratatui::layout::Rect;
+use ratatui::text::{Line, Span, Text};
+use ratatui::widgets::{Block, Borders, Paragraph};
+use ratatui::Frame;
+
+/// Inline completion popup for a header key or curl flag being typed,
+/// anchored below the field it completes. Modeled on Helix's completion
+/// menu: each row carries the candidate plus a kind tag and a short hint,
+/// e.g. "Authorization  header  bearer/basic/digest".
+pub struct Completion<'a> {
+    /// Application state
+    app: &'a App,
+    /// UI theme
+    theme: &'a Theme,
+}
+
+impl<'a> Completion<'a> {
+    /// Create a new completion popup component
+    pub fn new(app: &'a App, theme: &'a Theme) -> Self {
+        Self { app, theme }
+    }
+
+    /// Render the completion popup
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        let block = Block::default()
+            .title("Completion")
+            .borders(Borders::ALL)
+            .style(self.theme.editing_border_style());
+
+        let text = if self.app.ui_state.completion_results.is_empty() {
+            Text::from(vec![Line::from("No matches")])
+        } else {
+            let lines: Vec<Line> = self
+                .app
+                .ui_state
+                .completion_results
+                .iter()
+                .enumerate()
+                .map(|(idx, candidate)| self.candidate_line(idx, candidate))
+                .collect();
+            Text::from(lines)
+        };
+
+        let paragraph = Paragraph::new(text).block(block);
+        frame.render_widget(paragraph, area);
+    }
+
+    /// A single multi-column row: candidate, kind tag, then hint
+    fn candidate_line(&self, idx: usize, candidate: &CompletionCandidate) -> Line<'static> {
+        let selected = idx == self.app.ui_state.completion_selected;
+        let style = if selected { self.theme.highlight_style() } else { self.theme.text_style() };
+
+        Line::from(vec![
+            Span::styled(format!("{:<24}", candidate.label), style),
+            Span::styled(format!("{:<8}", candidate.kind.label()), self.theme.help_style()),
+            Span::styled(candidate.hint.clone(), self.theme.help_style()),
+        ])
+    }
+}
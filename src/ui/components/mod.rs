@@ -1,13 +1,18 @@
 pub mod command_builder;
 pub mod command_display;
+pub mod completion;
 pub mod options_panel;
 pub mod output_panel;
+pub mod picker;
+pub mod scrollable_list;
 pub mod status_bar;
 pub mod templates_panel;
 
 pub use command_builder::CommandBuilder;
 pub use command_display::CommandDisplay;
+pub use completion::Completion;
 pub use options_panel::OptionsPanel;
 pub use output_panel::OutputPanel;
+pub use picker::Picker;
 pub use status_bar::StatusBar;
 pub use templates_panel::{TemplatesPanel, TemplatesTree};
\ No newline at end of file
@@ -1,11 +1,12 @@
 use crate::app::{App, OptionCategory};
-use crate::command::options::{CurlOptions, OptionTier};
+use crate::command::options::{CurlOptions, OptionDefinition, OptionTier};
 use crate::ui::theme::Theme;
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
 use ratatui::style::Style;
-use ratatui::text::{Line, Span, Text};
-use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Paragraph};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Cell, List, ListItem, ListState, Paragraph, Row, Table, Wrap};
 use ratatui::Frame;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
 /// Options panel component
 pub struct OptionsPanel<'a> {
@@ -32,6 +33,8 @@ impl<'a> OptionsPanel<'a> {
             OptionCategory::Ssl => 5,
             OptionCategory::Proxy => 6,
             OptionCategory::Output => 7,
+            OptionCategory::CommandLine => 8,
+            OptionCategory::Protocol => 9,
         }));
         
         Self {
@@ -56,8 +59,13 @@ impl<'a> OptionsPanel<'a> {
         // Render categories
         self.render_categories(frame, chunks[0]);
 
-        // Render options
-        self.render_options(frame, chunks[1]);
+        // While the user is typing a flag, show completions instead of the
+        // static category listing
+        if !self.app.ui_state.edit_buffer.is_empty() && self.app.ui_state.edit_buffer.starts_with('-') {
+            self.render_completions(frame, chunks[1]);
+        } else {
+            self.render_options(frame, chunks[1]);
+        }
     }
 
     /// Render option categories
@@ -78,6 +86,8 @@ impl<'a> OptionsPanel<'a> {
             ListItem::new("SSL/TLS Options"),
             ListItem::new("Proxy Options"),
             ListItem::new("Output Options"),
+            ListItem::new("Command Line Options"),
+            ListItem::new("Protocol Options"),
         ];
 
         // Create list
@@ -89,7 +99,29 @@ impl<'a> OptionsPanel<'a> {
         frame.render_stateful_widget(list, area, &mut self.category_state);
     }
 
-    /// Render options for the selected category
+    /// Fixed width, in columns, of the Status and Option columns; unlike
+    /// the old hand-padded spans (which counted `text.len()`, i.e. bytes,
+    /// so a multibyte flag or checkmark glyph threw off every column after
+    /// it), these are real `Constraint::Length`s the `Table` enforces
+    /// itself, with `Self::truncate_to_width` picking an ellipsis point by
+    /// display width rather than byte count
+    const STATUS_COLUMN_WIDTH: usize = 3;
+    const OPTION_COLUMN_WIDTH: usize = 22;
+
+    /// Narrowest inner width, in columns, that still leaves both the
+    /// options table and the preview pane usable -- mirrors
+    /// `Picker::MIN_WIDTH_FOR_PREVIEW`; below this the preview is dropped
+    /// and the table takes the full width
+    const MIN_WIDTH_FOR_PREVIEW: u16 = 80;
+
+    /// Render options for the selected category as a `Table` with
+    /// `Constraint`-based column widths (fixed Status/Option, a short
+    /// fixed Value column, and a flexible percentage-width Description)
+    /// instead of hand-padding four columns into a single `Paragraph`.
+    /// When `ui_state.options_grid_preview_visible` is set and `area` is
+    /// wide enough, a side pane shows the highlighted option's full
+    /// detail via a `ratatui` layout split; otherwise the table alone
+    /// takes the full width.
     fn render_options(&self, frame: &mut Frame, area: Rect) {
         // Get selected category
         let selected_category = match self.category_state.selected() {
@@ -101,6 +133,8 @@ impl<'a> OptionsPanel<'a> {
             Some(5) => crate::command::options::OptionCategory::Ssl,
             Some(6) => crate::command::options::OptionCategory::Proxy,
             Some(7) => crate::command::options::OptionCategory::Output,
+            Some(8) => crate::command::options::OptionCategory::CommandLine,
+            Some(9) => crate::command::options::OptionCategory::Protocol,
             _ => crate::command::options::OptionCategory::Basic,
         };
 
@@ -114,78 +148,186 @@ impl<'a> OptionsPanel<'a> {
             &OptionTier::Advanced,
         );
 
+        // Flattened basic+advanced option list, in the same order rendered
+        // below, so `ui_state.options_grid_selected` can index straight
+        // into it for the preview pane
+        let mut sorted_command_line_options: Vec<&OptionDefinition> = basic_options.clone();
+        sorted_command_line_options.extend(advanced_options.iter());
+
+        let show_preview = self.app.ui_state.options_grid_preview_visible && area.width >= Self::MIN_WIDTH_FOR_PREVIEW;
+
+        let table_area = if show_preview {
+            let columns = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Percentage(65), Constraint::Percentage(35)])
+                .split(area);
+            self.render_option_preview(frame, columns[1], sorted_command_line_options.get(self.app.ui_state.options_grid_selected).copied());
+            columns[0]
+        } else {
+            area
+        };
+
         // Create block
         let block = Block::default()
             .title(format!("{}", selected_category))
             .borders(Borders::ALL)
             .style(self.theme.border_style());
 
-        // Create text
-        let mut lines = Vec::new();
-
-        // Add basic options
-        lines.push(Line::from(vec![
-            Span::styled("Basic Options", self.theme.header_style()),
-        ]));
-        lines.push(Line::from(""));
-
-        for option in &basic_options {
-            // Check if option is enabled in the current command
-            let enabled = self.app.current_command.options.iter()
-                .any(|o| o.flag == option.flag && o.enabled);
-            
-            let checkbox = if enabled { "✓" } else { "☐" };
-            let value_text = if option.takes_value {
-                " <value>"
-            } else {
-                ""
-            };
-            
-            lines.push(Line::from(vec![
-                Span::styled(checkbox, self.theme.text_style()),
-                Span::raw(" "),
-                Span::styled(&option.flag, Style::default().fg(self.theme.primary)),
-                Span::styled(value_text, Style::default().fg(self.theme.secondary)),
-                Span::raw(" - "),
-                Span::styled(&option.description, self.theme.text_style()),
-            ]));
-        }
+        let mut rows = vec![self.header_row()];
+
+        rows.push(self.section_row("Basic Options"));
+        rows.extend(basic_options.iter().map(|option| self.option_row(option)));
 
-        // Add advanced options
         if !advanced_options.is_empty() {
-            lines.push(Line::from(""));
-            lines.push(Line::from(vec![
-                Span::styled("Advanced Options", self.theme.header_style()),
-            ]));
-            lines.push(Line::from(""));
-
-            for option in &advanced_options {
-                // Check if option is enabled in the current command
-                let enabled = self.app.current_command.options.iter()
-                    .any(|o| o.flag == option.flag && o.enabled);
-                
-                let checkbox = if enabled { "✓" } else { "☐" };
-                let value_text = if option.takes_value {
-                    " <value>"
-                } else {
-                    ""
-                };
-                
-                lines.push(Line::from(vec![
-                    Span::styled(checkbox, self.theme.text_style()),
-                    Span::raw(" "),
-                    Span::styled(&option.flag, Style::default().fg(self.theme.primary)),
-                    Span::styled(value_text, Style::default().fg(self.theme.secondary)),
-                    Span::raw(" - "),
-                    Span::styled(&option.description, self.theme.text_style()),
-                ]));
-            }
+            rows.push(self.section_row("Advanced Options"));
+            rows.extend(advanced_options.iter().map(|option| self.option_row(option)));
         }
 
-        // Create paragraph
-        let paragraph = Paragraph::new(Text::from(lines)).block(block);
+        let widths = [
+            Constraint::Length(Self::STATUS_COLUMN_WIDTH as u16),
+            Constraint::Length(Self::OPTION_COLUMN_WIDTH as u16),
+            Constraint::Length(10),
+            Constraint::Percentage(100),
+        ];
+
+        let table = Table::new(rows).widths(&widths).block(block).column_spacing(1);
+
+        frame.render_widget(table, table_area);
+    }
+
+    /// Render the side preview pane for the option the grid's selected row
+    /// points at: its full (untruncated) description, whether it takes a
+    /// value, an example invocation, and the fragment it actually
+    /// assembles to given `current_command.options` -- the same
+    /// already-added/value-aware logic `render_picker_item_preview` uses
+    /// for the picker overlay's preview
+    fn render_option_preview(&self, frame: &mut Frame, area: Rect, option: Option<&OptionDefinition>) {
+        let block = Block::default()
+            .title("Preview")
+            .borders(Borders::ALL)
+            .style(self.theme.border_style());
+
+        let Some(option) = option else {
+            frame.render_widget(Paragraph::new("No option selected").block(block), area);
+            return;
+        };
+
+        let label = option.long_flag.as_deref().unwrap_or(&option.flag);
+        let already_added = self.app.current_command.options.iter().find(|o| o.flag == option.flag);
+        let fragment = match (already_added, option.takes_value) {
+            (Some(existing), true) => format!("curl {} {} ...", option.flag, existing.value.as_deref().unwrap_or("<value>")),
+            (Some(_), false) | (None, false) => format!("curl {} ...", option.flag),
+            (None, true) => format!("curl {} <value> ...", option.flag),
+        };
+
+        let lines = vec![
+            Line::from(Span::styled(label.to_string(), Style::default().fg(self.theme.primary))),
+            Line::from(""),
+            Line::from(option.description.clone()),
+            Line::from(""),
+            Line::from(format!("Takes a value: {}", if option.takes_value { "yes" } else { "no" })),
+            Line::from(""),
+            Line::from(if already_added.is_some() { "Already in command:" } else { "If enabled:" }),
+            Line::from(fragment),
+        ];
 
-        // Render paragraph
+        let paragraph = Paragraph::new(lines).block(block).wrap(Wrap { trim: false });
         frame.render_widget(paragraph, area);
     }
+
+    /// The header row naming each column
+    fn header_row(&self) -> Row<'static> {
+        Row::new(vec![
+            Cell::from(""),
+            Cell::from(Span::styled("Option", self.theme.header_style())),
+            Cell::from(Span::styled("Value", self.theme.header_style())),
+            Cell::from(Span::styled("Description", self.theme.header_style())),
+        ])
+    }
+
+    /// A full-width section divider row (e.g. "Basic Options")
+    fn section_row(&self, title: &str) -> Row<'static> {
+        Row::new(vec![
+            Cell::from(""),
+            Cell::from(Span::styled(title.to_string(), self.theme.header_style())),
+            Cell::from(""),
+            Cell::from(""),
+        ])
+    }
+
+    /// One option's row: enabled checkbox, flag (truncated to
+    /// `OPTION_COLUMN_WIDTH` by display width, not byte length), whether it
+    /// takes a value, and its description
+    fn option_row(&self, option: &OptionDefinition) -> Row<'static> {
+        let enabled = self.app.current_command.options.iter().any(|o| o.flag == option.flag && o.enabled);
+        let checkbox = if enabled { "✓" } else { "☐" };
+        let value_text = if option.takes_value { "<value>" } else { "" };
+
+        Row::new(vec![
+            Cell::from(Span::styled(checkbox, self.theme.text_style())),
+            Cell::from(Span::styled(
+                Self::truncate_to_width(&option.flag, Self::OPTION_COLUMN_WIDTH),
+                Style::default().fg(self.theme.primary),
+            )),
+            Cell::from(Span::styled(value_text, Style::default().fg(self.theme.secondary))),
+            Cell::from(Span::styled(option.description.clone(), self.theme.text_style())),
+        ])
+    }
+
+    /// Truncate `text` to at most `max_width` display columns (as measured
+    /// by `UnicodeWidthStr`, not `str::len`'s byte count), appending an
+    /// ellipsis when it had to cut
+    fn truncate_to_width(text: &str, max_width: usize) -> String {
+        if text.width() <= max_width {
+            return text.to_string();
+        }
+
+        let budget = max_width.saturating_sub(1); // room for the ellipsis
+        let mut truncated = String::new();
+        let mut width = 0;
+        for ch in text.chars() {
+            let ch_width = ch.width().unwrap_or(0);
+            if width + ch_width > budget {
+                break;
+            }
+            truncated.push(ch);
+            width += ch_width;
+        }
+        truncated.push('…');
+        truncated
+    }
+
+    /// Render flag completions for the partial flag currently in the edit
+    /// buffer, e.g. typing "--loc" suggests "--location"
+    fn render_completions(&self, frame: &mut Frame, area: Rect) {
+        let partial = &self.app.ui_state.edit_buffer;
+        let matches = self.options.complete(partial);
+
+        let block = Block::default()
+            .title("Completions")
+            .borders(Borders::ALL)
+            .style(self.theme.border_style());
+
+        let items: Vec<ListItem> = if matches.is_empty() {
+            vec![ListItem::new("No matches")]
+        } else {
+            matches
+                .iter()
+                .map(|def| {
+                    let label = match &def.long_flag {
+                        Some(long) => format!("{} ({})", def.flag, long),
+                        None => def.flag.clone(),
+                    };
+                    ListItem::new(Line::from(vec![
+                        Span::styled(label, Style::default().fg(self.theme.primary)),
+                        Span::raw(" - "),
+                        Span::styled(&def.description, self.theme.text_style()),
+                    ]))
+                })
+                .collect()
+        };
+
+        let list = List::new(items).block(block);
+        frame.render_widget(list, area);
+    }
 }
\ No newline at end of file
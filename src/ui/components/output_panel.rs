@@ -1,10 +1,13 @@
 use crate::app::App;
+use crate::execution::output::{OutputFormat, OutputParser};
+use crate::ui::highlight;
 use crate::ui::theme::Theme;
 use ratatui::layout::Rect;
-use ratatui::style::Style;
+use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span, Text};
 use ratatui::widgets::{Block, Borders, Paragraph, Wrap};
 use ratatui::Frame;
+use syntect::easy::HighlightLines;
 
 /// Output panel component
 pub struct OutputPanel<'a> {
@@ -22,90 +25,202 @@ impl<'a> OutputPanel<'a> {
 
     /// Render the output panel
     pub fn render(&self, frame: &mut Frame, area: Rect) {
+        let format = self.app.ui_state.output_format;
+        let title = match format {
+            OutputFormat::Formatted => match self.app.ui_state.output_view_mode.label() {
+                Some(label) => format!("Output [{}]", label),
+                None => "Output".to_string(),
+            },
+            OutputFormat::Raw => "Output (Raw)".to_string(),
+            OutputFormat::Json => "Output (JSON)".to_string(),
+            OutputFormat::Pretty => "Output (Pretty)".to_string(),
+        };
+
         // Create block
         let block = Block::default()
-            .title("Output")
+            .title(title)
             .borders(Borders::ALL)
             .style(self.theme.border_style());
 
         // Create text
-        let text = match &self.app.output {
-            Some(output) => self.format_output(output),
-            None => Text::from("No output"),
+        let text = match format {
+            OutputFormat::Formatted => match &self.app.output {
+                Some(output) => self.format_output(output),
+                None => Text::from("No output"),
+            },
+            OutputFormat::Raw | OutputFormat::Json | OutputFormat::Pretty => match &self.app.execution_result {
+                Some(result) => {
+                    let info = OutputParser::parse(result);
+                    Text::from(OutputParser::format_response(&info, format))
+                }
+                None => Text::from("No output"),
+            },
         };
 
         // Create paragraph
         let paragraph = Paragraph::new(text)
             .block(block)
-            .wrap(Wrap { trim: false });
+            .wrap(Wrap { trim: false })
+            .scroll((self.app.ui_state.output_scroll_offset, 0));
 
         // Render paragraph
         frame.render_widget(paragraph, area);
     }
 
-    /// Format the output with syntax highlighting
-    fn format_output<'b>(&self, output: &'b str) -> Text<'b> {
-        let mut lines = Vec::new();
-        let mut in_headers = true;
+    /// Above this many bytes, the body is shown as plain unstyled lines
+    /// instead of being highlighted -- highlighting re-runs on every
+    /// render, so a huge body would otherwise re-highlight megabytes of
+    /// text per frame for no visual benefit past what fits on screen
+    const MAX_HIGHLIGHT_BYTES: usize = 200_000;
+
+    /// Format the output with syntax highlighting: the status line and
+    /// headers get their existing flat coloring, and the body is driven
+    /// through `syntect`'s [`HighlightLines`] via [`highlight::body_highlighter`]
+    /// / [`highlight::highlight_line`] (keyed off the response's
+    /// `Content-Type` header, falling back to sniffing the body's first
+    /// character -- both done by [`crate::syntax::display_output`], not by
+    /// the highlighter) so JSON/XML bodies get real per-token coloring
+    /// instead of a flat gradient. The same highlighter instance is reused
+    /// across every body line so multi-line constructs keep correct parser
+    /// state. When `ui_state.output_pretty_print` is set and the body is
+    /// JSON, it's reflowed into indented multi-line form via
+    /// [`crate::syntax::pretty_print`] before highlighting, rather than
+    /// highlighting the server's exact (possibly minified) bytes. Lines
+    /// matching `ui_state.output_search_query` get their matching
+    /// substrings highlighted in place of their usual styling.
+    ///
+    /// `ui_state.output_view_mode` narrows which lines are emitted at all:
+    /// `HeadersOnly` stops after the blank separator, `BodyOnly` skips
+    /// everything up to and including it, and `All` emits every line.
+    fn format_output(&self, output: &str) -> Text<'static> {
+        use crate::app::OutputViewMode;
+
+        let displayed = crate::syntax::display_output(output, self.app.ui_state.output_pretty_print);
+        let body_start = displayed.header_end.map(|end| end + 1).unwrap_or(0);
+        let body_too_large = displayed.lines[body_start..].iter().map(|line| line.len() + 1).sum::<usize>() > Self::MAX_HIGHLIGHT_BYTES;
+        let query = self.app.ui_state.output_search_query.as_str();
+        let view_mode = self.app.ui_state.output_view_mode;
 
-        for line in output.lines() {
-            // Check if we're transitioning from headers to body
-            if in_headers && line.is_empty() {
-                in_headers = false;
-                lines.push(Line::from(""));
+        let mut body_highlighter = if body_too_large { None } else { highlight::body_highlighter(displayed.content_type) };
+
+        let mut lines = Vec::with_capacity(displayed.lines.len());
+        for (idx, line) in displayed.lines.iter().enumerate() {
+            let is_header = displayed.header_end.map_or(true, |end| idx < end);
+            let is_separator = displayed.header_end == Some(idx);
+
+            if view_mode == OutputViewMode::BodyOnly && (is_header || is_separator) {
                 continue;
             }
 
-            if in_headers {
-                // Format header line
-                if line.starts_with("HTTP/") {
-                    // Status line
-                    let parts: Vec<&str> = line.splitn(3, ' ').collect();
-                    if parts.len() >= 3 {
-                        let status_code = parts[1];
-                        let status_style = match status_code.chars().next() {
-                            Some('2') => self.theme.success_style(),
-                            Some('3') => self.theme.warning_style(),
-                            Some('4') | Some('5') => self.theme.error_style(),
-                            _ => self.theme.text_style(),
-                        };
-
-                        lines.push(Line::from(vec![
-                            Span::styled(parts[0], Style::default().fg(self.theme.primary)),
-                            Span::raw(" "),
-                            Span::styled(status_code, status_style),
-                            Span::raw(" "),
-                            Span::styled(parts[2], self.theme.text_style()),
-                        ]));
-                    } else {
-                        lines.push(Line::from(Span::raw(line)));
-                    }
-                } else if let Some(colon_pos) = line.find(':') {
-                    // Header line
-                    let (key, value) = line.split_at(colon_pos + 1);
-                    lines.push(Line::from(vec![
-                        Span::styled(key, Style::default().fg(self.theme.secondary)),
-                        Span::styled(value, self.theme.text_style()),
-                    ]));
-                } else {
-                    // Unknown header line
-                    lines.push(Line::from(Span::raw(line)));
-                }
+            let spans = if is_separator {
+                vec![]
+            } else if !query.is_empty() && line.to_lowercase().contains(&query.to_lowercase()) {
+                self.highlight_matches(line, query)
+            } else if is_header {
+                self.styled_header_line(line)
+            } else if body_too_large {
+                vec![Span::styled(line.clone(), self.theme.text_style())]
             } else {
-                // Format body line
-                if line.trim().starts_with('{') || line.trim().starts_with('[') {
-                    // JSON content
-                    lines.push(Line::from(Span::styled(line, self.theme.text_style())));
-                } else if line.trim().starts_with('<') {
-                    // XML/HTML content
-                    lines.push(Line::from(Span::styled(line, self.theme.text_style())));
-                } else {
-                    // Plain text
-                    lines.push(Line::from(Span::raw(line)));
-                }
+                self.styled_body_line(&mut body_highlighter, line)
+            };
+
+            lines.push(Line::from(spans));
+
+            if view_mode == OutputViewMode::HeadersOnly && is_separator {
+                break;
             }
         }
 
         Text::from(lines)
     }
+
+    /// Style a single header/preamble line: the `HTTP/…` status line gets
+    /// its status code colored by class, a `key: value` line gets its key
+    /// highlighted, anything else is shown as-is
+    fn styled_header_line(&self, line: &str) -> Vec<Span<'static>> {
+        if line.starts_with("HTTP/") {
+            let parts: Vec<&str> = line.splitn(3, ' ').collect();
+            if parts.len() >= 3 {
+                let status_code = parts[1];
+                let status_style = match status_code.chars().next() {
+                    Some('2') => self.theme.success_style(),
+                    Some('3') => self.theme.warning_style(),
+                    Some('4') | Some('5') => self.theme.error_style(),
+                    _ => self.theme.text_style(),
+                };
+
+                return vec![
+                    Span::styled(parts[0].to_string(), Style::default().fg(self.theme.primary)),
+                    Span::raw(" "),
+                    Span::styled(status_code.to_string(), status_style),
+                    Span::raw(" "),
+                    Span::styled(parts[2].to_string(), self.theme.text_style()),
+                ];
+            }
+            return vec![Span::raw(line.to_string())];
+        }
+
+        if let Some(colon_pos) = line.find(':') {
+            let (key, value) = line.split_at(colon_pos + 1);
+            let value_text = if Self::is_url_bearing_header(key) && !value.trim().is_empty() {
+                crate::ui::caps::hyperlink(value.trim(), value)
+            } else {
+                value.to_string()
+            };
+            return vec![
+                Span::styled(key.to_string(), Style::default().fg(self.theme.secondary)),
+                Span::styled(value_text, self.theme.text_style()),
+            ];
+        }
+
+        vec![Span::raw(line.to_string())]
+    }
+
+    /// Whether a header's value is a clickable URL worth wrapping in an
+    /// OSC 8 hyperlink escape -- the redirect/content-location headers a
+    /// user would actually want to open
+    fn is_url_bearing_header(key: &str) -> bool {
+        matches!(key.trim_end_matches(':').to_lowercase().as_str(), "location" | "link" | "content-location")
+    }
+
+    /// Highlight one body line via the shared `highlighter` (from
+    /// [`highlight::body_highlighter`]). Falls back to the theme's flat
+    /// text style when there's no highlighter -- the content type has no
+    /// bundled `syntect` grammar (e.g. form-urlencoded, plain text).
+    fn styled_body_line(&self, highlighter: &mut Option<HighlightLines<'static>>, line: &str) -> Vec<Span<'static>> {
+        match highlighter {
+            Some(highlighter) => highlight::highlight_line(highlighter, line)
+                .into_iter()
+                .map(|(text, style)| Span::styled(text, style))
+                .collect(),
+            None => vec![Span::styled(line.to_string(), self.theme.text_style())],
+        }
+    }
+
+    /// Split `line` around each case-insensitive occurrence of `query`,
+    /// styling the matches with a reversed warning style so they stand out
+    /// against the surrounding plain text
+    fn highlight_matches(&self, line: &str, query: &str) -> Vec<Span<'static>> {
+        let match_style = self.theme.warning_style().add_modifier(Modifier::REVERSED);
+        let lower_query = query.to_lowercase();
+        let lower_line = line.to_lowercase();
+
+        let mut spans = Vec::new();
+        let mut rest = line;
+        let mut lower_rest = lower_line.as_str();
+
+        while let Some(pos) = lower_rest.find(&lower_query) {
+            if pos > 0 {
+                spans.push(Span::styled(rest[..pos].to_string(), self.theme.text_style()));
+            }
+            spans.push(Span::styled(rest[pos..pos + lower_query.len()].to_string(), match_style));
+            rest = &rest[pos + lower_query.len()..];
+            lower_rest = &lower_rest[pos + lower_query.len()..];
+        }
+        if !rest.is_empty() {
+            spans.push(Span::styled(rest.to_string(), self.theme.text_style()));
+        }
+
+        spans
+    }
 }
\ No newline at end of file
@@ -0,0 +1,142 @@
+use crate::app::{App, PickerItem};
+use crate::ui::theme::Theme;
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span, Text};
+use ratatui::widgets::{Block, Borders, Paragraph};
+use ratatui::Frame;
+
+/// Fuzzy picker overlay for standard headers, curl flags, and saved
+/// requests: a filtered list on the left, and a live preview of the
+/// highlighted candidate on the right. Modeled on Helix's `FilePicker`,
+/// which pairs a fuzzy-filtered list with a preview computed from the
+/// highlighted item and cached so it isn't recomputed on every keystroke
+/// (the cache itself lives on `App::ui_state.picker_preview`).
+pub struct Picker<'a> {
+    /// Application state
+    app: &'a App,
+    /// UI theme
+    theme: &'a Theme,
+}
+
+impl<'a> Picker<'a> {
+    /// Create a new picker component
+    pub fn new(app: &'a App, theme: &'a Theme) -> Self {
+        Self { app, theme }
+    }
+
+    /// Narrowest inner width, in columns, that still leaves both the
+    /// result list and the preview pane usable; below this the preview is
+    /// dropped and the list takes the full width
+    const MIN_WIDTH_FOR_PREVIEW: u16 = 60;
+
+    /// Render the picker overlay: query + results on the left, preview on
+    /// the right, unless `area` is too narrow to split
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        let block = Block::default()
+            .title("Picker (headers, flags, saved requests)")
+            .borders(Borders::ALL)
+            .style(self.theme.border_style());
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        if inner.width < Self::MIN_WIDTH_FOR_PREVIEW {
+            self.render_results(frame, inner);
+            return;
+        }
+
+        let columns = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(inner);
+
+        self.render_results(frame, columns[0]);
+        self.render_preview(frame, columns[1]);
+    }
+
+    /// Render the query line followed by ranked results
+    fn render_results(&self, frame: &mut Frame, area: Rect) {
+        let mut lines = vec![
+            Line::from(vec![
+                Span::styled("> ", self.theme.header_style()),
+                Span::styled(&self.app.ui_state.picker_query, self.theme.text_style()),
+                Span::raw(" █"),
+            ]),
+            Line::from(""),
+        ];
+
+        if self.app.ui_state.picker_results.is_empty() {
+            lines.push(Line::from("No matches"));
+        } else {
+            for (idx, item) in self.app.ui_state.picker_results.iter().enumerate() {
+                let selected = idx == self.app.ui_state.picker_selected;
+                let style = if selected { self.theme.selected_style() } else { self.theme.text_style() };
+                lines.push(Line::from(self.highlighted_label(item, style)));
+            }
+        }
+
+        let paragraph = Paragraph::new(Text::from(lines))
+            .block(Block::default().borders(Borders::RIGHT).style(self.theme.border_style()));
+        frame.render_widget(paragraph, area);
+    }
+
+    /// Render the cached preview for the currently highlighted item
+    fn render_preview(&self, frame: &mut Frame, area: Rect) {
+        let text = match &self.app.ui_state.picker_preview {
+            Some(preview) => {
+                let lines: Vec<Line> = preview
+                    .lines
+                    .iter()
+                    .map(|line| Line::from(Span::styled(line.clone(), self.theme.text_style())))
+                    .collect();
+                Text::from(lines)
+            }
+            None => Text::from("No preview"),
+        };
+
+        let paragraph = Paragraph::new(text)
+            .block(Block::default().title("Preview").borders(Borders::ALL).style(self.theme.border_style()));
+        frame.render_widget(paragraph, area);
+    }
+
+    /// Split a picker item's row into its "[kind] " prefix and the bare
+    /// label that was fuzzy-matched against in `App::update_picker_results`
+    fn split_label(&self, item: &PickerItem) -> (&'static str, String) {
+        match item {
+            PickerItem::Header(idx) => (
+                "[header] ",
+                crate::headers::STANDARD_HEADERS.get(*idx).map(|header| header.name.to_string()).unwrap_or_else(|| "?".to_string()),
+            ),
+            PickerItem::Option(flag) => (
+                "[flag] ",
+                crate::command::options::CurlOptions::new()
+                    .get_option(flag)
+                    .map(|option| option.long_flag.clone().unwrap_or_else(|| option.flag.clone()))
+                    .unwrap_or_else(|| flag.clone()),
+            ),
+            PickerItem::SavedRequest(idx) => (
+                "[saved] ",
+                self.app.templates.get(*idx).map(|template| template.name.clone()).unwrap_or_else(|| "?".to_string()),
+            ),
+        }
+    }
+
+    /// Build one result row's spans: the "[kind] " prefix in `style`, then
+    /// the label with each character the fuzzy query matched picked out in
+    /// a distinct style, re-scoring against the label here since ranking
+    /// only keeps the winning item, not its match positions
+    fn highlighted_label(&self, item: &PickerItem, style: Style) -> Vec<Span<'static>> {
+        let (prefix, label) = self.split_label(item);
+        let match_style = Style::default().fg(self.theme.accent).add_modifier(Modifier::BOLD);
+        let positions = crate::fuzzy::score_subsequence(&self.app.ui_state.picker_query, &label)
+            .map(|matched| matched.positions)
+            .unwrap_or_default();
+
+        let mut spans = vec![Span::styled(prefix, style)];
+        for (idx, ch) in label.chars().enumerate() {
+            let char_style = if positions.contains(&idx) { match_style } else { style };
+            spans.push(Span::styled(ch.to_string(), char_style));
+        }
+        spans
+    }
+}
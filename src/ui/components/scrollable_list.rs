@@ -0,0 +1,141 @@
+//! Shared viewport-scrolling and folding helpers for the headers,
+//! query-param, and options tab lists in `command_builder`. Each tab keeps
+//! building its own styled `Line` per row (editing/completion/selection
+//! logic differs too much between them to share), then calls through here
+//! to keep the selected row in view and collapse disabled entries -- the
+//! same idea as an editor translating the cursor into a visible line range
+//! before rendering.
+
+use ratatui::style::Style;
+use ratatui::text::Line;
+
+/// Rows of padding kept above/below the selected row, the way Helix's
+/// `scrolloff` setting keeps the cursor off the very edge of the viewport,
+/// so the selection doesn't end up glued to the top or bottom border with
+/// no surrounding context
+const SCROLLOFF: usize = 2;
+
+/// Clamp `offset` so row `selected` stays within the visible rows, with at
+/// least [`SCROLLOFF`] rows of padding above and below it whenever the list
+/// is long enough to afford that (the padding shrinks near either end of
+/// the list, where there simply aren't that many rows to show). `inner_height`
+/// should be the block's *inner* height (e.g. `block.inner(area).height`),
+/// not the outer `area.height`, so callers with non-default borders still
+/// get the right usable row count.
+pub fn clamp_offset(offset: usize, selected: usize, total: usize, inner_height: u16) -> usize {
+    let visible_rows = (inner_height as usize).max(1);
+    let scrolloff = SCROLLOFF.min(visible_rows.saturating_sub(1) / 2);
+
+    let max_offset = total.saturating_sub(visible_rows);
+    // No more than this, or there wouldn't be `scrolloff` rows visible above `selected`
+    let highest_allowed = selected.saturating_sub(scrolloff);
+    // No less than this, or there wouldn't be `scrolloff` rows visible below `selected`
+    let lowest_allowed = (selected + scrolloff + 1).saturating_sub(visible_rows);
+
+    offset.min(highest_allowed).max(lowest_allowed).min(max_offset)
+}
+
+/// Collapse the rows whose `enabled` flag is `false` into a single
+/// "Disabled (n)" summary line, unless `active` points at one of them (so
+/// an item currently selected or being edited is never hidden). Returns
+/// the collapsed rows plus `active`'s row index within them, so the caller
+/// can still scroll to keep the active row in view after folding.
+pub fn fold_disabled_rows(
+    enabled: &[bool],
+    lines: Vec<Line<'static>>,
+    active: Option<usize>,
+    disabled_style: Style,
+) -> (Vec<Line<'static>>, Option<usize>) {
+    let disabled_count = enabled.iter().filter(|e| !**e).count();
+    if disabled_count == 0 {
+        return (lines, active);
+    }
+
+    let mut result = Vec::with_capacity(lines.len());
+    let mut active_row = None;
+    let mut folded_summary_pushed = false;
+
+    for (idx, line) in lines.into_iter().enumerate() {
+        let is_enabled = enabled.get(idx).copied().unwrap_or(true);
+        let is_active = active == Some(idx);
+
+        if is_enabled || is_active {
+            if is_active {
+                active_row = Some(result.len());
+            }
+            result.push(line);
+        } else if !folded_summary_pushed {
+            result.push(Line::styled(format!("▸ Disabled ({})", disabled_count), disabled_style));
+            folded_summary_pushed = true;
+        }
+    }
+
+    (result, active_row)
+}
+
+/// A `"(offset+1/total)"` position indicator for a block title, shown only
+/// once the list overflows the visible area, with a "↑"/"↓" appended
+/// whenever rows are actually scrolled off above/below -- so, unlike a bare
+/// fraction, the title signals *which* direction has more content, not just
+/// that the list is long. `inner_height` should be the same block-inner
+/// height passed to [`clamp_offset`].
+pub fn position_indicator(offset: usize, total: usize, inner_height: u16) -> Option<String> {
+    let visible_rows = (inner_height as usize).max(1);
+    if total <= visible_rows {
+        return None;
+    }
+
+    let more_above = if offset > 0 { "↑" } else { "" };
+    let more_below = if offset + visible_rows < total { "↓" } else { "" };
+    Some(format!(" ({}/{}){}{}", offset + 1, total, more_above, more_below))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clamp_offset_keeps_scrolloff_padding_in_the_middle_of_a_long_list() {
+        // 10 rows total, 5 visible, selecting row 5 from an offset that
+        // would otherwise put it flush against the bottom border
+        let offset = clamp_offset(3, 5, 10, 5);
+        // With SCROLLOFF = 2, row 5 needs at least 2 rows visible below it,
+        // so the offset must be at least 5 + 2 + 1 - 5 = 3, i.e. still leaves
+        // rows 6 and 7 on screen beneath it
+        assert!(offset <= 3);
+        assert!(5 - offset >= 2);
+        assert!((offset + 5 - 1) - 5 >= 2);
+    }
+
+    #[test]
+    fn test_clamp_offset_does_not_scroll_past_the_top_of_the_list() {
+        assert_eq!(clamp_offset(0, 0, 10, 5), 0);
+        assert_eq!(clamp_offset(0, 1, 10, 5), 0);
+    }
+
+    #[test]
+    fn test_clamp_offset_does_not_scroll_past_the_bottom_of_the_list() {
+        // Selecting the last row should pin the offset at the list's end,
+        // even though that leaves fewer than SCROLLOFF rows below it
+        assert_eq!(clamp_offset(0, 9, 10, 5), 5);
+    }
+
+    #[test]
+    fn test_clamp_offset_is_a_no_op_when_the_whole_list_already_fits() {
+        assert_eq!(clamp_offset(0, 2, 4, 5), 0);
+    }
+
+    #[test]
+    fn test_position_indicator_only_shows_once_the_list_overflows() {
+        assert_eq!(position_indicator(0, 3, 5), None);
+        assert_eq!(position_indicator(1, 10, 5), Some(" (2/10)↑↓".to_string()));
+    }
+
+    #[test]
+    fn test_position_indicator_only_shows_the_arrow_for_the_scrolled_off_direction() {
+        // Nothing above, more below
+        assert_eq!(position_indicator(0, 10, 5), Some(" (1/10)↓".to_string()));
+        // More above, nothing below (scrolled all the way to the end)
+        assert_eq!(position_indicator(5, 10, 5), Some(" (6/10)↑".to_string()));
+    }
+}
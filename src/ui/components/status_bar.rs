@@ -1,23 +1,245 @@
 use crate::app::{App, AppState, Tab};
+use crate::models::environment::VariableSource;
 use crate::ui::theme::Theme;
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
-    style::{Color, Modifier, Style},
-    text::{Line, Span, Text},
-    widgets::{Block, Borders, Paragraph},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Wrap},
     Frame,
 };
 
+/// A line's worth of styled spans produced by a single status bar segment
+pub type Spans = Vec<Span<'static>>;
+
+/// Everything a segment needs to render itself, decoupled from the `Frame`
+/// so segments can be unit-tested without a real terminal surface
+pub struct RenderContext<'a> {
+    pub app: &'a App,
+    pub theme: &'a Theme,
+}
+
+/// One independent piece of status bar information (app state, the active
+/// tab, the environment name, ...). Each segment builds its own spans;
+/// a separate pass (`StatusBar::render_region`) joins them and writes them
+/// to the screen, so adding a new indicator (latency, response size, auth
+/// status) is a matter of adding one `SegmentId` variant rather than
+/// editing a monolithic render method.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum SegmentId {
+    /// The current `AppState` (Normal, Editing, ...)
+    AppState,
+    /// The active tab (URL, Headers, Body, Options)
+    Tab,
+    /// The most recent validation error, if any
+    StatusMessage,
+    /// The currently selected environment's name
+    Environment,
+    /// The current command's HTTP method
+    Method,
+    /// Whether a URL has been entered
+    UrlStatus,
+    /// The outcome of the last execution, if any
+    ExecutionResult,
+    /// Context-sensitive keyboard shortcuts
+    Shortcuts,
+}
+
+impl SegmentId {
+    /// Build this segment's spans for the given context; an empty vec
+    /// means the segment has nothing to show right now (e.g. no status
+    /// message) and is skipped entirely, including its separator
+    fn render(&self, ctx: &RenderContext) -> Spans {
+        match self {
+            SegmentId::AppState => render_app_state(ctx),
+            SegmentId::Tab => render_tab(ctx),
+            SegmentId::StatusMessage => render_status_message(ctx),
+            SegmentId::Environment => render_environment(ctx),
+            SegmentId::Method => render_method(ctx),
+            SegmentId::UrlStatus => render_url_status(ctx),
+            SegmentId::ExecutionResult => render_execution_result(ctx),
+            SegmentId::Shortcuts => render_shortcuts(ctx),
+        }
+    }
+}
+
+fn render_app_state(ctx: &RenderContext) -> Spans {
+    vec![
+        Span::styled("State: ", Style::default().fg(ctx.theme.secondary)),
+        Span::styled(
+            get_state_text(ctx.app),
+            Style::default().fg(ctx.theme.accent).add_modifier(Modifier::BOLD),
+        ),
+    ]
+}
+
+fn render_tab(ctx: &RenderContext) -> Spans {
+    vec![
+        Span::styled("Tab: ", Style::default().fg(ctx.theme.secondary)),
+        Span::styled(get_current_tab_text(ctx.app), Style::default().fg(ctx.theme.primary)),
+    ]
+}
+
+fn render_status_message(ctx: &RenderContext) -> Spans {
+    match &ctx.app.status_message {
+        Some(message) => vec![
+            Span::styled("Error: ", Style::default().fg(ctx.theme.error).add_modifier(Modifier::BOLD)),
+            Span::styled(message.clone(), ctx.theme.error_style()),
+        ],
+        None => Vec::new(),
+    }
+}
+
+fn render_environment(ctx: &RenderContext) -> Spans {
+    let mut spans = vec![
+        Span::styled("Env: ", Style::default().fg(ctx.theme.secondary)),
+        Span::styled(ctx.app.current_environment.clone(), Style::default().fg(ctx.theme.primary)),
+    ];
+
+    if let Some(environment) = ctx.app.environments.get(&ctx.app.current_environment) {
+        // Keys the active environment alone can't resolve; re-check each
+        // against the full active -> parent -> global chain so a value
+        // inherited from a parent or the global environment isn't
+        // reported as missing
+        let candidates = ctx.app.current_command.unresolved_variables(environment);
+        let mut missing = Vec::new();
+        let mut inherited_from = Vec::new();
+
+        for key in &candidates {
+            match environment.resolve_variable(key, &ctx.app.environments) {
+                // `candidates` only contains keys the active environment
+                // itself couldn't resolve, so `Active` can't occur here
+                Some((_, VariableSource::Active)) => {}
+                Some((_, VariableSource::Parent(name))) => {
+                    let label = format!("+{}", name);
+                    if !inherited_from.contains(&label) {
+                        inherited_from.push(label);
+                    }
+                }
+                Some((_, VariableSource::Global)) => {
+                    let label = "+global".to_string();
+                    if !inherited_from.contains(&label) {
+                        inherited_from.push(label);
+                    }
+                }
+                None => missing.push(key.clone()),
+            }
+        }
+
+        if !inherited_from.is_empty() {
+            spans.push(Span::raw(" "));
+            spans.push(Span::styled(format!("({})", inherited_from.join(", ")), Style::default().fg(ctx.theme.secondary)));
+        }
+
+        if !missing.is_empty() {
+            spans.push(Span::raw(" "));
+            spans.push(Span::styled(format!("⚠ missing: {}", missing.join(", ")), ctx.theme.error_style()));
+        }
+    }
+
+    spans
+}
+
+fn render_method(ctx: &RenderContext) -> Spans {
+    let method = ctx
+        .app
+        .current_command
+        .method
+        .as_ref()
+        .map(|m| m.to_string())
+        .unwrap_or_else(|| "GET".to_string());
+
+    vec![
+        Span::styled("Method: ", Style::default().fg(ctx.theme.secondary)),
+        Span::styled(method, Style::default().fg(ctx.theme.accent)),
+    ]
+}
+
+fn render_url_status(ctx: &RenderContext) -> Spans {
+    let has_url = !ctx.app.current_command.url.is_empty();
+    let (text, color) = if has_url {
+        ("URL Set", ctx.theme.success)
+    } else {
+        ("No URL", ctx.theme.error)
+    };
+
+    vec![Span::styled(text, Style::default().fg(color))]
+}
+
+fn render_execution_result(ctx: &RenderContext) -> Spans {
+    let (text, color) = match &ctx.app.execution_result {
+        Some(result) if result.exit_code == Some(0) => ("✓ Success", ctx.theme.success),
+        Some(_) => ("✗ Failed", ctx.theme.error),
+        None => ("Ready", ctx.theme.foreground),
+    };
+
+    vec![
+        Span::styled("Status: ", Style::default().fg(ctx.theme.secondary)),
+        Span::styled(text, Style::default().fg(color)),
+    ]
+}
+
+fn render_shortcuts(ctx: &RenderContext) -> Spans {
+    let shortcuts = get_available_shortcuts(ctx.app);
+    let mut spans = Vec::new();
+
+    for (i, (key, desc)) in shortcuts.iter().enumerate() {
+        spans.push(Span::styled(
+            key.clone(),
+            Style::default().fg(ctx.theme.accent).add_modifier(Modifier::BOLD),
+        ));
+        spans.push(Span::styled(format!(":{} ", desc), Style::default().fg(ctx.theme.foreground)));
+
+        if i < shortcuts.len() - 1 {
+            spans.push(Span::styled("│ ", Style::default().fg(ctx.theme.secondary)));
+        }
+    }
+
+    spans
+}
+
+/// Which segments appear in each region of the status bar, and the
+/// separator joining segments within a region. Reordering or dropping a
+/// `SegmentId` here changes the layout without touching any render method.
+pub struct StatusBarConfig {
+    pub left: Vec<SegmentId>,
+    pub center: Vec<SegmentId>,
+    pub right: Vec<SegmentId>,
+    pub separator: String,
+}
+
+impl Default for StatusBarConfig {
+    fn default() -> Self {
+        Self {
+            left: vec![SegmentId::AppState, SegmentId::Tab, SegmentId::StatusMessage],
+            center: vec![SegmentId::Shortcuts],
+            right: vec![
+                SegmentId::Environment,
+                SegmentId::Method,
+                SegmentId::UrlStatus,
+                SegmentId::ExecutionResult,
+            ],
+            separator: " │ ".to_string(),
+        }
+    }
+}
+
 /// Status bar component that displays application state and available shortcuts
 pub struct StatusBar<'a> {
     app: &'a App,
     theme: &'a Theme,
+    config: StatusBarConfig,
 }
 
 impl<'a> StatusBar<'a> {
-    /// Create a new status bar
+    /// Create a new status bar with the default segment layout
     pub fn new(app: &'a App, theme: &'a Theme) -> Self {
-        Self { app, theme }
+        Self::with_config(app, theme, StatusBarConfig::default())
+    }
+
+    /// Create a new status bar with a custom segment layout
+    pub fn with_config(app: &'a App, theme: &'a Theme, config: StatusBarConfig) -> Self {
+        Self { app, theme, config }
     }
 
     /// Render the status bar
@@ -26,235 +248,275 @@ impl<'a> StatusBar<'a> {
         let chunks = Layout::default()
             .direction(Direction::Horizontal)
             .constraints([
-                Constraint::Percentage(30), // Left: Application state
-                Constraint::Percentage(40), // Center: Available shortcuts
-                Constraint::Percentage(30), // Right: Environment and other info
+                Constraint::Percentage(30),
+                Constraint::Percentage(40),
+                Constraint::Percentage(30),
             ])
             .split(area);
 
-        // Render left section (application state)
-        self.render_app_state(f, chunks[0]);
-
-        // Render center section (shortcuts)
-        self.render_shortcuts(f, chunks[1]);
-
-        // Render right section (environment info)
-        self.render_environment_info(f, chunks[2]);
+        self.render_region(f, chunks[0], &self.config.left, "Status", Alignment::Left);
+        self.render_region(f, chunks[1], &self.config.center, "Shortcuts", Alignment::Center);
+        self.render_region(f, chunks[2], &self.config.right, "Info", Alignment::Left);
     }
 
-    /// Render the application state section
-    fn render_app_state(&self, f: &mut Frame, area: Rect) {
-        let state_text = self.get_state_text();
-        let tab_text = self.get_current_tab_text();
-        
-        let content = vec![
-            Line::from(vec![
-                Span::styled("State: ", Style::default().fg(self.theme.secondary)),
-                Span::styled(state_text, Style::default().fg(self.theme.accent).add_modifier(Modifier::BOLD)),
-            ]),
-            Line::from(vec![
-                Span::styled("Tab: ", Style::default().fg(self.theme.secondary)),
-                Span::styled(tab_text, Style::default().fg(self.theme.primary)),
-            ]),
-        ];
-
-        let paragraph = Paragraph::new(content)
+    /// Evaluate a region's configured segments, join their spans with the
+    /// configured separator, and blit the resulting line into `area`
+    fn render_region(&self, f: &mut Frame, area: Rect, segments: &[SegmentId], title: &str, alignment: Alignment) {
+        let ctx = RenderContext { app: self.app, theme: self.theme };
+        let line = self.join_segments(segments, &ctx);
+
+        let paragraph = Paragraph::new(vec![line])
             .block(
                 Block::default()
                     .borders(Borders::ALL)
                     .border_style(self.theme.border_style())
-                    .title("Status")
-                    .title_style(self.theme.title_style())
+                    .title(title.to_string())
+                    .title_style(self.theme.title_style()),
             )
-            .style(Style::default().bg(self.theme.background));
+            .style(Style::default().bg(self.theme.background))
+            .alignment(alignment)
+            .wrap(Wrap { trim: true });
 
         f.render_widget(paragraph, area);
     }
 
-    /// Render the shortcuts section
-    fn render_shortcuts(&self, f: &mut Frame, area: Rect) {
-        let shortcuts = self.get_available_shortcuts();
-        
-        let content: Vec<Line> = shortcuts
-            .chunks(2) // Display 2 shortcuts per line
-            .map(|chunk| {
-                let spans: Vec<Span> = chunk
-                    .iter()
-                    .enumerate()
-                    .flat_map(|(i, (key, desc))| {
-                        let mut spans = vec![
-                            Span::styled(key.clone(), Style::default().fg(self.theme.accent).add_modifier(Modifier::BOLD)),
-                            Span::styled(format!(":{} ", desc), Style::default().fg(self.theme.foreground)),
-                        ];
-                        
-                        // Add separator if not the last item in the chunk
-                        if i < chunk.len() - 1 {
-                            spans.push(Span::styled("│ ", Style::default().fg(self.theme.secondary)));
-                        }
-                        
-                        spans
-                    })
-                    .collect();
-                Line::from(spans)
-            })
+    /// Render every segment in `segments`, skipping ones with nothing to
+    /// show, and interleave the configured separator between what remains
+    fn join_segments(&self, segments: &[SegmentId], ctx: &RenderContext) -> Line<'static> {
+        let rendered: Vec<Spans> = segments
+            .iter()
+            .map(|segment| segment.render(ctx))
+            .filter(|spans| !spans.is_empty())
             .collect();
 
-        let paragraph = Paragraph::new(content)
-            .block(
-                Block::default()
-                    .borders(Borders::ALL)
-                    .border_style(self.theme.border_style())
-                    .title("Shortcuts")
-                    .title_style(self.theme.title_style())
-            )
-            .style(Style::default().bg(self.theme.background))
-            .alignment(Alignment::Center);
+        let mut spans = Vec::new();
+        for (i, segment_spans) in rendered.into_iter().enumerate() {
+            if i > 0 {
+                spans.push(Span::styled(self.config.separator.clone(), Style::default().fg(self.theme.secondary)));
+            }
+            spans.extend(segment_spans);
+        }
 
-        f.render_widget(paragraph, area);
+        Line::from(spans)
     }
+}
 
-    /// Render the environment info section
-    fn render_environment_info(&self, f: &mut Frame, area: Rect) {
-        let method = self.app.current_command.method
-            .as_ref()
-            .map(|m| m.to_string())
-            .unwrap_or_else(|| "GET".to_string());
-        
-        let url_status = if self.app.current_command.url.is_empty() {
-            "No URL"
-        } else {
-            "URL Set"
-        };
-
-        let execution_status = match &self.app.execution_result {
-            Some(result) => {
-                if result.exit_code == Some(0) {
-                    "✓ Success"
-                } else {
-                    "✗ Failed"
-                }
-            }
-            None => "Ready"
-        };
-
-        let content = vec![
-            Line::from(vec![
-                Span::styled("Env: ", Style::default().fg(self.theme.secondary)),
-                Span::styled(&self.app.current_environment, Style::default().fg(self.theme.primary)),
-            ]),
-            Line::from(vec![
-                Span::styled("Method: ", Style::default().fg(self.theme.secondary)),
-                Span::styled(method, Style::default().fg(self.theme.accent)),
-                Span::styled(" │ ", Style::default().fg(self.theme.secondary)),
-                Span::styled(url_status, Style::default().fg(
-                    if self.app.current_command.url.is_empty() {
-                        self.theme.error
-                    } else {
-                        self.theme.success
-                    }
-                )),
-            ]),
-            Line::from(vec![
-                Span::styled("Status: ", Style::default().fg(self.theme.secondary)),
-                Span::styled(execution_status, Style::default().fg(
-                    match &self.app.execution_result {
-                        Some(result) if result.exit_code == Some(0) => self.theme.success,
-                        Some(_) => self.theme.error,
-                        None => self.theme.foreground,
-                    }
-                )),
-            ]),
-        ];
-
-        let paragraph = Paragraph::new(content)
-            .block(
-                Block::default()
-                    .borders(Borders::ALL)
-                    .border_style(self.theme.border_style())
-                    .title("Info")
-                    .title_style(self.theme.title_style())
-            )
-            .style(Style::default().bg(self.theme.background));
+/// Get the current application state as a string
+fn get_state_text(app: &App) -> String {
+    match &app.state {
+        AppState::Normal => "Normal".to_string(),
+        AppState::Editing(field) => match field {
+            crate::app::EditField::Url => "Editing URL".to_string(),
+            crate::app::EditField::Method => "Editing Method".to_string(),
+            crate::app::EditField::HeaderKey(_) => "Editing Header Key".to_string(),
+            crate::app::EditField::HeaderValue(_) => "Editing Header Value".to_string(),
+            crate::app::EditField::QueryParamKey(_) => "Editing Query Key".to_string(),
+            crate::app::EditField::QueryParamValue(_) => "Editing Query Value".to_string(),
+            crate::app::EditField::Body => "Editing Body".to_string(),
+            crate::app::EditField::OptionValue(_) => "Editing Option".to_string(),
+            crate::app::EditField::OptionFlag(_) => "Editing Option Flag".to_string(),
+        },
+        AppState::MethodDropdown => "Method Selection".to_string(),
+        AppState::EditingTemplateName => "Editing Template".to_string(),
+        AppState::EditingEnvironment => "Editing Environment".to_string(),
+        AppState::FillingTemplateVariables => "Filling Template Variables".to_string(),
+        AppState::EditingTemplateFolder => "Moving Template".to_string(),
+        AppState::CommandPalette => "Command Palette".to_string(),
+        AppState::Picker => "Picker".to_string(),
+        AppState::Completing(_) => "Completion".to_string(),
+        AppState::ImportingCurlCommand => "Importing Curl Command".to_string(),
+        AppState::Help => "Help".to_string(),
+        AppState::FilteringTemplates => "Filtering Templates".to_string(),
+        AppState::Exiting => "Exiting".to_string(),
+    }
+}
 
-        f.render_widget(paragraph, area);
+/// Get the current tab as a string
+fn get_current_tab_text(app: &App) -> String {
+    match &app.ui_state.active_tab {
+        Tab::Url => "URL".to_string(),
+        Tab::Headers => "Headers".to_string(),
+        Tab::Body => "Body".to_string(),
+        Tab::Options => "Options".to_string(),
     }
+}
 
-    /// Get the current application state as a string
-    fn get_state_text(&self) -> String {
-        match &self.app.state {
-            AppState::Normal => "Normal".to_string(),
-            AppState::Editing(field) => {
-                match field {
-                    crate::app::EditField::Url => "Editing URL".to_string(),
-                    crate::app::EditField::Method => "Editing Method".to_string(),
-                    crate::app::EditField::HeaderKey(_) => "Editing Header Key".to_string(),
-                    crate::app::EditField::HeaderValue(_) => "Editing Header Value".to_string(),
-                    crate::app::EditField::QueryParamKey(_) => "Editing Query Key".to_string(),
-                    crate::app::EditField::QueryParamValue(_) => "Editing Query Value".to_string(),
-                    crate::app::EditField::Body => "Editing Body".to_string(),
-                    crate::app::EditField::OptionValue(_) => "Editing Option".to_string(),
-                }
-            },
-            AppState::MethodDropdown => "Method Selection".to_string(),
-            AppState::EditingTemplateName => "Editing Template".to_string(),
-            AppState::EditingEnvironment => "Editing Environment".to_string(),
-            AppState::Help => "Help".to_string(),
-            AppState::Exiting => "Exiting".to_string(),
+/// Get available shortcuts based on current state
+fn get_available_shortcuts(app: &App) -> Vec<(String, String)> {
+    match &app.state {
+        AppState::Normal => {
+            vec![
+                ("F5".to_string(), "Execute".to_string()),
+                ("F4".to_string(), "Export Hurl".to_string()),
+                ("F2".to_string(), "Output Format".to_string()),
+                ("Tab".to_string(), "Next Tab".to_string()),
+                ("↑↓".to_string(), "Navigate".to_string()),
+                ("Enter".to_string(), "Edit".to_string()),
+                ("F1".to_string(), "Help".to_string()),
+                ("Ctrl+Q".to_string(), "Quit".to_string()),
+            ]
+        }
+        AppState::Editing(_) => {
+            vec![
+                ("Enter".to_string(), "Save".to_string()),
+                ("Esc".to_string(), "Cancel".to_string()),
+            ]
+        }
+        AppState::MethodDropdown => {
+            vec![
+                ("↑↓".to_string(), "Select".to_string()),
+                ("Enter".to_string(), "Confirm".to_string()),
+                ("Esc".to_string(), "Cancel".to_string()),
+            ]
+        }
+        AppState::Help => {
+            vec![
+                ("F1".to_string(), "Close Help".to_string()),
+                ("Esc".to_string(), "Close Help".to_string()),
+            ]
+        }
+        AppState::EditingTemplateName | AppState::EditingEnvironment | AppState::EditingTemplateFolder
+        | AppState::ImportingCurlCommand => {
+            vec![
+                ("Enter".to_string(), "Confirm".to_string()),
+                ("Esc".to_string(), "Cancel".to_string()),
+            ]
+        }
+        AppState::FillingTemplateVariables => {
+            vec![
+                ("Enter".to_string(), "Next".to_string()),
+                ("Esc".to_string(), "Cancel".to_string()),
+            ]
+        }
+        AppState::CommandPalette => {
+            vec![
+                ("↑↓".to_string(), "Select".to_string()),
+                ("Enter".to_string(), "Open".to_string()),
+                ("Esc".to_string(), "Close".to_string()),
+            ]
+        }
+        AppState::Picker => {
+            vec![
+                ("↑↓".to_string(), "Select".to_string()),
+                ("Enter".to_string(), "Insert".to_string()),
+                ("Esc".to_string(), "Close".to_string()),
+            ]
+        }
+        AppState::Completing(_) => {
+            vec![
+                ("↑↓".to_string(), "Select".to_string()),
+                ("Enter".to_string(), "Insert".to_string()),
+                ("Esc".to_string(), "Cancel".to_string()),
+            ]
+        }
+        AppState::FilteringTemplates => {
+            vec![
+                ("Enter".to_string(), "Keep Filter".to_string()),
+                ("Esc".to_string(), "Clear Filter".to_string()),
+            ]
+        }
+        AppState::Exiting => {
+            vec![("".to_string(), "Goodbye!".to_string())]
         }
     }
+}
 
-    /// Get the current tab as a string
-    fn get_current_tab_text(&self) -> String {
-        match &self.app.ui_state.active_tab {
-            Tab::Url => "URL".to_string(),
-            Tab::Headers => "Headers".to_string(),
-            Tab::Body => "Body".to_string(),
-            Tab::Options => "Options".to_string(),
-        }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::theme::Theme;
+
+    #[test]
+    fn test_app_state_segment_renders_state_text() {
+        let app = App::default();
+        let theme = Theme::default();
+        let ctx = RenderContext { app: &app, theme: &theme };
+
+        let spans = SegmentId::AppState.render(&ctx);
+        let text: String = spans.iter().map(|s| s.content.as_ref()).collect();
+        assert_eq!(text, "State: Normal");
     }
 
-    /// Get available shortcuts based on current state
-    fn get_available_shortcuts(&self) -> Vec<(String, String)> {
-        match &self.app.state {
-            AppState::Normal => {
-                vec![
-                    ("F5".to_string(), "Execute".to_string()),
-                    ("Tab".to_string(), "Next Tab".to_string()),
-                    ("↑↓".to_string(), "Navigate".to_string()),
-                    ("Enter".to_string(), "Edit".to_string()),
-                    ("F1".to_string(), "Help".to_string()),
-                    ("Ctrl+Q".to_string(), "Quit".to_string()),
-                ]
-            }
-            AppState::Editing(_) => {
-                vec![
-                    ("Enter".to_string(), "Save".to_string()),
-                    ("Esc".to_string(), "Cancel".to_string()),
-                ]
-            }
-            AppState::MethodDropdown => {
-                vec![
-                    ("↑↓".to_string(), "Select".to_string()),
-                    ("Enter".to_string(), "Confirm".to_string()),
-                    ("Esc".to_string(), "Cancel".to_string()),
-                ]
-            }
-            AppState::Help => {
-                vec![
-                    ("F1".to_string(), "Close Help".to_string()),
-                    ("Esc".to_string(), "Close Help".to_string()),
-                ]
-            }
-            AppState::EditingTemplateName | AppState::EditingEnvironment => {
-                vec![
-                    ("Esc".to_string(), "Cancel".to_string()),
-                ]
-            }
-            AppState::Exiting => {
-                vec![
-                    ("".to_string(), "Goodbye!".to_string()),
-                ]
-            }
-        }
+    #[test]
+    fn test_status_message_segment_empty_when_unset() {
+        let app = App::default();
+        let theme = Theme::default();
+        let ctx = RenderContext { app: &app, theme: &theme };
+
+        assert!(SegmentId::StatusMessage.render(&ctx).is_empty());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_status_message_segment_renders_when_set() {
+        let mut app = App::default();
+        app.status_message = Some("bad request".to_string());
+        let theme = Theme::default();
+        let ctx = RenderContext { app: &app, theme: &theme };
+
+        let spans = SegmentId::StatusMessage.render(&ctx);
+        let text: String = spans.iter().map(|s| s.content.as_ref()).collect();
+        assert_eq!(text, "Error: bad request");
+    }
+
+    #[test]
+    fn test_environment_segment_flags_unresolved_variables() {
+        let mut app = App::default();
+        app.current_command.url = "https://{{host}}/api".to_string();
+        app.environments.insert(app.current_environment.clone(), crate::models::environment::Environment::new(app.current_environment.clone()));
+        let theme = Theme::default();
+        let ctx = RenderContext { app: &app, theme: &theme };
+
+        let spans = SegmentId::Environment.render(&ctx);
+        let text: String = spans.iter().map(|s| s.content.as_ref()).collect();
+        assert!(text.contains("⚠ missing: host"), "expected unresolved marker, got: {}", text);
+    }
+
+    #[test]
+    fn test_environment_segment_reports_inherited_global_variable() {
+        use crate::models::environment::{Environment, GLOBAL_ENVIRONMENT_NAME};
+
+        let mut app = App::default();
+        app.current_command.url = "https://{{host}}/api".to_string();
+
+        let mut global = Environment::new(GLOBAL_ENVIRONMENT_NAME.to_string());
+        global.add_variable("host".to_string(), "global.example.com".to_string(), false);
+        app.environments.insert(GLOBAL_ENVIRONMENT_NAME.to_string(), global);
+        app.environments.insert(app.current_environment.clone(), Environment::new(app.current_environment.clone()));
+
+        let theme = Theme::default();
+        let ctx = RenderContext { app: &app, theme: &theme };
+
+        let spans = SegmentId::Environment.render(&ctx);
+        let text: String = spans.iter().map(|s| s.content.as_ref()).collect();
+        assert!(text.contains("(+global)"), "expected inherited marker, got: {}", text);
+        assert!(!text.contains("⚠ missing"), "host should resolve via global, got: {}", text);
+    }
+
+    #[test]
+    fn test_url_status_segment_reflects_empty_url() {
+        let app = App::default();
+        let theme = Theme::default();
+        let ctx = RenderContext { app: &app, theme: &theme };
+
+        let spans = SegmentId::UrlStatus.render(&ctx);
+        let text: String = spans.iter().map(|s| s.content.as_ref()).collect();
+        assert_eq!(text, "No URL");
+    }
+
+    #[test]
+    fn test_join_segments_skips_empty_and_separates_the_rest() {
+        let app = App::default();
+        let theme = Theme::default();
+        let status_bar = StatusBar::new(&app, &theme);
+        let ctx = RenderContext { app: &app, theme: &theme };
+
+        // StatusMessage is empty by default, so it should contribute
+        // neither spans nor a stray separator between AppState and Tab
+        let line = status_bar.join_segments(
+            &[SegmentId::AppState, SegmentId::StatusMessage, SegmentId::Tab],
+            &ctx,
+        );
+        let text: String = line.spans.iter().map(|s| s.content.as_ref()).collect();
+        assert_eq!(text, "State: Normal │ Tab: URL");
+    }
+}
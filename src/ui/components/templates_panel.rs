@@ -74,73 +74,262 @@ impl<'a> TemplatesTree<'a> {
 
     /// Render the templates tree
     pub fn render(&self, frame: &mut Frame, area: Rect) {
-        // Create block
+        if matches!(self.app.state, crate::app::AppState::FillingTemplateVariables) {
+            self.render_variable_prompt(frame, area);
+            return;
+        }
+
+        if matches!(self.app.state, crate::app::AppState::CommandPalette) {
+            self.render_command_palette(frame, area);
+            return;
+        }
+
+        if matches!(self.app.state, crate::app::AppState::EditingTemplateFolder) {
+            self.render_folder_prompt(frame, area);
+            return;
+        }
+
+        if matches!(self.app.state, crate::app::AppState::ImportingCurlCommand) {
+            self.render_import_prompt(frame, area);
+            return;
+        }
+
+        if matches!(self.app.state, crate::app::AppState::FilteringTemplates) {
+            self.render_filter_prompt(frame, area);
+            return;
+        }
+
+        // A filter query typed earlier stays applied once the user presses
+        // Enter and returns to normal navigation, so the title reflects
+        // that the tree below is still filtered
+        let title = if self.app.ui_state.template_filter_query.is_empty() {
+            "Templates".to_string()
+        } else {
+            format!("Templates (filter: {})", self.app.ui_state.template_filter_query)
+        };
         let block = Block::default()
-            .title("Templates")
+            .title(title)
             .borders(Borders::ALL)
             .style(self.theme.border_style());
 
-        // Create text
-        // Create text
+        // Create text from the flattened, visible (or filtered) collections tree
         let text = if self.app.templates.is_empty() {
             Text::from("No templates")
         } else {
-            // Create lines for templates grouped by category
-            let mut lines = Vec::new();
-            
-            // Group templates by category
-            let mut category_map = std::collections::HashMap::new();
-            for template in &self.app.templates {
-                let category = template.category.clone().unwrap_or_else(|| "Uncategorized".to_string());
-                category_map.entry(category).or_insert_with(Vec::new).push(template);
-            }
-            
-            // Convert the HashMap into a Vec for stable iteration
-            let mut categories: Vec<(String, Vec<&crate::models::template::CommandTemplate>)> =
-                category_map.into_iter().collect();
-            
-            // Sort categories by name for consistent display
-            categories.sort_by(|a, b| a.0.cmp(&b.0));
-            
-            // Add each category and its templates
-            for (category, templates) in categories {
-                // Add category
-                let expanded = true; // TODO: Track expanded state
-                let symbol = if expanded { "▼" } else { "▶" };
-                lines.push(Line::from(vec![
-                    Span::styled(symbol, self.theme.text_style()),
-                    Span::raw(" "),
-                    Span::styled(category.clone(), self.theme.header_style()),
-                ]));
-
-                // Add templates
-                if expanded {
-                    for template in templates {
-                        let selected = self.app.ui_state.selected_template
-                            .map(|idx| &self.app.templates[idx].id == &template.id)
-                            .unwrap_or(false);
-                        
-                        let style = if selected {
-                            self.theme.selected_style()
-                        } else {
-                            self.theme.text_style()
-                        };
-                        
-                        lines.push(Line::from(vec![
-                            Span::raw("  ▶ "),
-                            Span::styled(&template.name, style),
-                        ]));
+            use crate::models::collections::CollectionTree;
+
+            let tree = CollectionTree::build(&self.app.templates);
+            let rows = if self.app.ui_state.template_filter_query.is_empty() {
+                tree.visible_rows(&self.app.ui_state.expanded_folders)
+            } else {
+                tree.filtered_rows(&self.app.templates, &self.app.ui_state.template_filter_query, &self.app.ui_state.expanded_folders)
+            };
+
+            Text::from(self.tree_row_lines(&rows))
+        };
+
+        // Create paragraph
+        let paragraph = ratatui::widgets::Paragraph::new(text).block(block);
+
+        // Render paragraph
+        frame.render_widget(paragraph, area);
+    }
+
+    /// Render each tree row, highlighting whichever one `selected_template`
+    /// points at. Shared by the plain tree view and the filter prompt so
+    /// both stay in sync with each other.
+    fn tree_row_lines(&self, rows: &[crate::models::collections::TreeRow]) -> Vec<Line> {
+        use crate::models::collections::TreeRow;
+
+        rows.iter()
+            .enumerate()
+            .map(|(row_idx, row)| {
+                let selected = self.app.ui_state.selected_template == Some(row_idx);
+                match row {
+                    TreeRow::Folder { path, depth, expanded } => {
+                        let indent = "  ".repeat(*depth);
+                        let symbol = if *expanded { "▼" } else { "▶" };
+                        let name = path.rsplit('/').next().unwrap_or(path.as_str());
+                        let style = if selected { self.theme.selected_style() } else { self.theme.header_style() };
+                        Line::from(vec![
+                            Span::raw(indent),
+                            Span::styled(symbol, self.theme.text_style()),
+                            Span::raw(" "),
+                            Span::styled(name.to_string(), style),
+                        ])
+                    }
+                    TreeRow::Template { index, depth } => {
+                        let indent = "  ".repeat(*depth);
+                        let style = if selected { self.theme.selected_style() } else { self.theme.text_style() };
+                        Line::from(vec![
+                            Span::raw(indent),
+                            Span::raw("▶ "),
+                            Span::styled(&self.app.templates[*index].name, style),
+                        ])
                     }
                 }
+            })
+            .collect()
+    }
+
+    /// Render the templates tree's incremental fuzzy filter: the query
+    /// being typed, followed by the live-filtered tree
+    fn render_filter_prompt(&self, frame: &mut Frame, area: Rect) {
+        use crate::models::collections::CollectionTree;
+
+        let block = Block::default()
+            .title("Filter Templates")
+            .borders(Borders::ALL)
+            .style(self.theme.border_style());
+
+        let mut lines = vec![
+            Line::from(vec![
+                Span::styled("/", self.theme.header_style()),
+                Span::styled(&self.app.ui_state.template_filter_query, self.theme.text_style()),
+                Span::raw(" █"),
+            ]),
+            Line::from(""),
+        ];
+
+        if self.app.templates.is_empty() {
+            lines.push(Line::from("No templates"));
+        } else {
+            let tree = CollectionTree::build(&self.app.templates);
+            let rows = tree.filtered_rows(&self.app.templates, &self.app.ui_state.template_filter_query, &self.app.ui_state.expanded_folders);
+            if rows.is_empty() {
+                lines.push(Line::from("No matches"));
+            } else {
+                lines.extend(self.tree_row_lines(&rows));
             }
+        }
 
-            Text::from(lines)
+        let paragraph = ratatui::widgets::Paragraph::new(Text::from(lines)).block(block);
+        frame.render_widget(paragraph, area);
+    }
+
+    /// Render the prompt for a template's unbound variables
+    fn render_variable_prompt(&self, frame: &mut Frame, area: Rect) {
+        let block = Block::default()
+            .title("Fill Template Variables")
+            .borders(Borders::ALL)
+            .style(self.theme.border_style());
+
+        let text = match &self.app.ui_state.template_variable_prompt {
+            Some(prompt) => {
+                let mut lines = Vec::new();
+                for (idx, var) in prompt.pending.iter().enumerate() {
+                    let is_current = idx == prompt.current_index;
+                    let value = if is_current {
+                        self.app.ui_state.edit_buffer.as_str()
+                    } else {
+                        prompt.values.get(&var.key).map(|s| s.as_str()).unwrap_or("")
+                    };
+                    let display = if var.var_type == crate::models::template::TemplateVariableType::Secret && !value.is_empty() {
+                        "*".repeat(value.len())
+                    } else {
+                        value.to_string()
+                    };
+                    let style = if is_current {
+                        self.theme.editing_style()
+                    } else {
+                        self.theme.text_style()
+                    };
+                    lines.push(Line::from(vec![
+                        Span::styled(format!("{}: ", var.key), self.theme.header_style()),
+                        Span::styled(display, style),
+                        if is_current { Span::raw(" █") } else { Span::raw("") },
+                    ]));
+                }
+                Text::from(lines)
+            }
+            None => Text::from("No pending variables"),
         };
 
-        // Create paragraph
         let paragraph = ratatui::widgets::Paragraph::new(text).block(block);
+        frame.render_widget(paragraph, area);
+    }
 
-        // Render paragraph
+    /// Render the fuzzy command palette: the current query followed by its
+    /// ranked results over templates, history, and curl options
+    fn render_command_palette(&self, frame: &mut Frame, area: Rect) {
+        let block = Block::default()
+            .title("Command Palette")
+            .borders(Borders::ALL)
+            .style(self.theme.border_style());
+
+        let mut lines = vec![
+            Line::from(vec![
+                Span::styled("> ", self.theme.header_style()),
+                Span::styled(&self.app.ui_state.palette_query, self.theme.text_style()),
+                Span::raw(" █"),
+            ]),
+            Line::from(""),
+        ];
+
+        if self.app.ui_state.palette_results.is_empty() {
+            lines.push(Line::from("No matches"));
+        } else {
+            for (idx, item) in self.app.ui_state.palette_results.iter().enumerate() {
+                let selected = idx == self.app.ui_state.palette_selected;
+                let style = if selected { self.theme.selected_style() } else { self.theme.text_style() };
+                let label = match item {
+                    crate::app::PaletteItem::Template(template_idx) => {
+                        format!("[template] {}", self.app.templates[*template_idx].name)
+                    }
+                    crate::app::PaletteItem::History(history_idx) => {
+                        let command = &self.app.history[*history_idx];
+                        let label = if command.name.is_empty() { &command.url } else { &command.name };
+                        format!("[history] {}", label)
+                    }
+                    crate::app::PaletteItem::Option(flag) => format!("[option] {}", flag),
+                };
+                lines.push(Line::from(vec![Span::styled(label, style)]));
+            }
+        }
+
+        let paragraph = ratatui::widgets::Paragraph::new(Text::from(lines)).block(block);
+        frame.render_widget(paragraph, area);
+    }
+
+    /// Render the prompt for moving the selected template into a different
+    /// collection folder, e.g. typing `APIs/Billing`
+    fn render_folder_prompt(&self, frame: &mut Frame, area: Rect) {
+        let block = Block::default()
+            .title("Move to Folder")
+            .borders(Borders::ALL)
+            .style(self.theme.border_style());
+
+        let text = Text::from(vec![
+            Line::from(vec![
+                Span::styled("Folder: ", self.theme.header_style()),
+                Span::styled(&self.app.ui_state.edit_buffer, self.theme.text_style()),
+                Span::raw(" █"),
+            ]),
+            Line::from(""),
+            Line::from("Enter to confirm, Esc to cancel. Leave blank for Uncategorized."),
+        ]);
+
+        let paragraph = ratatui::widgets::Paragraph::new(text).block(block);
+        frame.render_widget(paragraph, area);
+    }
+
+    /// Render the prompt for pasting a raw curl command to import
+    fn render_import_prompt(&self, frame: &mut Frame, area: Rect) {
+        let block = Block::default()
+            .title("Import Curl Command")
+            .borders(Borders::ALL)
+            .style(self.theme.border_style());
+
+        let text = Text::from(vec![
+            Line::from(vec![
+                Span::styled(&self.app.ui_state.edit_buffer, self.theme.text_style()),
+                Span::raw(" █"),
+            ]),
+            Line::from(""),
+            Line::from("Paste a curl command, then Enter to import. Esc to cancel."),
+        ]);
+
+        let paragraph = ratatui::widgets::Paragraph::new(text).block(block);
         frame.render_widget(paragraph, area);
     }
 }
\ No newline at end of file
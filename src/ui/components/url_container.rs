@@ -490,12 +490,18 @@ impl<'a> UrlContainer<'a> {
                             } else {
                                 self.theme.text_style()
                             };
+                            let value_text = match &item.kind {
+                                crate::models::command::FormFieldKind::Text(value) => value.clone(),
+                                crate::models::command::FormFieldKind::File { path, filename, .. } => {
+                                    format!("@{} (file{})", path.display(), filename.as_deref().map(|f| format!(", as {}", f)).unwrap_or_default())
+                                }
+                            };
                             lines.push(Line::from(vec![
                                 Span::styled(enabled, style),
                                 Span::raw(" "),
                                 Span::styled(&item.key, style),
                                 Span::raw(": "),
-                                Span::styled(&item.value, style),
+                                Span::styled(value_text, style),
                             ]));
                         }
                         Text::from(lines)
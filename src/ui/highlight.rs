@@ -0,0 +1,135 @@
+//! `syntect`-backed highlighting, shared by the generated command line
+//! (see `ui::components::command_display::highlight_command`) and the
+//! response body (see `ui::components::output_panel`), using bundled
+//! grammars and a bundled theme instead of per-use bespoke tokenizers.
+//! The `SyntaxSet`/`ThemeSet` are loaded once into a `OnceLock` so parsing
+//! isn't rebuilt per frame.
+
+use crate::syntax::ContentType;
+use ratatui::style::{Color, Modifier, Style};
+use syntect::easy::HighlightLines;
+use syntect::highlighting::{FontStyle, Theme, ThemeSet};
+use syntect::parsing::SyntaxSet;
+use std::sync::OnceLock;
+
+fn syntax_set() -> &'static SyntaxSet {
+    static SYNTAX_SET: OnceLock<SyntaxSet> = OnceLock::new();
+    SYNTAX_SET.get_or_init(SyntaxSet::load_defaults_newlines)
+}
+
+fn theme() -> &'static Theme {
+    static THEME_SET: OnceLock<ThemeSet> = OnceLock::new();
+    let theme_set = THEME_SET.get_or_init(ThemeSet::load_defaults);
+    &theme_set.themes["base16-ocean.dark"]
+}
+
+/// Build a fresh highlighter for the bundled grammar registered under
+/// `extension`, or `None` if the bundled `SyntaxSet` has no such grammar.
+fn highlighter_for_extension(extension: &str) -> Option<HighlightLines<'static>> {
+    let syntax = syntax_set().find_syntax_by_extension(extension)?;
+    Some(HighlightLines::new(syntax, theme()))
+}
+
+/// Highlight `line` as a bash command line, returning `(text, Style)` runs
+/// in source order ready to hand to `ratatui::text::Span::styled`. Falls
+/// back to a single unstyled run for the whole line if bash's syntax
+/// definition isn't found in the bundled `SyntaxSet` or highlighting fails
+/// (`syntect` can error on a line that desyncs its internal parser state).
+pub fn highlight_bash_line(line: &str) -> Vec<(String, Style)> {
+    match highlighter_for_extension("sh") {
+        Some(mut highlighter) => highlight_line(&mut highlighter, line),
+        None => vec![(line.to_string(), Style::default())],
+    }
+}
+
+/// Build a highlighter for a response body of the given `content_type`, to
+/// be reused across every line of that body via [`highlight_line`] so
+/// multi-line constructs (e.g. a pretty-printed JSON object) keep correct
+/// parser state between calls, the same way `syntect`'s own line-by-line
+/// examples drive `HighlightLines`. Returns `None` for content types with
+/// no bundled grammar (`FormUrlEncoded`, `PlainText`) or if the bundled
+/// `SyntaxSet` doesn't have the grammar; callers should render those lines
+/// with a flat style instead.
+pub fn body_highlighter(content_type: ContentType) -> Option<HighlightLines<'static>> {
+    let extension = match content_type {
+        ContentType::Json => "json",
+        ContentType::Xml => "xml",
+        ContentType::FormUrlEncoded | ContentType::PlainText => return None,
+    };
+    highlighter_for_extension(extension)
+}
+
+/// Highlight one `line` with an existing `highlighter` (from
+/// [`body_highlighter`] or built fresh per call, as [`highlight_bash_line`]
+/// does), returning `(text, Style)` runs in source order. Falls back to a
+/// single unstyled run for the whole line if `syntect` errors (it can, on a
+/// line that desyncs its internal parser state).
+pub fn highlight_line(highlighter: &mut HighlightLines<'static>, line: &str) -> Vec<(String, Style)> {
+    match highlighter.highlight_line(line, syntax_set()) {
+        Ok(ranges) => ranges
+            .into_iter()
+            .map(|(style, text)| (text.to_string(), syntect_style_to_ratatui(style)))
+            .collect(),
+        Err(_) => vec![(line.to_string(), Style::default())],
+    }
+}
+
+/// Map a `syntect::highlighting::Style` onto a ratatui `Style`: the
+/// resolved theme foreground becomes an RGB `Color`, and bold/italic/
+/// underline font flags become the matching `Modifier`s. The background is
+/// left unset so the panel's own `Block` background shows through rather
+/// than syntect's theme background.
+fn syntect_style_to_ratatui(style: syntect::highlighting::Style) -> Style {
+    let fg = style.foreground;
+    let mut ratatui_style = Style::default().fg(Color::Rgb(fg.r, fg.g, fg.b));
+
+    if style.font_style.contains(FontStyle::BOLD) {
+        ratatui_style = ratatui_style.add_modifier(Modifier::BOLD);
+    }
+    if style.font_style.contains(FontStyle::ITALIC) {
+        ratatui_style = ratatui_style.add_modifier(Modifier::ITALIC);
+    }
+    if style.font_style.contains(FontStyle::UNDERLINE) {
+        ratatui_style = ratatui_style.add_modifier(Modifier::UNDERLINED);
+    }
+
+    ratatui_style
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_highlight_bash_line_covers_the_whole_line() {
+        let runs = highlight_bash_line("curl -X POST https://example.com");
+        let rejoined: String = runs.iter().map(|(text, _)| text.as_str()).collect();
+        assert_eq!(rejoined, "curl -X POST https://example.com");
+    }
+
+    #[test]
+    fn test_highlight_bash_line_does_not_panic_on_an_empty_line() {
+        let runs = highlight_bash_line("");
+        let rejoined: String = runs.iter().map(|(text, _)| text.as_str()).collect();
+        assert_eq!(rejoined, "");
+    }
+
+    #[test]
+    fn test_body_highlighter_covers_json_lines_across_calls() {
+        let mut highlighter = body_highlighter(ContentType::Json).expect("json grammar is bundled");
+        let first = highlight_line(&mut highlighter, "{");
+        let second = highlight_line(&mut highlighter, "  \"key\": \"value\"");
+        let third = highlight_line(&mut highlighter, "}");
+
+        let rejoin = |runs: &[(String, Style)]| -> String { runs.iter().map(|(text, _)| text.as_str()).collect() };
+        assert_eq!(rejoin(&first), "{");
+        assert_eq!(rejoin(&second), "  \"key\": \"value\"");
+        assert_eq!(rejoin(&third), "}");
+    }
+
+    #[test]
+    fn test_body_highlighter_has_no_grammar_for_form_urlencoded_or_plain_text() {
+        assert!(body_highlighter(ContentType::FormUrlEncoded).is_none());
+        assert!(body_highlighter(ContentType::PlainText).is_none());
+    }
+}
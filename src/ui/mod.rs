@@ -1,5 +1,7 @@
+pub mod caps;
 pub mod components;
 pub mod event;
+pub mod highlight;
 pub mod theme;
 
 pub use event::{Event, EventHandler};
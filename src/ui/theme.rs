@@ -1,4 +1,283 @@
 use ratatui::style::{Color, Modifier, Style};
+use std::collections::HashMap;
+
+/// The style keys every theme TOML file must define
+const REQUIRED_COLOR_KEYS: &[&str] = &[
+    "primary", "secondary", "accent", "background", "foreground", "error", "warning", "success",
+];
+
+/// A single problem found while validating a theme file: which key is at
+/// fault, the line it was found on (when present), and why it failed
+#[derive(Debug, Clone, PartialEq)]
+pub struct ThemeDiagnostic {
+    pub key: String,
+    pub line: Option<usize>,
+    pub message: String,
+}
+
+impl std::fmt::Display for ThemeDiagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.line {
+            Some(line) => write!(f, "line {}: {} ({})", line, self.message, self.key),
+            None => write!(f, "{} ({})", self.message, self.key),
+        }
+    }
+}
+
+/// Parse a color spec accepting named colors (matching ratatui's `Color`
+/// variants), ANSI indices (`0`-`255`), or `#rrggbb` truecolor hex
+fn parse_color(value: &str) -> Result<Color, String> {
+    let trimmed = value.trim();
+
+    if let Some(hex) = trimmed.strip_prefix('#') {
+        let channels = (
+            hex.get(0..2).and_then(|s| u8::from_str_radix(s, 16).ok()),
+            hex.get(2..4).and_then(|s| u8::from_str_radix(s, 16).ok()),
+            hex.get(4..6).and_then(|s| u8::from_str_radix(s, 16).ok()),
+        );
+        return match (hex.len(), channels) {
+            (6, (Some(r), Some(g), Some(b))) => Ok(Color::Rgb(r, g, b)),
+            _ => Err(format!("'{}' is not a valid #rrggbb truecolor value", value)),
+        };
+    }
+
+    if let Ok(index) = trimmed.parse::<u8>() {
+        return Ok(Color::Indexed(index));
+    }
+
+    match trimmed.to_lowercase().as_str() {
+        "black" => Ok(Color::Black),
+        "red" => Ok(Color::Red),
+        "green" => Ok(Color::Green),
+        "yellow" => Ok(Color::Yellow),
+        "blue" => Ok(Color::Blue),
+        "magenta" => Ok(Color::Magenta),
+        "cyan" => Ok(Color::Cyan),
+        "white" => Ok(Color::White),
+        "gray" | "grey" => Ok(Color::Gray),
+        "darkgray" | "darkgrey" => Ok(Color::DarkGray),
+        "lightred" => Ok(Color::LightRed),
+        "lightgreen" => Ok(Color::LightGreen),
+        "lightyellow" => Ok(Color::LightYellow),
+        "lightblue" => Ok(Color::LightBlue),
+        "lightmagenta" => Ok(Color::LightMagenta),
+        "lightcyan" => Ok(Color::LightCyan),
+        _ => Err(format!(
+            "'{}' is not a recognized color name, ANSI index, or #rrggbb value",
+            value
+        )),
+    }
+}
+
+/// Find the 1-based line number of a `key = ...` assignment in raw TOML
+/// text, so lint diagnostics can point at where the bad value lives
+fn find_key_line(contents: &str, key: &str) -> Option<usize> {
+    contents.lines().enumerate().find_map(|(i, line)| {
+        let trimmed = line.trim_start();
+        let after_key = trimmed.strip_prefix(key)?;
+        if after_key.trim_start().starts_with('=') {
+            Some(i + 1)
+        } else {
+            None
+        }
+    })
+}
+
+/// A theme TOML document's shape: the required flat colors as named
+/// fields (rather than a generic `HashMap<String, String>`) so an
+/// optional `[palette]`/`[scopes]` table alongside them parses cleanly
+/// instead of being coerced into a map of strings
+#[derive(serde::Deserialize, Default)]
+struct ThemeDocument {
+    primary: Option<String>,
+    secondary: Option<String>,
+    accent: Option<String>,
+    background: Option<String>,
+    foreground: Option<String>,
+    error: Option<String>,
+    warning: Option<String>,
+    success: Option<String>,
+    /// Named colors, referenced by name from `[scopes]` entries instead of
+    /// repeating the same color spec in several scopes
+    #[serde(default)]
+    palette: HashMap<String, String>,
+    /// Named highlight scopes (e.g. `command.program`, `ui.border`), looked
+    /// up by [`Theme::scope_style`]
+    #[serde(default)]
+    scopes: HashMap<String, ScopeSpec>,
+}
+
+/// Fetch one of `ThemeDocument`'s required color fields by its TOML key
+fn required_field<'a>(doc: &'a ThemeDocument, key: &str) -> Option<&'a String> {
+    match key {
+        "primary" => doc.primary.as_ref(),
+        "secondary" => doc.secondary.as_ref(),
+        "accent" => doc.accent.as_ref(),
+        "background" => doc.background.as_ref(),
+        "foreground" => doc.foreground.as_ref(),
+        "error" => doc.error.as_ref(),
+        "warning" => doc.warning.as_ref(),
+        "success" => doc.success.as_ref(),
+        _ => None,
+    }
+}
+
+/// A `[scopes]` entry: either a bare color/palette-name string (an
+/// fg-only style) or a full style with background and modifiers, e.g.
+///
+/// ```toml
+/// [scopes]
+/// "command.program" = "accent"
+/// "command.option" = { fg = "primary", modifiers = ["bold"] }
+/// ```
+#[derive(serde::Deserialize, Clone)]
+#[serde(untagged)]
+enum ScopeSpec {
+    Color(String),
+    Styled {
+        fg: Option<String>,
+        #[serde(default)]
+        bg: Option<String>,
+        #[serde(default)]
+        modifiers: Vec<String>,
+    },
+}
+
+/// Resolve a style modifier name (e.g. `"bold"`) to its ratatui `Modifier`
+fn parse_modifier(name: &str) -> Option<Modifier> {
+    match name.to_lowercase().as_str() {
+        "bold" => Some(Modifier::BOLD),
+        "dim" => Some(Modifier::DIM),
+        "italic" => Some(Modifier::ITALIC),
+        "underlined" | "underline" => Some(Modifier::UNDERLINED),
+        "slow_blink" => Some(Modifier::SLOW_BLINK),
+        "rapid_blink" => Some(Modifier::RAPID_BLINK),
+        "reversed" => Some(Modifier::REVERSED),
+        "hidden" => Some(Modifier::HIDDEN),
+        "crossed_out" | "strikethrough" => Some(Modifier::CROSSED_OUT),
+        _ => None,
+    }
+}
+
+/// Resolve a color spec to a `Color`, first trying it as a literal value
+/// (hex/ANSI index/named, via [`parse_color`]), then as a reference into
+/// `palette`. A palette entry may itself reference another palette entry;
+/// that chain is followed up to `palette.len()` hops (enough for any
+/// definition order) before giving up on a cycle.
+fn resolve_palette_color(spec: &str, palette: &HashMap<String, String>) -> Result<Color, String> {
+    if let Ok(color) = parse_color(spec) {
+        return Ok(color);
+    }
+
+    let mut seen = std::collections::HashSet::new();
+    let mut current = spec;
+    for _ in 0..=palette.len() {
+        if !seen.insert(current) {
+            return Err(format!("palette reference '{}' forms a cycle", spec));
+        }
+        match palette.get(current) {
+            Some(next) => match parse_color(next) {
+                Ok(color) => return Ok(color),
+                Err(_) => current = next,
+            },
+            None => return Err(format!("'{}' is not a color or a [palette] name", current)),
+        }
+    }
+
+    Err(format!("palette reference '{}' could not be resolved", spec))
+}
+
+/// Resolve a `[scopes]` entry to a concrete `Style`, resolving any
+/// palette-name colors along the way
+fn resolve_scope_style(spec: &ScopeSpec, palette: &HashMap<String, String>) -> Result<Style, String> {
+    match spec {
+        ScopeSpec::Color(value) => Ok(Style::default().fg(resolve_palette_color(value, palette)?)),
+        ScopeSpec::Styled { fg, bg, modifiers } => {
+            let mut style = Style::default();
+            if let Some(fg) = fg {
+                style = style.fg(resolve_palette_color(fg, palette)?);
+            }
+            if let Some(bg) = bg {
+                style = style.bg(resolve_palette_color(bg, palette)?);
+            }
+            for modifier_name in modifiers {
+                let modifier = parse_modifier(modifier_name)
+                    .ok_or_else(|| format!("'{}' is not a recognized style modifier", modifier_name))?;
+                style = style.add_modifier(modifier);
+            }
+            Ok(style)
+        }
+    }
+}
+
+/// Validate a theme TOML document against the required style keys,
+/// reporting every missing or unparseable key (with line context where
+/// available) instead of silently falling back to defaults
+pub fn lint_theme_toml(contents: &str) -> Result<Theme, Vec<ThemeDiagnostic>> {
+    let doc: ThemeDocument = match toml::from_str(contents) {
+        Ok(doc) => doc,
+        Err(err) => {
+            return Err(vec![ThemeDiagnostic {
+                key: "<document>".to_string(),
+                line: None,
+                message: format!("failed to parse TOML: {}", err),
+            }])
+        }
+    };
+
+    let mut diagnostics = Vec::new();
+    let mut resolved: HashMap<&str, Color> = HashMap::new();
+
+    for key in REQUIRED_COLOR_KEYS {
+        match required_field(&doc, key) {
+            None => diagnostics.push(ThemeDiagnostic {
+                key: key.to_string(),
+                line: None,
+                message: "missing required theme key".to_string(),
+            }),
+            Some(value) => match parse_color(value) {
+                Ok(color) => {
+                    resolved.insert(key, color);
+                }
+                Err(reason) => diagnostics.push(ThemeDiagnostic {
+                    key: key.to_string(),
+                    line: find_key_line(contents, key),
+                    message: reason,
+                }),
+            },
+        }
+    }
+
+    let mut scopes = HashMap::new();
+    for (name, spec) in &doc.scopes {
+        match resolve_scope_style(spec, &doc.palette) {
+            Ok(style) => {
+                scopes.insert(name.clone(), style);
+            }
+            Err(reason) => diagnostics.push(ThemeDiagnostic {
+                key: format!("scopes.{}", name),
+                line: find_key_line(contents, name),
+                message: reason,
+            }),
+        }
+    }
+
+    if !diagnostics.is_empty() {
+        return Err(diagnostics);
+    }
+
+    Ok(Theme {
+        primary: resolved["primary"],
+        secondary: resolved["secondary"],
+        accent: resolved["accent"],
+        background: resolved["background"],
+        foreground: resolved["foreground"],
+        error: resolved["error"],
+        warning: resolved["warning"],
+        success: resolved["success"],
+        scopes,
+    })
+}
 
 /// UI theme
 pub struct Theme {
@@ -18,6 +297,12 @@ pub struct Theme {
     pub warning: Color,
     /// Success color
     pub success: Color,
+    /// Named highlight scopes (e.g. `command.program`, `ui.border`) loaded
+    /// from a theme file's `[scopes]` table, with `[palette]` references
+    /// already resolved to concrete colors. Empty for the built-in
+    /// `new`/`dark`/`light` themes, which callers fall back from via
+    /// [`Theme::scope_style`].
+    scopes: HashMap<String, Style>,
 }
 
 impl Theme {
@@ -32,6 +317,7 @@ impl Theme {
             error: Color::Red,
             warning: Color::Yellow,
             success: Color::Green,
+            scopes: HashMap::new(),
         }
     }
 
@@ -40,6 +326,21 @@ impl Theme {
         Self::new()
     }
 
+    /// Load a theme from a user TOML file (e.g.
+    /// `~/.config/lazycurl/themes/solarized.toml`), validating every
+    /// required style key and reporting all problems at once rather than
+    /// silently falling back to defaults
+    pub fn from_file(path: &std::path::Path) -> Result<Self, Vec<ThemeDiagnostic>> {
+        let contents = std::fs::read_to_string(path).map_err(|err| {
+            vec![ThemeDiagnostic {
+                key: "<file>".to_string(),
+                line: None,
+                message: format!("failed to read theme file {}: {}", path.display(), err),
+            }]
+        })?;
+        lint_theme_toml(&contents)
+    }
+
     /// Create a light theme
     pub fn light() -> Self {
         Self {
@@ -51,9 +352,18 @@ impl Theme {
             error: Color::Red,
             warning: Color::Yellow,
             success: Color::Green,
+            scopes: HashMap::new(),
         }
     }
 
+    /// Look up a named highlight scope (e.g. `"command.program"`), falling
+    /// back to `default` when the current theme has no `[scopes]` entry by
+    /// that name — true for every built-in theme, and for any loaded theme
+    /// file that doesn't define a `[scopes]` table
+    pub fn scope_style(&self, name: &str, default: Style) -> Style {
+        self.scopes.get(name).copied().unwrap_or(default)
+    }
+
     /// Get title style
     pub fn title_style(&self) -> Style {
         Style::default()
@@ -126,10 +436,449 @@ impl Theme {
             .fg(self.secondary)
             .add_modifier(Modifier::ITALIC)
     }
+
+    /// Generate `steps` colors smoothly interpolated across `controls` via
+    /// the Bézier curve they define (repeated linear interpolation, i.e. a
+    /// de Casteljau sweep, of the control points sampled at
+    /// `t = i/(steps-1)`), each wrapped in a `Style` with that foreground
+    /// color, for painting successive lines with a smooth gradient instead
+    /// of one flat color.
+    ///
+    /// A single control color always yields a flat gradient of length
+    /// `steps`, and `steps < 2` (with more than one control) returns just
+    /// the first color. Interpolated `Color::Rgb` values degrade to the
+    /// nearest of the 16 basic ANSI colors when the terminal doesn't
+    /// advertise truecolor support.
+    pub fn gradient(controls: &[Color], steps: usize) -> Vec<Style> {
+        gradient_colors(controls, steps)
+            .into_iter()
+            .map(|color| Style::default().fg(gradient_color(color, supports_truecolor())))
+            .collect()
+    }
+}
+
+/// The pure color math behind `Theme::gradient`, kept separate from the
+/// `COLORTERM` env read so the interpolation itself is deterministic and
+/// testable without depending on process environment state
+fn gradient_colors(controls: &[Color], steps: usize) -> Vec<Color> {
+    if controls.is_empty() {
+        return Vec::new();
+    }
+
+    if controls.len() == 1 {
+        return vec![controls[0]; steps];
+    }
+
+    if steps < 2 {
+        return vec![controls[0]];
+    }
+
+    let points: Vec<(f64, f64, f64)> = controls
+        .iter()
+        .map(|color| {
+            let (r, g, b) = color_to_rgb(*color);
+            (r as f64, g as f64, b as f64)
+        })
+        .collect();
+
+    (0..steps)
+        .map(|i| {
+            let t = i as f64 / (steps - 1) as f64;
+            let (r, g, b) = interpolate_curve(&points, t);
+            Color::Rgb(
+                r.round().clamp(0.0, 255.0) as u8,
+                g.round().clamp(0.0, 255.0) as u8,
+                b.round().clamp(0.0, 255.0) as u8,
+            )
+        })
+        .collect()
+}
+
+/// The 16 basic ANSI colors paired with their conventional RGB midpoints,
+/// used both to approximate a named color's RGB value and as the fallback
+/// palette gradients degrade to without truecolor support
+const ANSI_PALETTE: &[(Color, (u8, u8, u8))] = &[
+    (Color::Black, (0, 0, 0)),
+    (Color::Red, (205, 0, 0)),
+    (Color::Green, (0, 205, 0)),
+    (Color::Yellow, (205, 205, 0)),
+    (Color::Blue, (0, 0, 238)),
+    (Color::Magenta, (205, 0, 205)),
+    (Color::Cyan, (0, 205, 205)),
+    (Color::White, (229, 229, 229)),
+    (Color::DarkGray, (127, 127, 127)),
+    (Color::LightRed, (255, 0, 0)),
+    (Color::LightGreen, (0, 255, 0)),
+    (Color::LightYellow, (255, 255, 0)),
+    (Color::LightBlue, (92, 92, 255)),
+    (Color::LightMagenta, (255, 0, 255)),
+    (Color::LightCyan, (0, 255, 255)),
+    (Color::Gray, (178, 178, 178)),
+];
+
+/// Best-effort RGB approximation for any `Color`, used as gradient control
+/// points; named ANSI colors map to their conventional RGB midpoints
+fn color_to_rgb(color: Color) -> (u8, u8, u8) {
+    match color {
+        Color::Rgb(r, g, b) => (r, g, b),
+        Color::Indexed(index) => indexed_to_rgb(index),
+        other => ANSI_PALETTE
+            .iter()
+            .find(|(candidate, _)| *candidate == other)
+            .map(|(_, rgb)| *rgb)
+            .unwrap_or((255, 255, 255)),
+    }
+}
+
+/// Approximate the RGB value of an xterm 256-color palette index: the
+/// first 16 are the basic ANSI colors, 16..=231 are the 6x6x6 color cube,
+/// and 232..=255 are the grayscale ramp
+fn indexed_to_rgb(index: u8) -> (u8, u8, u8) {
+    if (index as usize) < ANSI_PALETTE.len() {
+        return ANSI_PALETTE[index as usize].1;
+    }
+    if index >= 232 {
+        let level = 8 + (index - 232) * 10;
+        return (level, level, level);
+    }
+    let cube_index = index - 16;
+    let scale = |v: u8| if v == 0 { 0 } else { 55 + v * 40 };
+    (
+        scale(cube_index / 36),
+        scale((cube_index % 36) / 6),
+        scale(cube_index % 6),
+    )
+}
+
+/// Linearly interpolate between two values at parameter `t`
+fn lerp(a: f64, b: f64, t: f64) -> f64 {
+    a + (b - a) * t
+}
+
+/// Evaluate the Bézier curve with control polygon `points` at parameter
+/// `t` (`0.0..=1.0`) via repeated linear interpolation: each pass lerps
+/// every adjacent pair of the current control polygon, shrinking it by one
+/// point, until a single point remains (a de Casteljau sweep)
+fn interpolate_curve(points: &[(f64, f64, f64)], t: f64) -> (f64, f64, f64) {
+    let mut current = points.to_vec();
+    while current.len() > 1 {
+        current = current
+            .windows(2)
+            .map(|pair| {
+                let (r0, g0, b0) = pair[0];
+                let (r1, g1, b1) = pair[1];
+                (lerp(r0, r1, t), lerp(g0, g1, t), lerp(b0, b1, t))
+            })
+            .collect();
+    }
+    current[0]
+}
+
+/// Whether the terminal advertises 24-bit truecolor support via the
+/// conventional `COLORTERM` env var
+fn supports_truecolor() -> bool {
+    std::env::var("COLORTERM")
+        .map(|value| value == "truecolor" || value == "24bit")
+        .unwrap_or(false)
+}
+
+/// Degrade an interpolated `Color::Rgb` to the nearest of the 16 basic
+/// ANSI colors when `truecolor` is `false`; any other color (already a
+/// named/indexed ANSI color) passes through unchanged
+fn gradient_color(color: Color, truecolor: bool) -> Color {
+    match color {
+        Color::Rgb(r, g, b) if !truecolor => nearest_ansi_color(r, g, b),
+        other => other,
+    }
+}
+
+/// Find the basic ANSI color with the smallest squared Euclidean distance
+/// (in RGB space) to the given channels
+fn nearest_ansi_color(r: u8, g: u8, b: u8) -> Color {
+    ANSI_PALETTE
+        .iter()
+        .min_by_key(|(_, (cr, cg, cb))| {
+            let dr = *cr as i32 - r as i32;
+            let dg = *cg as i32 - g as i32;
+            let db = *cb as i32 - b as i32;
+            dr * dr + dg * dg + db * db
+        })
+        .map(|(color, _)| *color)
+        .unwrap_or(Color::White)
 }
 
 impl Default for Theme {
     fn default() -> Self {
         Self::new()
     }
+}
+
+/// Read the `LAZYCURL_LIGHT_THEME` env var, interpreting common truthy
+/// spellings; `None` if it's unset
+pub fn light_theme_env_override() -> Option<bool> {
+    let value = std::env::var("LAZYCURL_LIGHT_THEME").ok()?;
+    Some(matches!(value.trim().to_lowercase().as_str(), "1" | "true" | "yes" | "on"))
+}
+
+/// Resolve which theme to start with: an explicit env var override wins,
+/// then the user config's `light_theme` field, and only if neither is set
+/// is the terminal's background auto-detected via OSC 11. Checked in that
+/// precedence order: env > config > auto-detect.
+pub fn resolve_theme(
+    env_light_theme: Option<bool>,
+    config_light_theme: Option<bool>,
+    detect_timeout: std::time::Duration,
+) -> Theme {
+    let light = env_light_theme.or(config_light_theme);
+    match light {
+        Some(true) => Theme::light(),
+        Some(false) => Theme::dark(),
+        None => detect_terminal_theme(detect_timeout),
+    }
+}
+
+/// Query the terminal's background color via OSC 11 and pick the theme
+/// that best suits it. Falls back to `Theme::dark()` if the terminal
+/// doesn't reply within `timeout` or the reply can't be parsed (many
+/// terminals and all non-interactive pipes silently ignore the query).
+pub fn detect_terminal_theme(timeout: std::time::Duration) -> Theme {
+    match query_background_luminance(timeout) {
+        Some(luminance) if luminance > 0.5 => Theme::light(),
+        _ => Theme::dark(),
+    }
+}
+
+/// Send `ESC ] 11 ; ? BEL` on stdout and read the reply on stdin from a
+/// background thread, bounded by `timeout` so an unresponsive terminal
+/// can't hang startup
+fn query_background_luminance(timeout: std::time::Duration) -> Option<f64> {
+    use std::io::{Read, Write};
+    use std::sync::mpsc;
+
+    std::io::stdout().write_all(b"\x1b]11;?\x07").ok()?;
+    std::io::stdout().flush().ok()?;
+
+    let (tx, rx) = mpsc::channel();
+    std::thread::spawn(move || {
+        let mut buf = [0u8; 256];
+        if let Ok(n) = std::io::stdin().read(&mut buf) {
+            let _ = tx.send(buf[..n].to_vec());
+        }
+    });
+
+    let bytes = rx.recv_timeout(timeout).ok()?;
+    parse_osc11_luminance(&String::from_utf8_lossy(&bytes))
+}
+
+/// Parse an OSC 11 reply of the form `ESC]11;rgb:RRRR/GGGG/BBBB` (terminated
+/// by BEL or ST) into a relative luminance (`0.0`-`1.0`) via the Rec. 709
+/// coefficients `L = 0.2126*r + 0.7152*g + 0.0722*b`
+fn parse_osc11_luminance(reply: &str) -> Option<f64> {
+    let start = reply.find("rgb:")? + "rgb:".len();
+    let rest = &reply[start..];
+    let end = rest.find(|c: char| c == '\u{7}' || c == '\u{1b}').unwrap_or(rest.len());
+    let channels: Vec<&str> = rest[..end].split('/').collect();
+    if channels.len() != 3 {
+        return None;
+    }
+
+    let to_unit = |channel: &str| -> Option<f64> {
+        let value = u32::from_str_radix(channel, 16).ok()?;
+        let max = (1u32 << (4 * channel.len())) - 1;
+        Some(value as f64 / max as f64)
+    };
+
+    let r = to_unit(channels[0])?;
+    let g = to_unit(channels[1])?;
+    let b = to_unit(channels[2])?;
+
+    Some(0.2126 * r + 0.7152 * g + 0.0722 * b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VALID_THEME: &str = r#"
+primary = "#00aaff"
+secondary = "21"
+accent = "yellow"
+background = "#000000"
+foreground = "white"
+error = "red"
+warning = "214"
+success = "green"
+"#;
+
+    #[test]
+    fn test_parse_color_accepts_hex_ansi_and_named() {
+        assert_eq!(parse_color("#ff0000").unwrap(), Color::Rgb(255, 0, 0));
+        assert_eq!(parse_color("21").unwrap(), Color::Indexed(21));
+        assert_eq!(parse_color("cyan").unwrap(), Color::Cyan);
+        assert!(parse_color("#zzzzzz").is_err());
+        assert!(parse_color("not-a-color").is_err());
+    }
+
+    #[test]
+    fn test_lint_theme_toml_accepts_valid_document() {
+        let theme = lint_theme_toml(VALID_THEME).expect("valid theme should parse");
+        assert_eq!(theme.primary, Color::Rgb(0, 170, 255));
+        assert_eq!(theme.accent, Color::Yellow);
+    }
+
+    #[test]
+    fn test_lint_theme_toml_reports_missing_key() {
+        let missing_accent = VALID_THEME.replace("accent = \"yellow\"\n", "");
+        let diagnostics = lint_theme_toml(&missing_accent).expect_err("accent is missing");
+        assert!(diagnostics.iter().any(|d| d.key == "accent" && d.line.is_none()));
+    }
+
+    #[test]
+    fn test_lint_theme_toml_reports_bad_value_with_line() {
+        let bad_primary = VALID_THEME.replace("primary = \"#00aaff\"", "primary = \"not-a-color\"");
+        let diagnostics = lint_theme_toml(&bad_primary).expect_err("primary is invalid");
+        let primary_diagnostic = diagnostics.iter().find(|d| d.key == "primary").unwrap();
+        assert_eq!(primary_diagnostic.line, Some(2));
+    }
+
+    #[test]
+    fn test_from_file_surfaces_diagnostics_for_missing_file() {
+        let path = std::path::Path::new("/nonexistent/lazycurl-theme-test.toml");
+        assert!(Theme::from_file(path).is_err());
+    }
+
+    #[test]
+    fn test_scope_style_falls_back_to_default_when_undefined() {
+        let theme = Theme::new();
+        let default = Style::default().fg(Color::Magenta);
+        assert_eq!(theme.scope_style("command.program", default), default);
+    }
+
+    #[test]
+    fn test_lint_theme_toml_resolves_scope_against_palette() {
+        let contents = format!(
+            "{}\n[palette]\naccent-color = \"#112233\"\n\n[scopes]\n\"command.program\" = \"accent-color\"\n",
+            VALID_THEME
+        );
+        let theme = lint_theme_toml(&contents).expect("valid theme with scopes should parse");
+        let style = theme.scope_style("command.program", Style::default());
+        assert_eq!(style.fg, Some(Color::Rgb(0x11, 0x22, 0x33)));
+    }
+
+    #[test]
+    fn test_lint_theme_toml_resolves_styled_scope_with_modifiers() {
+        let contents = format!(
+            "{}\n[scopes]\n\"command.option\" = {{ fg = \"cyan\", modifiers = [\"bold\"] }}\n",
+            VALID_THEME
+        );
+        let theme = lint_theme_toml(&contents).expect("valid theme with a styled scope should parse");
+        let style = theme.scope_style("command.option", Style::default());
+        assert_eq!(style.fg, Some(Color::Cyan));
+        assert!(style.add_modifier.contains(Modifier::BOLD));
+    }
+
+    #[test]
+    fn test_lint_theme_toml_reports_unresolvable_scope_color() {
+        let contents = format!(
+            "{}\n[scopes]\n\"command.program\" = \"not-a-color-or-palette-name\"\n",
+            VALID_THEME
+        );
+        let diagnostics = lint_theme_toml(&contents).expect_err("unresolvable scope color should fail");
+        assert!(diagnostics.iter().any(|d| d.key == "scopes.command.program"));
+    }
+
+    #[test]
+    fn test_parse_osc11_luminance_light_background() {
+        let reply = "\x1b]11;rgb:ffff/ffff/ffff\x07";
+        let luminance = parse_osc11_luminance(reply).unwrap();
+        assert!((luminance - 1.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_parse_osc11_luminance_dark_background() {
+        let reply = "\x1b]11;rgb:0000/0000/0000\x07";
+        let luminance = parse_osc11_luminance(reply).unwrap();
+        assert!((luminance - 0.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_parse_osc11_luminance_rejects_malformed_reply() {
+        assert!(parse_osc11_luminance("not an osc11 reply").is_none());
+        assert!(parse_osc11_luminance("\x1b]11;rgb:ffff/ffff\x07").is_none());
+    }
+
+    #[test]
+    fn test_resolve_theme_env_override_wins_over_config() {
+        let theme = resolve_theme(Some(true), Some(false), std::time::Duration::from_millis(0));
+        assert_eq!(theme.background, Color::White);
+    }
+
+    #[test]
+    fn test_resolve_theme_config_used_when_env_unset() {
+        let theme = resolve_theme(None, Some(true), std::time::Duration::from_millis(0));
+        assert_eq!(theme.background, Color::White);
+    }
+
+    #[test]
+    fn test_resolve_theme_falls_back_to_detection_when_unset() {
+        // With nothing to read from stdin, detection should still resolve
+        // (to dark, since the OSC 11 query will time out immediately)
+        let theme = resolve_theme(None, None, std::time::Duration::from_millis(0));
+        assert_eq!(theme.background, Color::Black);
+    }
+
+    #[test]
+    fn test_gradient_single_control_is_flat() {
+        let gradient = Theme::gradient(&[Color::Cyan], 4);
+        assert_eq!(gradient.len(), 4);
+        assert!(gradient.iter().all(|style| style.fg == Some(Color::Cyan)));
+    }
+
+    #[test]
+    fn test_gradient_steps_below_two_returns_first_color_only() {
+        let gradient = Theme::gradient(&[Color::Red, Color::Blue], 1);
+        assert_eq!(gradient.len(), 1);
+        assert_eq!(gradient[0].fg, Some(Color::Red));
+
+        let gradient = Theme::gradient(&[Color::Red, Color::Blue], 0);
+        assert_eq!(gradient.len(), 1);
+    }
+
+    #[test]
+    fn test_gradient_endpoints_match_control_colors() {
+        // Exercised via the pure color math directly (not the public
+        // `Theme::gradient`), since that also applies a `COLORTERM`-gated
+        // ANSI fallback that would make exact Rgb assertions environment-dependent
+        let colors = gradient_colors(&[Color::Rgb(0, 0, 0), Color::Rgb(255, 255, 255)], 5);
+        assert_eq!(colors.len(), 5);
+        assert_eq!(colors.first(), Some(&Color::Rgb(0, 0, 0)));
+        assert_eq!(colors.last(), Some(&Color::Rgb(255, 255, 255)));
+    }
+
+    #[test]
+    fn test_gradient_is_monotonic_between_endpoints() {
+        let colors = gradient_colors(&[Color::Rgb(0, 0, 0), Color::Rgb(200, 0, 0)], 5);
+        let reds: Vec<u8> = colors
+            .iter()
+            .map(|color| match color {
+                Color::Rgb(r, _, _) => *r,
+                _ => panic!("expected an Rgb color"),
+            })
+            .collect();
+        assert!(reds.windows(2).all(|pair| pair[0] <= pair[1]));
+    }
+
+    #[test]
+    fn test_nearest_ansi_color_matches_exact_palette_entries() {
+        assert_eq!(nearest_ansi_color(0, 0, 0), Color::Black);
+        assert_eq!(nearest_ansi_color(255, 255, 0), Color::LightYellow);
+    }
+
+    #[test]
+    fn test_gradient_color_degrades_rgb_without_truecolor() {
+        assert_eq!(gradient_color(Color::Rgb(255, 255, 0), false), Color::LightYellow);
+        assert_eq!(gradient_color(Color::Rgb(255, 255, 0), true), Color::Rgb(255, 255, 0));
+        // Named colors pass through unchanged either way
+        assert_eq!(gradient_color(Color::Cyan, false), Color::Cyan);
+    }
 }
\ No newline at end of file